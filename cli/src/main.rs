@@ -0,0 +1,462 @@
+//! `cargo ts-rs export` exports the bindings of the crate in the current directory by running the
+//! tests generated by `#[ts(export)]` and `export_consts!`. With the `export-all` feature, the test
+//! calling `ts_rs::export_all()` is run if it's named `export_bindings`.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use ts_rs_config::{manifest_file, mirror_path, Config};
+
+/// The manifest listing the exported declarations, which entries are merged into, so it's not
+/// compared when checking the bindings.
+const MANIFEST_FILE: &str = "ts-rs-manifest.json";
+/// The warnings written next to the manifest.
+const WARNINGS_FILE: &str = "ts-rs-warnings.json";
+/// The file all bindings are merged into with `--single-file`.
+const SINGLE_FILE: &str = "index.ts";
+/// The note ts-rs writes at the top of every file it generates, unless `banner` overrides it.
+const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs).";
+
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum Cargo {
+    #[command(name = "ts-rs", subcommand)]
+    TsRs(TsRs),
+}
+
+#[derive(Subcommand)]
+enum TsRs {
+    /// Export the bindings of the crate in the current directory
+    Export(Export),
+}
+
+#[derive(Args)]
+struct Export {
+    /// Directory the bindings are exported to, instead of the `export_dir` from `ts-rs.toml` or
+    /// `bindings/`. Types annotated with `#[ts(export_to = "..")]` are exported to their path.
+    #[arg(long)]
+    out_dir: Option<PathBuf>,
+    /// Remove the files generated by ts-rs from the directory the bindings are exported to before
+    /// exporting them. Other files within the directory are kept.
+    #[arg(long)]
+    clean: bool,
+    /// Fail if the bindings in the directory are out of date, without writing anything
+    #[arg(long)]
+    check: bool,
+    /// Merge all bindings within the directory into `index.ts`, removing the imports between them
+    #[arg(long)]
+    single_file: bool,
+    /// Arguments passed to `cargo test`, like `--features`
+    #[arg(last = true)]
+    cargo_args: Vec<String>,
+}
+
+fn main() -> Result<()> {
+    let Cargo::TsRs(TsRs::Export(export)) = Cargo::parse();
+    export.run()
+}
+
+impl Export {
+    fn run(&self) -> Result<()> {
+        let crate_dir = std::env::current_dir()?;
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => crate_dir.join(out_dir),
+            None => crate_dir.join(default_export_dir(&crate_dir)?),
+        };
+
+        // bindings which are checked or merged are exported to a temporary directory first
+        if !self.check && !self.single_file {
+            if self.clean {
+                clean(&crate_dir, &out_dir)?;
+            }
+            return self.export_to(&out_dir, None);
+        }
+
+        // every file is written to its path mirrored within `staging`, so the bindings of types
+        // annotated with `#[ts(export_to = "..")]` within `out_dir` are checked and merged as well
+        let staging = std::env::temp_dir().join(format!("cargo-ts-rs-{}", std::process::id()));
+        remove_dir(&staging)?;
+        let exported = self
+            .export_to(&out_dir, Some(&staging))
+            .and_then(|()| read_files(&mirror_path(&staging, &out_dir)));
+        remove_dir(&staging)?;
+
+        let mut files = exported?;
+        if self.single_file {
+            let (bindings, other): (BTreeMap<_, _>, _) = files
+                .into_iter()
+                .partition(|(path, _)| path.extension().is_some_and(|ext| ext == "ts"));
+            files = other;
+            files.insert(PathBuf::from(SINGLE_FILE), merge(&bindings));
+        }
+
+        if self.check {
+            let problems = compare(&files, &out_dir)?;
+            for problem in &problems {
+                eprintln!("{}", problem);
+            }
+            if !problems.is_empty() {
+                bail!(
+                    "the bindings in `{}` are out of date, run `cargo ts-rs export` to update them",
+                    out_dir.display()
+                );
+            }
+            return Ok(());
+        }
+
+        if self.clean {
+            clean(&crate_dir, &out_dir)?;
+        }
+        for (path, content) in &files {
+            let path = out_dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, content)
+                .with_context(|| format!("could not write `{}`", path.display()))?;
+        }
+        Ok(())
+    }
+
+    // runs the tests exporting the bindings to `dir`, writing every file within `output_root`
+    // instead, if given
+    fn export_to(&self, dir: &Path, output_root: Option<&Path>) -> Result<()> {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+        let mut command = Command::new(cargo);
+        command
+            .arg("test")
+            .args(&self.cargo_args)
+            .args(["--", "export_bindings", "export_consts_"])
+            .env("TS_RS_EXPORT_DIR", dir);
+        if let Some(output_root) = output_root {
+            command.env("TS_RS_OUTPUT_ROOT", output_root);
+        }
+        let status = command.status().context("could not run `cargo test`")?;
+        if !status.success() {
+            bail!("exporting the bindings failed");
+        }
+        Ok(())
+    }
+}
+
+// the `export_dir` from the config of the crate in `dir`, or `bindings/`
+fn default_export_dir(dir: &Path) -> Result<PathBuf> {
+    let config = Config::find(dir)?.map(|(_, config)| config);
+    let export_dir = config.and_then(|config| config.export_dir);
+    Ok(PathBuf::from(export_dir.as_deref().unwrap_or("bindings")))
+}
+
+fn remove_dir(dir: &Path) -> Result<()> {
+    match std::fs::remove_dir_all(dir) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            Err(err).with_context(|| format!("could not remove `{}`", dir.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Removes the files within `dir` which were generated by ts-rs, i.e. those listed in its manifest,
+/// those starting with the note that they were generated and the manifest itself, followed by the
+/// directories which are empty afterwards. Any other file is kept, so `dir` may contain sources
+/// which are not generated.
+fn clean(crate_dir: &Path, dir: &Path) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    let manifest = std::fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap_or_default();
+    // the files in the manifest are relative to the crate, or absolute if exported by the CLI
+    let listed = manifest
+        .lines()
+        .filter_map(manifest_file)
+        .filter_map(|file| crate_dir.join(file).canonicalize().ok())
+        .collect::<HashSet<_>>();
+
+    let mut dirs = vec![dir.to_owned()];
+    let mut visited = Vec::new();
+    while let Some(current) = dirs.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.ends_with(MANIFEST_FILE)
+                || path.ends_with(WARNINGS_FILE)
+                || listed.contains(&path.canonicalize()?)
+                || is_generated(&path)
+            {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("could not remove `{}`", path.display()))?;
+            }
+        }
+        visited.push(current);
+    }
+    // every directory was visited after its parent
+    for dir in visited.iter().rev() {
+        if std::fs::read_dir(dir)?.next().is_none() {
+            std::fs::remove_dir(dir)
+                .with_context(|| format!("could not remove `{}`", dir.display()))?;
+        }
+    }
+    Ok(())
+}
+
+// whether the header of the file at `path` contains the note that it was generated by ts-rs
+fn is_generated(path: &Path) -> bool {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return false;
+    };
+    let (header, _) = split_header(&content);
+    header.iter().any(|line| line.starts_with(NOTE))
+}
+
+/// Reads the files within `dir`, except the manifest, by their paths relative to `dir`.
+fn read_files(dir: &Path) -> Result<BTreeMap<PathBuf, String>> {
+    let mut files = BTreeMap::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    let mut dirs = vec![dir.to_owned()];
+    while let Some(current) = dirs.pop() {
+        for entry in std::fs::read_dir(current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if !path.ends_with(MANIFEST_FILE) {
+                let content = std::fs::read_to_string(&path)?;
+                files.insert(path.strip_prefix(dir)?.to_owned(), content);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Merges the exported `bindings` into `index.ts`, removing the imports between them. Imports of
+/// files which were not merged, e.g. of types exported outside of the directory, are kept and
+/// resolved relative to `index.ts`. The remaining imports are sorted, and the header of the first
+/// file, like the note that it was generated by ts-rs, is kept.
+fn merge(bindings: &BTreeMap<PathBuf, String>) -> String {
+    let mut header = None;
+    let mut imports = Vec::<String>::new();
+    let mut decls = Vec::new();
+    for (path, content) in bindings {
+        let (file_header, lines) = split_header(content);
+        header.get_or_insert(file_header);
+
+        let mut body = Vec::new();
+        for line in lines {
+            if !line.starts_with("import ") {
+                body.push(line);
+                continue;
+            }
+            let import = match import_target(path, line) {
+                Some(target) if is_merged(bindings, &target) => continue,
+                Some(target) => rebase_import(line, &target),
+                None => line.to_owned(),
+            };
+            if !imports.contains(&import) {
+                imports.push(import);
+            }
+        }
+        decls.push(body.join("\n").trim().to_owned());
+    }
+
+    imports.sort_unstable();
+    let mut out = String::new();
+    for line in header.into_iter().flatten() {
+        out.push_str(line);
+        out.push('\n');
+    }
+    for line in imports {
+        out.push_str(&line);
+        out.push('\n');
+    }
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(&decls.join("\n\n"));
+    out.push('\n');
+    out
+}
+
+/// Splits `content` into its header, i.e. the comments and blank lines it starts with, and the
+/// remaining lines. Blank lines at the end of the header are not part of it.
+fn split_header(content: &str) -> (Vec<&str>, impl Iterator<Item = &str>) {
+    let mut lines = content.lines().peekable();
+    let mut header = Vec::new();
+    let mut in_comment = false;
+    while let Some(line) = lines.peek().map(|line| line.trim()) {
+        if !in_comment && !line.is_empty() && !line.starts_with("//") && !line.starts_with("/*") {
+            break;
+        }
+        in_comment = (in_comment || line.starts_with("/*")) && !line.ends_with("*/");
+        header.extend(lines.next());
+    }
+    while header.last().is_some_and(|line| line.trim().is_empty()) {
+        header.pop();
+    }
+    (header, lines)
+}
+
+// the path an import within the binding at `path` refers to, relative to the directory the
+// bindings were exported to, if it's a relative import
+fn import_target(path: &Path, import: &str) -> Option<PathBuf> {
+    let (_, specifier) = import.split_once(" from \"")?;
+    let specifier = specifier.split('"').next()?;
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return None;
+    }
+    let joined = path.parent().unwrap_or(Path::new("")).join(specifier);
+    let mut target = Vec::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir if matches!(target.last(), Some(Component::Normal(_))) => {
+                target.pop();
+            }
+            component => target.push(component),
+        }
+    }
+    Some(target.iter().collect())
+}
+
+// whether the file `target` is imported from, which may have a `.js` extension, was merged
+fn is_merged(bindings: &BTreeMap<PathBuf, String>, target: &Path) -> bool {
+    let file = match target.extension() {
+        Some(ext) if ext == "js" => target.with_extension("ts"),
+        _ => {
+            let mut file = target.as_os_str().to_owned();
+            file.push(".ts");
+            PathBuf::from(file)
+        }
+    };
+    bindings.contains_key(&file)
+}
+
+// replaces the path of `import` by `target`, relative to `index.ts`
+fn rebase_import(import: &str, target: &Path) -> String {
+    let (start, rest) = import.split_once(" from \"").unwrap();
+    let (_, end) = rest.split_once('"').unwrap();
+    let target = target
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let prefix = if target.starts_with("../") { "" } else { "./" };
+    format!("{start} from \"{prefix}{target}\"{end}")
+}
+
+/// Compares the exported `files` to those in `dir`, returning a description of every difference.
+fn compare(files: &BTreeMap<PathBuf, String>, dir: &Path) -> Result<Vec<String>> {
+    let existing = read_files(dir)?;
+    let mut problems = Vec::new();
+    for (path, content) in files {
+        match existing.get(path) {
+            None => problems.push(format!("`{}` is missing", path.display())),
+            Some(existing) if existing != content => {
+                problems.push(format!("`{}` is out of date", path.display()))
+            }
+            Some(_) => (),
+        }
+    }
+    for path in existing.keys() {
+        if !files.contains_key(path) && path.extension().is_some_and(|ext| ext == "ts") {
+            problems.push(format!("`{}` is not exported anymore", path.display()));
+        }
+    }
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_bindings() {
+        let header = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+        let bindings = BTreeMap::from([
+            (
+                PathBuf::from("User.ts"),
+                format!("{header}import type {{ Role }} from \"./auth/Role\";\nimport type {{ Id }} from \"../ids/Id\";\n\nexport type User = {{ id: Id, role: Role, }};\n"),
+            ),
+            (
+                PathBuf::from("auth/Role.ts"),
+                format!("/* eslint-disable */\n{header}import type {{ Id }} from \"../../ids/Id\";\n\nexport type Role = \"Admin\" | \"Member\";\n"),
+            ),
+        ]);
+        // imports of files which were not merged are kept, relative to `index.ts`
+        assert_eq!(
+            merge(&bindings),
+            format!("{header}import type {{ Id }} from \"../ids/Id\";\n\nexport type User = {{ id: Id, role: Role, }};\n\nexport type Role = \"Admin\" | \"Member\";\n")
+        );
+    }
+
+    #[test]
+    fn clean_generated() {
+        let crate_dir =
+            std::env::temp_dir().join(format!("cargo-ts-rs-clean-{}", std::process::id()));
+        let dir = crate_dir.join("bindings");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(
+            dir.join("A.ts"),
+            format!("{NOTE} Do not edit this file manually.\n"),
+        )
+        .unwrap();
+        std::fs::write(dir.join("nested/B.ts"), "// custom banner\n").unwrap();
+        std::fs::write(dir.join("src/main.ts"), "console.log(1);\n").unwrap();
+        std::fs::write(
+            dir.join(MANIFEST_FILE),
+            "[\n{\"file\":\"bindings/nested/B.ts\",\"type\":\"B\"}\n]\n",
+        )
+        .unwrap();
+
+        clean(&crate_dir, &dir).unwrap();
+        let remaining = read_files(&dir).unwrap();
+        let nested = dir.join("nested").exists();
+        std::fs::remove_dir_all(&crate_dir).unwrap();
+        assert_eq!(
+            remaining.into_keys().collect::<Vec<_>>(),
+            [PathBuf::from("src/main.ts")]
+        );
+        assert!(!nested);
+    }
+
+    #[test]
+    fn compare_bindings() {
+        let dir = std::env::temp_dir().join(format!("cargo-ts-rs-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("A.ts"), "export type A = number;\n").unwrap();
+        std::fs::write(dir.join("B.ts"), "export type B = number;\n").unwrap();
+        std::fs::write(dir.join("Old.ts"), "export type Old = number;\n").unwrap();
+        std::fs::write(dir.join(MANIFEST_FILE), "[]\n").unwrap();
+
+        let files = BTreeMap::from([
+            (
+                PathBuf::from("A.ts"),
+                "export type A = number;\n".to_owned(),
+            ),
+            (
+                PathBuf::from("B.ts"),
+                "export type B = string;\n".to_owned(),
+            ),
+            (
+                PathBuf::from("C.ts"),
+                "export type C = number;\n".to_owned(),
+            ),
+        ]);
+        let problems = compare(&files, &dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            problems,
+            [
+                "`B.ts` is out of date",
+                "`C.ts` is missing",
+                "`Old.ts` is not exported anymore"
+            ]
+        );
+    }
+}
@@ -1,62 +1,334 @@
 use std::{
-    path::{Path, PathBuf},
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
     sync::Arc,
 };
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use once_cell::sync::OnceCell;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
-#[derive(Serialize, Deserialize)]
+/// Crate-wide defaults, read from `ts-rs.toml` in the root of the crate or the workspace, or
+/// from the metadata in `Cargo.toml`, see [`Config::find`].
+/// Every option is optional, and attributes on a type take precedence over it.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
-    ambient_declarations: bool,
-    out_dir: String,
+    /// Directory types without `#[ts(export_to = "..")]` are exported to
+    pub export_dir: Option<String>,
+    /// Default `rename_all` for the fields of structs and struct variants
+    pub rename_all: Option<String>,
+    /// Exports `Option<T>` fields as `t?: T` ("optional") or `t?: T | null` ("nullable")
+    pub optional: Option<String>,
+    /// TypeScript type of integers which are exported as `bigint` by default
+    pub large_integers: Option<String>,
+    /// TypeScript type of `usize` and `isize`, which are exported as `number` by default
+    pub pointer_sized_integers: Option<String>,
+    /// Language level the bindings are used with, like "ES2015" or "ES2020". Before ES2020,
+    /// which introduced `bigint`, integers are exported as `string` instead of `bigint`
+    pub target: Option<String>,
+    /// Document the range of integers narrower than 64 bits, like `u8`, using `@minimum` and
+    /// `@maximum` tags
+    pub numeric_bounds: bool,
+    /// Text inserted at the top of every exported file
+    pub header: Option<String>,
+    /// Comment following the `header`, replacing the note that the file was generated by ts-rs
+    pub banner: Option<String>,
+    /// Comments following the banner, like `// @ts-nocheck`, each on its own line
+    pub pragmas: Vec<String>,
+    /// Template the names of types are derived from, e.g. "Api{name}" for `ApiUser`
+    pub type_names: Option<String>,
+    /// Case of the names of exported files, e.g. "kebab-case" for `user-profile.ts`
+    pub file_names: Option<String>,
+    /// Path of the file types without `#[ts(export_to = "..")]` are exported to within the
+    /// `export_dir`, in which placeholders like `{snake_name}` are replaced by the name of the
+    /// type, e.g. "models/{snake_name}.ts" for `models/user_profile.ts`
+    pub export_to: Option<String>,
+    /// Export types to subdirectories mirroring their modules, e.g. `api/v1/User.ts` for
+    /// `crate::api::v1::User`
+    pub module_dirs: bool,
+    /// Unions of enums with more variants are declared on multiple lines
+    pub union_max_members: Option<usize>,
+    /// Unions of enums whose declaration is longer are declared on multiple lines
+    pub union_max_width: Option<usize>,
+    /// Type of structs without fields, "Record<string, never>" or "Record<PropertyKey, never>"
+    pub empty_struct: Option<String>,
+    /// Import other files with a `.js` extension, like the `import-esm` feature
+    pub esm_imports: bool,
+    /// Export ambient declarations of global types to `.d.ts` files, without `export` keywords or
+    /// imports between them
+    pub ambient: bool,
+    /// Type of `Result<T, E>`, in which `{T}` and `{E}` are replaced by the types of `T` and `E`,
+    /// e.g. "{ ok: {T} } | { err: {E} }"
+    pub result: Option<String>,
+    /// Number of spaces every level of indentation is written with, 2 by default
+    pub indent_width: Option<usize>,
+    /// Indent with tabs instead of spaces
+    pub indent_tabs: bool,
+    /// Enclose string literals in single instead of double quotes
+    pub single_quotes: bool,
+    /// Terminate statements with a semicolon (true) or not (false), instead of leaving them as
+    /// they're generated
+    pub semicolons: Option<bool>,
+    /// Named sets of options used when exporting, selected by `TS_RS_PROFILE`
+    pub profiles: BTreeMap<String, Profile>,
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            ambient_declarations: false,
-            out_dir: "typescript".to_owned(),
+/// Options overriding those of the [`Config`] when exporting with this profile, e.g. to export
+/// the bindings of a crate to multiple frontends using different conventions.
+/// Only options which affect how files are written, not the declarations, can be overridden.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// Directory types without `#[ts(export_to = "..")]` are exported to
+    pub export_dir: Option<String>,
+    /// Text inserted at the top of every exported file
+    pub header: Option<String>,
+    /// Comment following the `header`, replacing the note that the file was generated by ts-rs
+    pub banner: Option<String>,
+    /// Comments following the banner, like `// @ts-nocheck`, each on its own line
+    pub pragmas: Option<Vec<String>>,
+    /// Import other files with a `.js` extension, like the `import-esm` feature
+    pub esm_imports: Option<bool>,
+    /// Number of spaces every level of indentation is written with, 2 by default
+    pub indent_width: Option<usize>,
+    /// Indent with tabs instead of spaces
+    pub indent_tabs: Option<bool>,
+    /// Enclose string literals in single instead of double quotes
+    pub single_quotes: Option<bool>,
+    /// Terminate statements with a semicolon (true) or not (false)
+    pub semicolons: Option<bool>,
+}
+
+/// Derives a file name from `name`, the name of a type in the case given by `file_names`, by
+/// replacing every character which isn't alphanumeric, `_`, `-` or `.` with `_`, so that it's
+/// valid on every platform, e.g. `Foo_Bar` for `Foo$Bar`.
+pub fn sanitize_file_name(name: &str) -> String {
+    let is_valid = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.');
+    name.chars()
+        .map(|c| if is_valid(c) { c } else { '_' })
+        .collect()
+}
+
+/// The path within `root` the file at `path` is written to if `TS_RS_OUTPUT_ROOT` is set, which
+/// mirrors the absolute `path`, e.g. `/tmp/out/home/app/bindings/User.ts` for
+/// `/home/app/bindings/User.ts`.
+pub fn mirror_path(root: &Path, path: &Path) -> PathBuf {
+    let relative = path
+        .components()
+        .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir));
+    root.join(relative.collect::<PathBuf>())
+}
+
+/// The file of an entry of `ts-rs-manifest.json`, which is a line starting with `{"file":"..."`.
+pub fn manifest_file(line: &str) -> Option<String> {
+    let mut chars = line.trim().strip_prefix(r#"{"file":""#)?.chars();
+    let mut file = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(file),
+            '\\' => match chars.next()? {
+                'u' => {
+                    let code = chars.by_ref().take(4).collect::<String>();
+                    file.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                escaped => file.push(escaped),
+            },
+            c => file.push(c),
         }
     }
 }
 
+/// Whether `target`, a language level like "ES2015" or "ESNext", supports `bigint`, which was
+/// introduced in ES2020. Returns `None` if `target` is invalid.
+pub fn supports_bigint(target: &str) -> Option<bool> {
+    match target.to_lowercase().strip_prefix("es")? {
+        "next" => Some(true),
+        "3" | "5" | "6" => Some(false),
+        year => {
+            let year = year.parse::<u32>().ok().filter(|year| *year >= 2015)?;
+            Some(year >= 2020)
+        }
+    }
+}
+
+/// Converts `name` to `case`, which is one of the values accepted by `rename_all`, like
+/// "camelCase" or "kebab-case", in any spelling. Returns `None` if `case` is invalid.
+pub fn convert_case(case: &str, name: &str) -> Option<String> {
+    use inflector::Inflector;
+
+    Some(match &*case.to_lowercase().replace(['_', '-'], "") {
+        "lowercase" => name.to_lowercase(),
+        "uppercase" => name.to_uppercase(),
+        "camelcase" => {
+            let pascal = convert_case("PascalCase", name)?;
+            match pascal.chars().next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + &pascal[first.len_utf8()..],
+                None => pascal,
+            }
+        }
+        "snakecase" => name.to_snake_case(),
+        "pascalcase" => {
+            let mut s = String::with_capacity(name.len());
+
+            let mut capitalize = true;
+            for c in name.chars() {
+                if c == '_' {
+                    capitalize = true;
+                    continue;
+                } else if capitalize {
+                    s.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    s.push(c)
+                }
+            }
+
+            s
+        }
+        "screamingsnakecase" => name.to_screaming_snake_case(),
+        "kebabcase" => name.to_kebab_case(),
+        "screamingkebabcase" => name.to_screaming_snake_case().replace('_', "-"),
+        _ => return None,
+    })
+}
+
 static CONFIG_INSTANCE: OnceCell<Arc<Config>> = OnceCell::new();
 
 impl Config {
-    const FILE_NAME: &'static str = "ts.toml";
+    pub const FILE_NAME: &'static str = "ts-rs.toml";
 
+    /// Returns the config of the current crate, which is only loaded once.
     pub fn get() -> Result<Arc<Self>> {
-        match CONFIG_INSTANCE.get() {
-            None => {
-                let cfg = Arc::new(Self::load()?);
-                CONFIG_INSTANCE.set(cfg.clone()).ok();
-                Ok(cfg)
-            }
-            Some(cfg) => Ok(cfg.clone()),
+        CONFIG_INSTANCE
+            .get_or_try_init(|| Self::load().map(Arc::new))
+            .cloned()
+    }
+
+    /// Loads the config of the crate in `CARGO_MANIFEST_DIR`, see [`Config::find`].
+    /// If there is no config, the default config is returned.
+    pub fn load() -> Result<Self> {
+        let config = Self::find(&Self::manifest_dir()?)?.map(|(_, config)| config);
+        Ok(config.unwrap_or_default())
+    }
+
+    /// Path of the file the config of the crate in `CARGO_MANIFEST_DIR` is read from, if any.
+    pub fn path() -> Result<Option<PathBuf>> {
+        Ok(Self::find(&Self::manifest_dir()?)?.map(|(path, _)| path))
+    }
+
+    /// The TypeScript type of integers exported as `bigint` by default, which is `large_integers`,
+    /// or "string" if the `target` doesn't support `bigint`.
+    pub fn large_integers_type(&self) -> Option<&str> {
+        let bigint = self.target.as_deref().and_then(supports_bigint);
+        match (self.large_integers.as_deref(), bigint) {
+            (None, Some(false)) => Some("string"),
+            (large_integers, _) => large_integers,
         }
     }
 
-    fn load() -> Result<Self> {
-        let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR")?);
-        let config = Self::try_load_from_dir(&manifest_dir)?.unwrap_or_default();
-        Ok(config)
+    fn manifest_dir() -> Result<PathBuf> {
+        Ok(PathBuf::from(std::env::var("CARGO_MANIFEST_DIR")?))
     }
 
-    fn try_load_from_dir(dir: &Path) -> Result<Option<Self>> {
-        let path = {
-            let mut path = PathBuf::from(dir);
-            path.push(Self::FILE_NAME);
-            path
+    /// Finds the config of the crate in `dir`, returning it together with the file it's read from.
+    /// The config is read from the first of
+    /// - `ts-rs.toml` in the crate,
+    /// - `[package.metadata.ts-rs]` in the `Cargo.toml` of the crate,
+    /// - `ts-rs.toml` in a parent directory, up to the root of the workspace,
+    /// - `[workspace.metadata.ts-rs]` in the `Cargo.toml` of the workspace.
+    ///
+    /// Like in cargo, the root of the workspace is the first directory containing a `Cargo.toml`
+    /// with a `[workspace]`, which may be the crate itself. Parent directories of a crate which
+    /// isn't part of a workspace are not searched.
+    ///
+    /// Relative paths in the config of a workspace are relative to the directory of its file.
+    pub fn find(dir: &Path) -> Result<Option<(PathBuf, Self)>> {
+        if let Some(found) = Self::try_load_from_dir(dir)? {
+            return Ok(Some(found));
+        }
+        if let Some(found) = Self::try_load_from_manifest(dir, &["package", "metadata", "ts-rs"])? {
+            return Ok(Some(found));
+        }
+        let Some(root) = Self::workspace_root(dir)? else {
+            return Ok(None);
         };
+        for ancestor in dir.ancestors().skip(1) {
+            if !ancestor.starts_with(root) {
+                break;
+            }
+            if let Some((path, config)) = Self::try_load_from_dir(ancestor)? {
+                return Ok(Some((path, config.relative_to(ancestor))));
+            }
+        }
+        let found = Self::try_load_from_manifest(root, &["workspace", "metadata", "ts-rs"])?;
+        Ok(found.map(|(path, config)| match root == dir {
+            true => (path, config),
+            false => (path, config.relative_to(root)),
+        }))
+    }
+
+    fn try_load_from_dir(dir: &Path) -> Result<Option<(PathBuf, Self)>> {
+        let path = dir.join(Self::FILE_NAME);
         match path.is_file() {
             true => {
-                let content = std::fs::read_to_string(path)?;
-                let parsed = toml::from_str::<Config>(&content)?;
-                Ok(Some(parsed))
+                let content = std::fs::read_to_string(&path)?;
+                let parsed = toml::from_str::<Config>(&content)
+                    .with_context(|| format!("invalid {}", Self::FILE_NAME))?;
+                Ok(Some((path, parsed)))
             }
             false => Ok(None),
         }
     }
+
+    // reads the table at `keys` in the `Cargo.toml` in `dir`, e.g. `[package.metadata.ts-rs]`
+    fn try_load_from_manifest(dir: &Path, keys: &[&str]) -> Result<Option<(PathBuf, Self)>> {
+        let path = dir.join("Cargo.toml");
+        let Some(manifest) = Self::read_manifest(&path)? else {
+            return Ok(None);
+        };
+        let Some(table) = keys.iter().try_fold(&manifest, |value, key| value.get(key)) else {
+            return Ok(None);
+        };
+        let parsed = table
+            .clone()
+            .try_into::<Config>()
+            .with_context(|| format!("invalid [{}] in {}", keys.join("."), path.display()))?;
+        Ok(Some((path, parsed)))
+    }
+
+    // the first of `dir` and its parent directories containing a `Cargo.toml` with a `[workspace]`
+    fn workspace_root(dir: &Path) -> Result<Option<&Path>> {
+        for ancestor in dir.ancestors() {
+            let manifest = Self::read_manifest(&ancestor.join("Cargo.toml"))?;
+            if manifest.is_some_and(|manifest| manifest.get("workspace").is_some()) {
+                return Ok(Some(ancestor));
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_manifest(path: &Path) -> Result<Option<toml::Value>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let manifest = toml::from_str::<toml::Value>(&content)
+            .with_context(|| format!("invalid {}", path.display()))?;
+        Ok(Some(manifest))
+    }
+
+    // resolves the `export_dir` of the config and its profiles relative to `dir` instead of the
+    // crate being compiled
+    fn relative_to(mut self, dir: &Path) -> Self {
+        let export_dirs = std::iter::once(&mut self.export_dir).chain(
+            self.profiles
+                .values_mut()
+                .map(|profile| &mut profile.export_dir),
+        );
+        for export_dir in export_dirs.flatten() {
+            *export_dir = dir.join(&*export_dir).to_string_lossy().into_owned();
+        }
+        self
+    }
 }
@@ -0,0 +1,68 @@
+use std::{fs, path::PathBuf};
+
+use ts_rs_config::Config;
+
+// creates an empty directory for the test, containing `files`
+fn setup(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ts-rs-config-{}-{test}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for (path, contents) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    dir
+}
+
+const OUTER: (&str, &str) = ("ts-rs.toml", "export_dir = \"outer\"");
+
+#[test]
+fn standalone_crate() {
+    let dir = setup(
+        "standalone",
+        &[OUTER, ("app/Cargo.toml", "[package]\nname = \"app\"")],
+    );
+
+    assert!(Config::find(&dir.join("app")).unwrap().is_none());
+}
+
+#[test]
+fn workspace_root_crate() {
+    let dir = setup(
+        "root",
+        &[
+            OUTER,
+            (
+                "app/Cargo.toml",
+                "[package]\nname = \"app\"\n[workspace]\n[workspace.metadata.ts-rs]\nexport_dir = \"web\"",
+            ),
+            ("plain/Cargo.toml", "[package]\nname = \"plain\"\n[workspace]"),
+        ],
+    );
+
+    let (path, config) = Config::find(&dir.join("app")).unwrap().unwrap();
+    assert_eq!(path, dir.join("app/Cargo.toml"));
+    assert_eq!(config.export_dir.as_deref(), Some("web"));
+
+    assert!(Config::find(&dir.join("plain")).unwrap().is_none());
+}
+
+#[test]
+fn workspace_member() {
+    let dir = setup(
+        "member",
+        &[
+            OUTER,
+            (
+                "ws/Cargo.toml",
+                "[workspace]\nmembers = [\"app\"]\n[workspace.metadata.ts-rs]\nexport_dir = \"web\"",
+            ),
+            ("ws/app/Cargo.toml", "[package]\nname = \"app\""),
+        ],
+    );
+
+    let (path, config) = Config::find(&dir.join("ws/app")).unwrap().unwrap();
+    assert_eq!(path, dir.join("ws/Cargo.toml"));
+    let export_dir = dir.join("ws/web").to_string_lossy().into_owned();
+    assert_eq!(config.export_dir, Some(export_dir));
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export)]
+struct User {
+    id: u32,
+    role: Role,
+}
+
+#[derive(TS)]
+#[ts(export)]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(export, enum)]
+enum Status {
+    Active,
+    Banned,
+}
+
+#[derive(TS)]
+#[ts(tag_values)]
+enum Color {
+    Red,
+    Green,
+}
+
+fn main() {}
+
+#[test]
+fn ambient() {
+    User::export().unwrap();
+    let exported = std::fs::read_to_string("ambient-bindings/User.d.ts").unwrap();
+    assert!(!exported.contains("import"));
+    assert!(exported.ends_with("\n\ntype User = { id: number, role: Role, }"));
+
+    let exported = std::fs::read_to_string("ambient-bindings/Role.d.ts").unwrap();
+    assert!(exported.ends_with("\n\ntype Role = \"Admin\" | \"Member\";"));
+
+    Status::export().unwrap();
+    let exported = std::fs::read_to_string("ambient-bindings/Status.d.ts").unwrap();
+    assert!(
+        exported.ends_with("\n\ndeclare enum Status { Active = \"Active\", Banned = \"Banned\" }")
+    );
+}
+
+#[test]
+fn values() {
+    let err = Color::export().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`Color` declares values, which cannot be exported as ambient declarations"
+    );
+}
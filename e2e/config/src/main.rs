@@ -0,0 +1,157 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export)]
+struct User {
+    user_id: u64,
+    display_name: Option<String>,
+    #[ts(rename = "LastSeen")]
+    last_seen: Vec<i64>,
+}
+
+#[derive(TS)]
+#[ts(export)]
+struct UserProfile {
+    user: User,
+}
+
+#[derive(TS)]
+#[ts(export, rename_all = "snake_case")]
+struct Overridden {
+    created_at: f64,
+    #[ts(optional)]
+    deleted_at: Option<f64>,
+    #[ts(optional = false)]
+    archived_at: Option<f64>,
+}
+
+#[derive(TS)]
+#[ts(export)]
+enum Event {
+    Created {
+        user_id: u64,
+    },
+    #[ts(rename_all = "UPPERCASE")]
+    Deleted {
+        user_id: u64,
+    },
+    Renamed(String, String),
+}
+
+#[derive(TS)]
+#[ts(export)]
+struct Pair(u64, Option<String>);
+
+#[derive(TS)]
+#[ts(export)]
+struct Page {
+    offset: usize,
+    #[ts(pointer_sized_integers = "number")]
+    len: usize,
+    #[ts(large_integers = "number")]
+    total: u64,
+}
+
+#[derive(TS)]
+#[ts(export)]
+struct Pixel {
+    /// The red channel
+    red: u8,
+    alpha: Option<std::num::NonZeroU16>,
+    #[ts(type = "number")]
+    blue: u8,
+}
+
+#[derive(TS)]
+#[ts(export)]
+struct Port(i32);
+
+#[derive(TS)]
+#[ts(export)]
+struct Empty {}
+
+#[derive(TS)]
+#[ts(export)]
+struct Skipped {
+    #[ts(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(TS)]
+#[ts(export)]
+struct Response {
+    result: Result<Pair, String>,
+}
+
+mod api {
+    pub mod v1 {
+        use ts_rs::TS;
+
+        #[derive(TS)]
+        #[ts(export)]
+        pub struct Session {
+            user: crate::User,
+        }
+    }
+}
+
+fn main() {}
+
+#[test]
+fn config() {
+    assert_eq!(
+        User::decl(),
+        "type User = { userId: string, displayName?: string | null, LastSeen: Array<string>, }"
+    );
+    assert_eq!(
+        Overridden::decl(),
+        "type Overridden = { created_at: number, deleted_at?: number, archived_at: number | null, }"
+    );
+    assert_eq!(
+        Event::decl(),
+        r#"type Event =
+  | { "Created": { userId: string, } }
+  | { "Deleted": { USER_ID: string, } }
+  | { "Renamed": [string, string] };"#
+    );
+    assert_eq!(Pair::decl(), "type Pair = [string, string | null];");
+    assert_eq!(
+        Page::decl(),
+        "type Page = { offset: bigint, len: number, total: number, }"
+    );
+    assert_eq!(Empty::decl(), "type Empty = Record<PropertyKey, never>;");
+    assert_eq!(Skipped::decl(), "type Skipped = Record<PropertyKey, never>");
+    assert_eq!(
+        Response::decl(),
+        "type Response = { result: { ok: Pair } | { err: string }, }"
+    );
+
+    User::export().unwrap();
+    let exported = std::fs::read_to_string("config-bindings/user.ts").unwrap();
+    assert!(exported.starts_with("/* eslint-disable */\n// This file was generated"));
+    assert!(exported.contains(
+        "Do not edit this file manually.\n// @ts-nocheck\n// prettier-ignore\n\nexport type User"
+    ));
+
+    UserProfile::export().unwrap();
+    let exported = std::fs::read_to_string("config-bindings/user-profile.ts").unwrap();
+    assert!(exported.contains("// prettier-ignore\nimport type { User } from \"./user\";"));
+
+    api::v1::Session::export().unwrap();
+    let exported = std::fs::read_to_string("config-bindings/api/v1/session.ts").unwrap();
+    assert!(exported.contains(r#"import type { User } from "../../user";"#));
+}
+
+#[test]
+fn numeric_bounds() {
+    assert_eq!(
+        Pixel::decl(),
+        "type Pixel = { \n/**\n * The red channel\n *\n * @minimum 0\n * @maximum 255\n */\nred: number, \n/**\n * @minimum 1\n * @maximum 65535\n */\nalpha?: number | null, blue: number, }"
+    );
+    let exported = Port::export_to_string().unwrap();
+    assert!(exported.contains(
+        "/**\n * @minimum -2147483648\n * @maximum 2147483647\n */\nexport type Port = number;"
+    ));
+}
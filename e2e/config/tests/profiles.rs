@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct Role {
+    name: String,
+}
+
+#[derive(TS)]
+enum Access {
+    Read,
+    Write,
+    Admin,
+}
+
+#[derive(TS)]
+struct Member {
+    role: Role,
+    access: Access,
+}
+
+// the selected profiles are only read once, so this is the only test exporting a type
+#[test]
+fn profiles() {
+    std::env::set_var("TS_RS_PROFILE", "web,admin");
+    let _ = fs::remove_dir_all("profile-bindings");
+
+    Member::export().unwrap();
+
+    let web = fs::read_to_string("profile-bindings/web/member.ts").unwrap();
+    assert!(web.starts_with("/* eslint-disable */\n// This file was generated"));
+    assert!(web.contains("// prettier-ignore\nimport type { Access } from \"./access.js\";"));
+    assert!(web.ends_with("export type Member = { role: Role, access: Access, };"));
+    let web = fs::read_to_string("profile-bindings/web/access.ts").unwrap();
+    assert!(
+        web.ends_with("export type Access =\n    | \"Read\"\n    | \"Write\"\n    | \"Admin\";")
+    );
+    assert!(fs::metadata("profile-bindings/web/role.ts").is_ok());
+
+    let admin = fs::read_to_string("profile-bindings/admin/member.ts").unwrap();
+    assert!(admin.starts_with("// admin\nimport type { Access } from './access'\n"));
+    assert!(admin.ends_with("export type Member = { role: Role, access: Access, }"));
+    let admin = fs::read_to_string("profile-bindings/admin/access.ts").unwrap();
+    assert!(admin.ends_with("export type Access =\n\t| 'Read'\n\t| 'Write'\n\t| 'Admin'"));
+    assert!(fs::metadata("profile-bindings/admin/role.ts").is_ok());
+
+    // types are only exported to the directories of the selected profiles
+    assert!(fs::metadata("config-bindings/member.ts").is_err());
+}
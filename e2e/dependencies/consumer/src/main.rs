@@ -7,4 +7,10 @@ struct ConsumerType {
     pub ty: LibraryType
 }
 
-fn main() {}
\ No newline at end of file
+fn main() {}
+
+#[test]
+fn type_names() {
+    assert_eq!(LibraryType::name(), "DepLibraryType");
+    assert_eq!(ConsumerType::decl(), "type ConsumerType = { ty: DepLibraryType, }");
+}
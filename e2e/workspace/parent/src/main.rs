@@ -1,6 +1,6 @@
-use ts_rs::TS;
 use crate1::Crate1;
 use crate2::Crate2;
+use ts_rs::TS;
 
 fn main() {
     println!("Hello, world!");
@@ -96,3 +96,18 @@ pub struct Parent15 {
     pub crate1: Crate1,
     pub crate2: Crate2,
 }
+
+#[test]
+fn workspace_config() {
+    let workspace = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap();
+    let export_to = Parent::get_export_to().unwrap();
+    assert_eq!(
+        std::path::Path::new(&export_to),
+        workspace.join("bindings/Parent.ts")
+    );
+
+    let exported = Parent::export_to_string().unwrap();
+    assert!(exported.contains(r#"import type { Crate2 } from "./Crate2.js";"#));
+}
@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use syn::{Attribute, Ident, Result};
+
+use crate::{
+    attr::{parse_assign_str, parse_cfg},
+    utils::parse_attrs,
+};
+
+/// Attributes of `export_consts!`, given as inner attributes like `#![ts(export_to = "..")]`
+#[derive(Default)]
+pub struct ConstsAttr {
+    pub export_to: Option<String>,
+    pub export_cfg: Option<TokenStream>,
+}
+
+impl ConstsAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+        parse_attrs(attrs)?.for_each(|a| result.merge(a));
+        Ok(result)
+    }
+
+    fn merge(
+        &mut self,
+        ConstsAttr {
+            export_to,
+            export_cfg,
+        }: ConstsAttr,
+    ) {
+        self.export_to = self.export_to.take().or(export_to);
+        self.export_cfg = self.export_cfg.take().or(export_cfg);
+    }
+}
+
+impl_parse! {
+    ConstsAttr(input, out) {
+        "export_to" => out.export_to = Some(parse_assign_str(input)?),
+        "cfg" => out.export_cfg = Some(parse_cfg(input)?),
+    }
+}
@@ -1,7 +1,13 @@
-use syn::{Attribute, Ident, Result};
+use proc_macro2::TokenStream;
+use syn::{parse::ParseStream, Attribute, Ident, Result, Type, WherePredicate};
 
 use crate::{
-    attr::{parse_assign_inflection, parse_assign_str, Inflection},
+    attr::{
+        add_export_target, check_export_targets, merge_export_targets, parse_assign_inflection,
+        parse_assign_str, parse_assign_target, parse_bound, parse_cfg, parse_concrete,
+        parse_export_to, parse_generic_assignments, parse_optional_assign_str, ExportTarget,
+        Inflection,
+    },
     utils::{parse_attrs, parse_docs},
 };
 
@@ -11,8 +17,40 @@ pub struct EnumAttr {
     pub rename_all_fields: Option<Inflection>,
     pub rename: Option<String>,
     pub export_to: Option<String>,
+    pub also_export_to: Vec<ExportTarget>,
+    /// case of the name of the file the type is exported to, overriding `file_names` in
+    /// `ts-rs.toml`
+    pub file_rename_all: Option<Inflection>,
+    /// whether the language level given by `#[ts(target = "..")]` supports `bigint`, overriding
+    /// `target` in `ts-rs.toml`
+    pub target: Option<bool>,
     pub export: bool,
+    pub export_cfg: Option<TokenStream>,
+    pub skip_generics: Vec<String>,
+    pub generic_bounds: Vec<(String, String)>,
+    /// where clause of the impl, replacing the `TS` bounds of the generic parameters, given by
+    /// `#[ts(bound = "..")]`
+    pub bound: Option<Vec<WherePredicate>>,
+    pub generic_docs: Vec<(String, String)>,
+    pub concrete: Vec<(String, Type)>,
     pub docs: String,
+    pub repr: Option<EnumRepr>,
+    pub tag_values: Option<Option<String>>,
+    pub from_string: Option<Option<String>>,
+    /// name of the constant listing the values of a fieldless enum, given by `#[ts(values)]` or
+    /// `#[ts(values = "..")]`
+    pub values: Option<Option<String>>,
+    /// given by `#[ts(variant_types)]`, declaring an alias narrowing the enum to each variant
+    pub variant_types: bool,
+    /// given by `#[ts(as_display)]`, exporting the type as `string`
+    pub as_display: bool,
+    /// representation of the unit variants of an untagged enum, given by
+    /// `#[ts(unit_variant = "..")]`
+    pub unit_variant: Option<UnitVariant>,
+    /// type given by `#[serde(from = "..")]` or `#[serde(try_from = "..")]`
+    pub from: Option<String>,
+    /// type given by `#[serde(into = "..")]`
+    pub into: Option<String>,
     tag: Option<String>,
     untagged: bool,
     content: Option<String>,
@@ -22,6 +60,22 @@ pub struct EnumAttr {
 #[derive(Default)]
 pub struct SerdeEnumAttr(EnumAttr);
 
+/// Indicates whether a fieldless enum should be emitted as a TypeScript `enum` instead of a union.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum EnumRepr {
+    Enum,
+    ConstEnum,
+}
+
+/// How the unit variants of an untagged enum are represented, which serde serializes as `null`
+/// unless they're serialized differently, e.g. by a custom `Serialize` implementation.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum UnitVariant {
+    Null,
+    /// the name of the variant, e.g. `"Unknown"`
+    String,
+}
+
 #[derive(Copy, Clone)]
 pub enum Tagged<'a> {
     Externally,
@@ -45,13 +99,29 @@ impl EnumAttr {
 
     pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut result = Self::default();
-        parse_attrs(attrs)?.for_each(|a| result.merge(a));
+        // a later `#[ts(rename)]` takes precedence, e.g. one selected by `cfg_attr`, while
+        // `#[ts(..)]` always takes precedence over serde
+        for mut attr in parse_attrs::<Self>(attrs)? {
+            result.rename = attr.rename.take().or(result.rename.take());
+            result.merge(attr);
+        }
 
         let docs = parse_docs(attrs)?;
         result.docs = docs;
 
         #[cfg(feature = "serde-compat")]
         crate::utils::parse_serde_attrs::<SerdeEnumAttr>(attrs).for_each(|a| result.merge(a.0));
+
+        #[cfg(feature = "strum-interop")]
+        crate::utils::parse_strum_attrs(attrs, |key, input| match key {
+            "serialize_all" => {
+                let inflection = parse_assign_inflection(input)?;
+                result.rename_all = result.rename_all.or(Some(inflection));
+                Ok(true)
+            }
+            _ => Ok(false),
+        })?;
+        check_export_targets(&result.export_to, &result.also_export_to)?;
         Ok(result)
     }
 
@@ -65,8 +135,26 @@ impl EnumAttr {
             content,
             untagged,
             export_to,
+            also_export_to,
+            file_rename_all,
+            target,
             export,
+            export_cfg,
+            skip_generics,
+            generic_bounds,
+            bound,
+            generic_docs,
+            concrete,
             docs,
+            repr,
+            tag_values,
+            from_string,
+            values,
+            variant_types,
+            as_display,
+            unit_variant,
+            from,
+            into,
         }: EnumAttr,
     ) {
         self.rename = self.rename.take().or(rename);
@@ -75,9 +163,31 @@ impl EnumAttr {
         self.tag = self.tag.take().or(tag);
         self.untagged = self.untagged || untagged;
         self.content = self.content.take().or(content);
+        self.file_rename_all = self.file_rename_all.take().or(file_rename_all);
+        self.target = self.target.take().or(target);
         self.export = self.export || export;
-        self.export_to = self.export_to.take().or(export_to);
+        self.export_cfg = self.export_cfg.take().or(export_cfg);
+        self.skip_generics.extend(skip_generics);
+        self.generic_bounds.extend(generic_bounds);
+        self.bound = self.bound.take().or(bound);
+        self.generic_docs.extend(generic_docs);
+        self.concrete.extend(concrete);
+        merge_export_targets(
+            &mut self.export_to,
+            &mut self.also_export_to,
+            export_to,
+            also_export_to,
+        );
         self.docs = docs;
+        self.repr = self.repr.take().or(repr);
+        self.tag_values = self.tag_values.take().or(tag_values);
+        self.from_string = self.from_string.take().or(from_string);
+        self.values = self.values.take().or(values);
+        self.variant_types = self.variant_types || variant_types;
+        self.as_display = self.as_display || as_display;
+        self.unit_variant = self.unit_variant.take().or(unit_variant);
+        self.from = self.from.take().or(from);
+        self.into = self.into.take().or(into);
     }
 }
 
@@ -86,11 +196,42 @@ impl_parse! {
         "rename" => out.rename = Some(parse_assign_str(input)?),
         "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
         "rename_all_fields" => out.rename_all_fields = Some(parse_assign_inflection(input)?),
-        "export_to" => out.export_to = Some(parse_assign_str(input)?),
+        "export_to" => add_export_target(
+            &mut out.export_to,
+            &mut out.also_export_to,
+            parse_export_to(input)?,
+        ),
+        "file_rename_all" => out.file_rename_all = Some(parse_assign_inflection(input)?),
+        "target" => out.target = Some(parse_assign_target(input)?),
         "export" => out.export = true,
+        "cfg" => out.export_cfg = Some(parse_cfg(input)?),
+        "skip_generic" => out.skip_generics.push(parse_assign_str(input)?),
+        "generic_bound" => out.generic_bounds.extend(parse_generic_assignments(input)?),
+        "bound" => out.bound = Some(parse_bound(input)?),
+        "doc_generic" => out.generic_docs.extend(parse_generic_assignments(input)?),
+        "concrete" => out.concrete.extend(parse_concrete(input)?),
         "tag" => out.tag = Some(parse_assign_str(input)?),
         "content" => out.content = Some(parse_assign_str(input)?),
-        "untagged" => out.untagged = true
+        "untagged" => out.untagged = true,
+        "enum" => out.repr = Some(EnumRepr::Enum),
+        "const_enum" => out.repr = Some(EnumRepr::ConstEnum),
+        "tag_values" => out.tag_values = Some(parse_optional_assign_str(input)?),
+        "from_string" => out.from_string = Some(parse_optional_assign_str(input)?),
+        "values" => out.values = Some(parse_optional_assign_str(input)?),
+        "variant_types" => out.variant_types = true,
+        "as_display" => out.as_display = true,
+        "unit_variant" => out.unit_variant = Some(parse_unit_variant(input)?)
+    }
+}
+
+fn parse_unit_variant(input: ParseStream) -> Result<UnitVariant> {
+    let span = input.span();
+    match &*parse_assign_str(input)? {
+        "null" => Ok(UnitVariant::Null),
+        "string" => Ok(UnitVariant::String),
+        other => {
+            syn_err!(span; "invalid unit_variant \"{}\", expected \"null\" or \"string\"", other)
+        }
     }
 }
 
@@ -102,6 +243,8 @@ impl_parse! {
         "rename_all_fields" => out.0.rename_all_fields = Some(parse_assign_inflection(input)?),
         "tag" => out.0.tag = Some(parse_assign_str(input)?),
         "content" => out.0.content = Some(parse_assign_str(input)?),
-        "untagged" => out.0.untagged = true
+        "untagged" => out.0.untagged = true,
+        "from" | "try_from" => out.0.from = Some(parse_assign_str(input)?),
+        "into" => out.0.into = Some(parse_assign_str(input)?)
     }
 }
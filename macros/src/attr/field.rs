@@ -1,29 +1,134 @@
-use syn::{spanned::Spanned, Attribute, Ident, Result};
+use std::convert::TryFrom;
 
-use super::parse_assign_str;
-use crate::utils::{parse_attrs, parse_docs};
+use syn::{spanned::Spanned, Attribute, Error, Ident, Lit, Result, Type};
+
+use super::{parse_assign_inflection, parse_assign_str, parse_types, suggest, Inflection};
+use crate::utils::{parse_attrs, parse_docs, record_warning};
 
 #[derive(Default)]
 pub struct FieldAttr {
     pub type_as: Option<String>,
     pub type_override: Option<String>,
+    /// types referenced by `type_override`, given by `#[ts(uses(..))]`, which are imported
+    pub uses: Vec<Type>,
+    /// constant the field is always serialized as, given by `#[ts(literal = "..")]`
+    pub literal: Option<Lit>,
+    /// type of `u64`, `i64`, `u128` and `i128` within the type of the field, given by
+    /// `#[ts(large_integers = "..")]`, which overrides the default set in `ts-rs.toml`
+    pub large_integers: Option<&'static str>,
+    /// type of `usize` and `isize` within the type of the field, given by
+    /// `#[ts(pointer_sized_integers = "..")]`, which overrides the default set in `ts-rs.toml`
+    pub pointer_sized_integers: Option<&'static str>,
     pub rename: Option<String>,
+    /// case of the keys of the inlined type, given by `#[ts(inline, rename_all = "..")]`
+    pub rename_all: Option<Inflection>,
     pub inline: bool,
     pub skip: bool,
-    pub optional: Optional,
+    /// given by `#[ts(optional)]`, or `#[ts(optional = false)]` to opt out of the default from
+    /// `ts-rs.toml`
+    pub optional: Option<Optional>,
     pub flatten: bool,
+    pub readonly: bool,
+    pub revive: Option<Revive>,
+    pub directional: Directional,
     pub docs: String,
 }
 
 /// Indicates whether the field is marked with `#[ts(optional)]`.
 /// `#[ts(optional)]` turns an `t: Option<T>` into `t?: T`, while
-/// `#[ts(optional = nullable)]` turns it into `t?: T | null`.
-#[derive(Default)]
+/// `#[ts(optional = nullable)]` turns it into `t?: T | null`, and `#[ts(optional = false)]` keeps
+/// it as `t: T | null`.
+#[derive(Default, Clone, Copy)]
 pub struct Optional {
     pub optional: bool,
     pub nullable: bool,
 }
 
+/// Attributes which only affect one direction of (de)serialization, which are used to generate
+/// the two views of a type annotated with `#[ts(dual)]`.
+#[derive(Default, Clone, Copy)]
+pub struct Directional {
+    pub skip_serializing: bool,
+    /// given by `#[serde(skip_serializing_if = "..")]`
+    pub skip_serializing_if: bool,
+    pub skip_deserializing: bool,
+    /// given by `#[serde(default)]`
+    pub default: bool,
+}
+
+impl Directional {
+    fn merge(&mut self, other: Directional) {
+        self.skip_serializing |= other.skip_serializing;
+        self.skip_serializing_if |= other.skip_serializing_if;
+        self.skip_deserializing |= other.skip_deserializing;
+        self.default |= other.default;
+    }
+
+    /// Returns the name of the first attribute which is set, used to warn about it being ignored.
+    pub fn any(self) -> Option<&'static str> {
+        [
+            (self.skip_serializing, "skip_serializing"),
+            (self.skip_serializing_if, "skip_serializing_if"),
+            (self.skip_deserializing, "skip_deserializing"),
+        ]
+        .into_iter()
+        .find_map(|(set, name)| set.then_some(name))
+    }
+}
+
+/// Marks a field which should be transformed by the client after `JSON.parse`, given by
+/// `#[ts(revive = "..")]`.
+#[derive(Clone, Copy)]
+pub enum Revive {
+    /// an ISO 8601 string, revived as a `Date`
+    Date,
+    /// a number or a string, revived as a `bigint`
+    BigInt,
+    /// a base64 encoded string, revived as an `Uint8Array`
+    Bytes,
+}
+
+impl Revive {
+    const VALID: [&'static str; 3] = ["Date", "bigint", "Uint8Array"];
+
+    /// The TypeScript type of the revived value
+    pub fn ts_type(self) -> &'static str {
+        match self {
+            Revive::Date => "Date",
+            Revive::BigInt => "bigint",
+            Revive::Bytes => "Uint8Array",
+        }
+    }
+}
+
+impl TryFrom<String> for Revive {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Ok(match value.as_str() {
+            "Date" => Self::Date,
+            "bigint" => Self::BigInt,
+            "Uint8Array" => Self::Bytes,
+            _ => {
+                let valid = Self::VALID.map(|v| format!("\"{v}\"")).join(", ");
+                match suggest(&value, &Self::VALID) {
+                    Some(suggestion) => syn_err!(
+                        "invalid value \"{}\" for `revive`, did you mean \"{}\"? Valid values are {}",
+                        value,
+                        suggestion,
+                        valid
+                    ),
+                    None => syn_err!(
+                        "invalid value \"{}\" for `revive`. Valid values are {}",
+                        value,
+                        valid
+                    ),
+                }
+            }
+        })
+    }
+}
+
 #[cfg(feature = "serde-compat")]
 #[derive(Default)]
 pub struct SerdeFieldAttr(FieldAttr);
@@ -31,39 +136,120 @@ pub struct SerdeFieldAttr(FieldAttr);
 impl FieldAttr {
     pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut result = Self::default();
-        parse_attrs(attrs)?.for_each(|a| result.merge(a));
+        // a later `#[ts(rename)]` or `#[ts(optional)]` takes precedence, e.g. one selected by
+        // `cfg_attr`, while `#[ts(..)]` always takes precedence over serde
+        for mut attr in parse_attrs::<Self>(attrs)? {
+            result.rename = attr.rename.take().or(result.rename.take());
+            result.optional = attr.optional.take().or(result.optional);
+            result.merge(attr);
+        }
         result.docs = parse_docs(attrs)?;
         #[cfg(feature = "serde-compat")]
         if !result.skip {
-            crate::utils::parse_serde_attrs::<SerdeFieldAttr>(attrs)
-                .for_each(|a| result.merge(a.0));
+            crate::utils::parse_serde_attrs::<SerdeFieldAttr>(attrs).for_each(|mut a| {
+                // `#[ts(type = "..")]` takes precedence over the type given by serde_with
+                if result.type_override.is_some() {
+                    a.0.type_as = None;
+                }
+                result.merge(a.0)
+            });
+        }
+        #[cfg(feature = "validator-interop")]
+        {
+            let tags = crate::utils::parse_validation_attrs(attrs)?;
+            if !tags.is_empty() {
+                crate::utils::append_doc_tags(&mut result.docs, &tags.join("\n"));
+            }
         }
+        result.check_uses()?;
+        result.check_literal()?;
         Ok(result)
     }
 
+    // the type of a field declared as a literal is given by its value
+    fn check_literal(&self) -> Result<()> {
+        if self.literal.is_none() {
+            return Ok(());
+        }
+        match () {
+            _ if self.type_override.is_some() => {
+                syn_err!("`literal` is not compatible with `type`")
+            }
+            _ if self.type_as.is_some() => syn_err!("`literal` is not compatible with `as`"),
+            _ if self.inline => syn_err!("`literal` is not compatible with `inline`"),
+            _ if self.flatten => syn_err!("`literal` is not compatible with `flatten`"),
+            _ if self.revive.is_some() => syn_err!("`literal` is not compatible with `revive`"),
+            _ if self.large_integers.is_some() => {
+                syn_err!("`literal` is not compatible with `large_integers`")
+            }
+            _ if self.pointer_sized_integers.is_some() => {
+                syn_err!("`literal` is not compatible with `pointer_sized_integers`")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // types given by `uses` are only imported if they appear in the `type` override
+    fn check_uses(&self) -> Result<()> {
+        let type_override = match (&self.type_override, self.uses.is_empty()) {
+            (_, true) => return Ok(()),
+            (None, false) => syn_err!("`uses` requires `type`"),
+            (Some(type_override), false) => type_override,
+        };
+        let idents = type_override
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+            .collect::<Vec<_>>();
+        for ty in &self.uses {
+            let ident = match ty {
+                Type::Path(path) => &path.path.segments.last().unwrap().ident,
+                _ => syn_err!(ty.span(); "expected a path to a type"),
+            };
+            if !idents.contains(&ident.to_string().as_str()) {
+                record_warning(format!(
+                    "`{}` is declared in `uses`, but is not referenced in `#[ts(type = \"{}\")]`",
+                    ident, type_override
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn merge(
         &mut self,
         FieldAttr {
             type_as,
             type_override,
+            uses,
+            literal,
+            large_integers,
+            pointer_sized_integers,
             rename,
+            rename_all,
             inline,
             skip,
-            optional: Optional { optional, nullable },
+            optional,
             flatten,
+            readonly,
+            revive,
+            directional,
             docs,
         }: FieldAttr,
     ) {
         self.rename = self.rename.take().or(rename);
+        self.rename_all = self.rename_all.take().or(rename_all);
         self.type_as = self.type_as.take().or(type_as);
         self.type_override = self.type_override.take().or(type_override);
+        self.uses.extend(uses);
+        self.literal = self.literal.take().or(literal);
+        self.large_integers = self.large_integers.or(large_integers);
+        self.pointer_sized_integers = self.pointer_sized_integers.or(pointer_sized_integers);
         self.inline = self.inline || inline;
         self.skip = self.skip || skip;
-        self.optional = Optional {
-            optional: self.optional.optional || optional,
-            nullable: self.optional.nullable || nullable,
-        };
+        self.optional = self.optional.or(optional);
         self.flatten |= flatten;
+        self.readonly |= readonly;
+        self.revive = self.revive.take().or(revive);
+        self.directional.merge(directional);
         self.docs.push_str(&docs);
     }
 }
@@ -72,26 +258,64 @@ impl_parse! {
     FieldAttr(input, out) {
         "as" => out.type_as = Some(parse_assign_str(input)?),
         "type" => out.type_override = Some(parse_assign_str(input)?),
+        "uses" => out.uses.extend(parse_types(input)?),
+        "literal" => out.literal = Some(parse_literal(input)?),
+        "large_integers" => {
+            let ts_type = parse_assign_str(input)?;
+            match crate::config::integer_type(&ts_type) {
+                Ok(ts_type) => out.large_integers = Some(ts_type),
+                Err(err) => syn_err!("invalid `large_integers`: {}", err),
+            }
+        },
+        "pointer_sized_integers" => {
+            let ts_type = parse_assign_str(input)?;
+            match crate::config::integer_type(&ts_type) {
+                Ok(ts_type) => out.pointer_sized_integers = Some(ts_type),
+                Err(err) => syn_err!("invalid `pointer_sized_integers`: {}", err),
+            }
+        },
         "rename" => out.rename = Some(parse_assign_str(input)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
         "inline" => out.inline = true,
         "skip" => out.skip = true,
         "optional" => {
-          use syn::{Token, Error};
-            let nullable = if input.peek(Token![=]) {
+          use syn::{Token, Error, LitBool};
+            let (optional, nullable) = if input.peek(Token![=]) {
                 input.parse::<Token![=]>()?;
-                match Ident::parse(input)?.to_string().as_str() {
-                    "nullable" => true,
-                    other => Err(Error::new(other.span(), "expected 'nullable'"))?
+                if input.peek(LitBool) {
+                    (input.parse::<LitBool>()?.value, false)
+                } else {
+                    match Ident::parse(input)?.to_string().as_str() {
+                        "nullable" => (true, true),
+                        other => Err(Error::new(other.span(), "expected 'nullable', 'true' or 'false'"))?
+                    }
                 }
             } else {
-                false
+                (true, false)
             };
-            out.optional = Optional {
-                optional: true,
+            out.optional = Some(Optional {
+                optional,
                 nullable,
-            }
+            })
         },
         "flatten" => out.flatten = true,
+        "readonly" => out.readonly = true,
+        "revive" => out.revive = Some(Revive::try_from(parse_assign_str(input)?)?),
+        "skip_serializing" => out.directional.skip_serializing = true,
+        "skip_deserializing" => out.directional.skip_deserializing = true,
+        "as_display" => out.type_as = Some("String".to_owned()),
+    }
+}
+
+// parses `= ".."`, `= 1` or `= true`
+fn parse_literal(input: syn::parse::ParseStream) -> Result<Lit> {
+    input.parse::<syn::Token![=]>()?;
+    match input.parse::<Lit>()? {
+        lit @ (Lit::Str(_) | Lit::Int(_) | Lit::Float(_) | Lit::Bool(_)) => Ok(lit),
+        other => Err(Error::new(
+            other.span(),
+            "expected a string, number or boolean",
+        )),
     }
 }
 
@@ -101,13 +325,156 @@ impl_parse! {
         "rename" => out.0.rename = Some(parse_assign_str(input)?),
         "skip" => out.0.skip = true,
         "flatten" => out.0.flatten = true,
-        // parse #[serde(default)] to not emit a warning
+        "with" => out.0.type_as = Some(with_type(&parse_assign_str(input)?)?),
+        "skip_serializing" => out.0.directional.skip_serializing = true,
+        "skip_serializing_if" => {
+            parse_assign_str(input)?;
+            out.0.directional.skip_serializing_if = true;
+        },
+        "skip_deserializing" => out.0.directional.skip_deserializing = true,
+        // borrowing when deserializing doesn't affect the type
+        "borrow" => {
+            use syn::Token;
+            if input.peek(Token![=]) {
+                parse_assign_str(input)?;
+            }
+        },
         "default" => {
             use syn::Token;
             if input.peek(Token![=]) {
-                input.parse::<Token![=]>()?;
                 parse_assign_str(input)?;
             }
+            out.0.directional.default = true;
         },
     }
 }
+
+/// Returns the type a field annotated with `#[serde(with = "..")]` is serialized as.
+/// Supported are the timestamp modules of chrono, like `chrono::serde::ts_seconds`, the modules
+/// of `time::serde`, and `serde_with::As::<..>`.
+#[cfg(feature = "serde-compat")]
+fn with_type(with: &str) -> Result<String> {
+    let path = syn::parse_str::<syn::Path>(with)?;
+    match chrono_timestamp(&path).or_else(|| time_format(&path)) {
+        Some(ty) => Ok(ty.to_owned()),
+        None => serde_with_type(&path),
+    }
+}
+
+// the type of a field serialized using one of the `ts_*` modules of chrono, which serialize a
+// `DateTime` as a number, e.g. `chrono::serde::ts_milliseconds` or `ts_seconds_option`
+#[cfg(feature = "serde-compat")]
+fn chrono_timestamp(path: &syn::Path) -> Option<&'static str> {
+    let mut segments = path.segments.iter().rev();
+    let module = segments.next()?.ident.to_string();
+    if segments
+        .next()
+        .is_some_and(|parent| parent.ident != "serde")
+    {
+        return None;
+    }
+    let (unit, ty) = match module.strip_prefix("ts_")?.strip_suffix("_option") {
+        Some(unit) => (unit, "Option<f64>"),
+        None => (module.strip_prefix("ts_")?, "f64"),
+    };
+    matches!(
+        unit,
+        "seconds" | "milliseconds" | "microseconds" | "nanoseconds"
+    )
+    .then_some(ty)
+}
+
+// the type of a field serialized using one of the modules of `time::serde`, which serialize a date
+// as a string, e.g. `time::serde::rfc3339`, or as a number, e.g. `time::serde::timestamp`.
+// Their `option` submodules serialize an `Option`, e.g. `time::serde::rfc3339::option`.
+#[cfg(feature = "serde-compat")]
+fn time_format(path: &syn::Path) -> Option<&'static str> {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    let start = segments.iter().position(|segment| {
+        matches!(
+            segment.as_str(),
+            "rfc3339" | "rfc2822" | "iso8601" | "timestamp"
+        )
+    })?;
+    if start > 0 && segments[start - 1] != "serde" {
+        return None;
+    }
+
+    let (format, mut rest) = segments[start..].split_first()?;
+    let timestamp = format == "timestamp";
+    if let (true, [unit, tail @ ..]) = (timestamp, rest) {
+        if matches!(
+            unit.as_str(),
+            "milliseconds" | "microseconds" | "nanoseconds"
+        ) {
+            rest = tail;
+        }
+    }
+    let option = match rest {
+        [] => false,
+        [option] if option == "option" => true,
+        _ => return None,
+    };
+    Some(match (timestamp, option) {
+        (true, false) => "f64",
+        (true, true) => "Option<f64>",
+        (false, false) => "String",
+        (false, true) => "Option<String>",
+    })
+}
+
+// the type of a field annotated with `#[serde(with = "::serde_with::As::<..>")]`, which is how
+// serde_with expands `#[serde_as(as = "..")]`.
+// Only adapters made of `DisplayFromStr`, like `Option<DisplayFromStr>`, are supported, and
+// `DisplayFromStr` is replaced with `String`.
+#[cfg(feature = "serde-compat")]
+fn serde_with_type(path: &syn::Path) -> Result<String> {
+    use quote::ToTokens;
+    use syn::{GenericArgument, PathArguments};
+
+    let mut segments = path.segments.iter().rev();
+    let adapter = match (segments.next(), segments.next()) {
+        (Some(last), Some(krate)) if last.ident == "As" && krate.ident == "serde_with" => {
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) if args.args.len() == 1 => &args.args[0],
+                _ => syn_err!("expected `serde_with::As::<..>`"),
+            }
+        }
+        _ => syn_err!("`with` is only supported for chrono timestamps and `serde_with::As::<..>`"),
+    };
+    match adapter {
+        GenericArgument::Type(ty) => Ok(display_from_str(ty)?.to_token_stream().to_string()),
+        _ => syn_err!("expected a type"),
+    }
+}
+
+// replaces `DisplayFromStr` in a serde_with adapter, e.g. `Vec<DisplayFromStr>`, with `String`
+#[cfg(feature = "serde-compat")]
+fn display_from_str(ty: &syn::Type) -> Result<syn::Type> {
+    use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+    let mut path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.clone(),
+        _ => syn_err!("only `DisplayFromStr` is supported"),
+    };
+    let last = path.segments.last_mut().unwrap();
+    match &mut last.arguments {
+        PathArguments::None if last.ident == "DisplayFromStr" => {
+            return Ok(syn::parse_quote!(String))
+        }
+        PathArguments::AngleBracketed(args) => {
+            for arg in &mut args.args {
+                match arg {
+                    GenericArgument::Type(ty) => *ty = display_from_str(ty)?,
+                    _ => syn_err!("only `DisplayFromStr` is supported"),
+                }
+            }
+        }
+        _ => syn_err!("only `DisplayFromStr` is supported"),
+    }
+    Ok(Type::Path(TypePath { qself: None, path }))
+}
@@ -1,14 +1,18 @@
 use std::convert::TryFrom;
 
+pub use consts::*;
 pub use field::*;
+use proc_macro2::TokenStream;
 pub use r#enum::*;
 pub use r#struct::*;
 use syn::{
-    parse::{Parse, ParseStream},
-    Error, Lit, Result, Token,
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    Error, Lit, LitStr, Result, Token, Type, WherePredicate,
 };
 pub use variant::*;
 
+mod consts;
 mod r#enum;
 mod field;
 mod r#struct;
@@ -23,42 +27,39 @@ pub enum Inflection {
     Pascal,
     ScreamingSnake,
     Kebab,
+    ScreamingKebab,
 }
 
 impl Inflection {
-    pub fn apply(self, string: &str) -> String {
-        use inflector::Inflector;
+    /// All accepted spellings, as documented by serde.
+    const VALID: [&'static str; 8] = [
+        "lowercase",
+        "UPPERCASE",
+        "PascalCase",
+        "camelCase",
+        "snake_case",
+        "SCREAMING_SNAKE_CASE",
+        "kebab-case",
+        "SCREAMING-KEBAB-CASE",
+    ];
 
+    /// The canonical spelling of this inflection, as documented by serde.
+    pub fn as_str(self) -> &'static str {
         match self {
-            Inflection::Lower => string.to_lowercase(),
-            Inflection::Upper => string.to_uppercase(),
-            Inflection::Camel => {
-                let pascal = Inflection::apply(Inflection::Pascal, string);
-                pascal[..1].to_ascii_lowercase() + &pascal[1..]
-            }
-            Inflection::Snake => string.to_snake_case(),
-            Inflection::Pascal => {
-                let mut s = String::with_capacity(string.len());
-
-                let mut capitalize = true;
-                for c in string.chars() {
-                    if c == '_' {
-                        capitalize = true;
-                        continue;
-                    } else if capitalize {
-                        s.push(c.to_ascii_uppercase());
-                        capitalize = false;
-                    } else {
-                        s.push(c)
-                    }
-                }
-
-                s
-            }
-            Inflection::ScreamingSnake => string.to_screaming_snake_case(),
-            Inflection::Kebab => string.to_kebab_case(),
+            Inflection::Lower => "lowercase",
+            Inflection::Upper => "UPPERCASE",
+            Inflection::Camel => "camelCase",
+            Inflection::Snake => "snake_case",
+            Inflection::Pascal => "PascalCase",
+            Inflection::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Inflection::Kebab => "kebab-case",
+            Inflection::ScreamingKebab => "SCREAMING-KEBAB-CASE",
         }
     }
+
+    pub fn apply(self, string: &str) -> String {
+        ts_rs_config::convert_case(self.as_str(), string).expect("invalid inflection")
+    }
 }
 
 impl TryFrom<String> for Inflection {
@@ -73,11 +74,110 @@ impl TryFrom<String> for Inflection {
             "pascalcase" => Self::Pascal,
             "screamingsnakecase" => Self::ScreamingSnake,
             "kebabcase" => Self::Kebab,
-            _ => syn_err!("invalid inflection: '{}'", value),
+            "screamingkebabcase" => Self::ScreamingKebab,
+            _ => {
+                let valid = Self::VALID.map(|v| format!("\"{v}\"")).join(", ");
+                match suggest(&value, &Self::VALID) {
+                    Some(suggestion) => syn_err!(
+                        "invalid inflection \"{}\", did you mean \"{}\"? Valid values are {}",
+                        value,
+                        suggestion,
+                        valid
+                    ),
+                    None => syn_err!(
+                        "invalid inflection \"{}\". Valid values are {}",
+                        value,
+                        valid
+                    ),
+                }
+            }
         })
     }
 }
 
+/// Placeholders in the path given by `export_to`, and the case of the name of the type they're
+/// replaced by.
+const PATH_PLACEHOLDERS: [(&str, Option<Inflection>); 9] = [
+    ("{name}", None),
+    ("{lower_name}", Some(Inflection::Lower)),
+    ("{upper_name}", Some(Inflection::Upper)),
+    ("{camel_name}", Some(Inflection::Camel)),
+    ("{snake_name}", Some(Inflection::Snake)),
+    ("{pascal_name}", Some(Inflection::Pascal)),
+    ("{screaming_snake_name}", Some(Inflection::ScreamingSnake)),
+    ("{kebab_name}", Some(Inflection::Kebab)),
+    ("{screaming_kebab_name}", Some(Inflection::ScreamingKebab)),
+];
+
+/// Replaces the placeholders in `path`, given by `export_to`, by `name` in the respective case,
+/// e.g. `models/{snake_name}.ts` by `models/user_profile.ts` for `UserProfile`.
+pub fn expand_path(path: &str, name: &str) -> String {
+    PATH_PLACEHOLDERS
+        .iter()
+        .filter(|(placeholder, _)| path.contains(placeholder))
+        .fold(path.to_owned(), |path, (placeholder, case)| {
+            let name = case.map_or_else(|| name.to_owned(), |case| case.apply(name));
+            path.replace(placeholder, &ts_rs_config::sanitize_file_name(&name))
+        })
+}
+
+/// Fails if `path`, given by `export_to`, contains a placeholder which isn't one of
+/// [`PATH_PLACEHOLDERS`].
+pub fn check_path_placeholders(path: &str) -> std::result::Result<(), String> {
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .map_or(rest.len(), |end| start + end + 1);
+        let placeholder = &rest[start..end];
+        let valid = PATH_PLACEHOLDERS.map(|(placeholder, _)| placeholder);
+        if !valid.contains(&placeholder) {
+            let msg = match suggest(placeholder, &valid) {
+                Some(suggestion) => format!(
+                    "unknown placeholder `{}` in \"{}\", did you mean `{}`?",
+                    placeholder, path, suggestion
+                ),
+                None => format!(
+                    "unknown placeholder `{}` in \"{}\". Valid placeholders are {}",
+                    placeholder,
+                    path,
+                    valid.map(|v| format!("`{v}`")).join(", ")
+                ),
+            };
+            return Err(msg);
+        }
+        rest = &rest[end..];
+    }
+    Ok(())
+}
+
+// returns the candidate closest to `value`, if it's reasonably close
+fn suggest<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    candidates
+        .iter()
+        .map(|c| (edit_distance(&value, &c.to_lowercase()), *c))
+        .filter(|(distance, _)| *distance <= 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+// levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitution.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 fn parse_assign_str(input: ParseStream) -> Result<String> {
     input.parse::<Token![=]>()?;
     match Lit::parse(input)? {
@@ -86,6 +186,181 @@ fn parse_assign_str(input: ParseStream) -> Result<String> {
     }
 }
 
+// parses `= ".."`, if present
+fn parse_optional_assign_str(input: ParseStream) -> Result<Option<String>> {
+    match input.peek(Token![=]) {
+        true => parse_assign_str(input).map(Some),
+        false => Ok(None),
+    }
+}
+
+// parses a language level like `= "ES2015"`, returning whether it supports `bigint`
+fn parse_assign_target(input: ParseStream) -> Result<bool> {
+    input.parse::<Token![=]>()?;
+    let lit = input.parse::<LitStr>()?;
+    crate::config::parse_target(&lit.value()).map_err(|err| Error::new(lit.span(), err))
+}
+
 fn parse_assign_inflection(input: ParseStream) -> Result<Inflection> {
-    parse_assign_str(input).and_then(Inflection::try_from)
+    input.parse::<Token![=]>()?;
+    let lit = input.parse::<LitStr>()?;
+    Inflection::try_from(lit.value()).map_err(|err| Error::new(lit.span(), err))
+}
+
+fn parse_cfg(input: ParseStream) -> Result<TokenStream> {
+    let content;
+    syn::parenthesized!(content in input);
+    content.parse()
+}
+
+// parses `= "T: TS, U: Clone"`, which may be empty
+fn parse_bound(input: ParseStream) -> Result<Vec<WherePredicate>> {
+    input.parse::<Token![=]>()?;
+    let lit = input.parse::<LitStr>()?;
+    let predicates = Punctuated::<WherePredicate, Token![,]>::parse_terminated
+        .parse_str(&lit.value())
+        .map_err(|err| Error::new(lit.span(), err))?;
+    Ok(predicates.into_iter().collect())
+}
+
+// parses `= "key => value"`, e.g. `= "string => JsonValue"`
+fn parse_index_signature(input: ParseStream) -> Result<(String, String)> {
+    input.parse::<Token![=]>()?;
+    let lit = input.parse::<LitStr>()?;
+    match lit.value().split_once("=>") {
+        Some((key, value)) if !key.trim().is_empty() && !value.trim().is_empty() => {
+            Ok((key.trim().to_owned(), value.trim().to_owned()))
+        }
+        _ => Err(Error::new(
+            lit.span(),
+            "expected an index signature like \"string => JsonValue\"",
+        )),
+    }
+}
+
+// parses `(T = "..", U = "..")`
+fn parse_generic_assignments(input: ParseStream) -> Result<Vec<(String, String)>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut bounds = Vec::new();
+    while !content.is_empty() {
+        let ident = content.parse::<syn::Ident>()?;
+        bounds.push((ident.to_string(), parse_assign_str(&content)?));
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(bounds)
+}
+
+// parses `(T = SomeType, ..)`
+fn parse_concrete(input: ParseStream) -> Result<Vec<(String, Type)>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut concrete = Vec::new();
+    while !content.is_empty() {
+        let ident = content.parse::<syn::Ident>()?;
+        content.parse::<Token![=]>()?;
+        concrete.push((ident.to_string(), content.parse::<Type>()?));
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(concrete)
+}
+
+// parses a parenthesized list of types, e.g. `(Page<Item>, Item)`
+fn parse_types(input: ParseStream) -> Result<Vec<Type>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let types = syn::punctuated::Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+    Ok(types.into_iter().collect())
+}
+
+/// An additional file a type is exported to, declared by repeating `export_to`.
+#[derive(Clone)]
+pub struct ExportTarget {
+    pub path: String,
+    pub rename: Option<String>,
+}
+
+// parses `= ".."` or `(path = "..", rename = "..")`
+fn parse_export_to(input: ParseStream) -> Result<ExportTarget> {
+    let span = input.span();
+    let target = parse_export_target(input)?;
+    check_path_placeholders(&target.path).map_err(|msg| Error::new(span, msg))?;
+    Ok(target)
+}
+
+fn parse_export_target(input: ParseStream) -> Result<ExportTarget> {
+    if input.peek(Token![=]) {
+        return Ok(ExportTarget {
+            path: parse_assign_str(input)?,
+            rename: None,
+        });
+    }
+
+    let content;
+    syn::parenthesized!(content in input);
+    let (mut path, mut rename) = (None, None);
+    while !content.is_empty() {
+        let key = content.parse::<syn::Ident>()?;
+        match &*key.to_string() {
+            "path" => path = Some(parse_assign_str(&content)?),
+            "rename" => rename = Some(parse_assign_str(&content)?),
+            _ => syn_err!(key.span(); "expected `path` or `rename`"),
+        }
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    match path {
+        Some(path) => Ok(ExportTarget { path, rename }),
+        None => syn_err!(content.span(); "missing `path`"),
+    }
+}
+
+// the first `export_to` is the primary target, which dependent types import from.
+// every following one is exported in addition to it.
+// a renamed first target is kept in `also_export_to` and rejected by `check_export_targets` once
+// all attributes are merged.
+fn add_export_target(
+    export_to: &mut Option<String>,
+    also_export_to: &mut Vec<ExportTarget>,
+    target: ExportTarget,
+) {
+    match export_to {
+        None if also_export_to.is_empty() && target.rename.is_none() => {
+            *export_to = Some(target.path)
+        }
+        _ => also_export_to.push(target),
+    }
+}
+
+// merges the targets of a following `#[ts(..)]` attribute, keeping the order they're declared in
+// across attributes
+fn merge_export_targets(
+    export_to: &mut Option<String>,
+    also_export_to: &mut Vec<ExportTarget>,
+    other: Option<String>,
+    other_also: Vec<ExportTarget>,
+) {
+    if let Some(path) = other {
+        add_export_target(
+            export_to,
+            also_export_to,
+            ExportTarget { path, rename: None },
+        );
+    }
+    for target in other_also {
+        add_export_target(export_to, also_export_to, target);
+    }
+}
+
+fn check_export_targets(export_to: &Option<String>, also_export_to: &[ExportTarget]) -> Result<()> {
+    if export_to.is_none() && !also_export_to.is_empty() {
+        syn_err!("the first `export_to` cannot be renamed, use `rename` instead")
+    }
+    Ok(())
 }
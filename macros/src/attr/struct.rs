@@ -1,9 +1,13 @@
-use std::convert::TryFrom;
-
-use syn::{Attribute, Ident, Result};
+use proc_macro2::TokenStream;
+use syn::{Attribute, Ident, Result, Type, WherePredicate};
 
 use crate::{
-    attr::{parse_assign_str, Inflection, VariantAttr},
+    attr::{
+        add_export_target, check_export_targets, merge_export_targets, parse_assign_inflection,
+        parse_assign_str, parse_assign_target, parse_bound, parse_cfg, parse_concrete,
+        parse_export_to, parse_generic_assignments, parse_index_signature,
+        parse_optional_assign_str, parse_types, EnumAttr, ExportTarget, Inflection, VariantAttr,
+    },
     utils::{parse_attrs, parse_docs},
 };
 
@@ -12,8 +16,50 @@ pub struct StructAttr {
     pub rename_all: Option<Inflection>,
     pub rename: Option<String>,
     pub export_to: Option<String>,
+    pub also_export_to: Vec<ExportTarget>,
+    /// case of the name of the file the type is exported to, overriding `file_names` in
+    /// `ts-rs.toml`
+    pub file_rename_all: Option<Inflection>,
+    /// whether the language level given by `#[ts(target = "..")]` supports `bigint`, overriding
+    /// `target` in `ts-rs.toml`
+    pub target: Option<bool>,
     pub export: bool,
+    pub export_cfg: Option<TokenStream>,
+    pub skip_generics: Vec<String>,
+    pub generic_bounds: Vec<(String, String)>,
+    /// where clause of the impl, replacing the `TS` bounds of the generic parameters, given by
+    /// `#[ts(bound = "..")]`
+    pub bound: Option<Vec<WherePredicate>>,
+    pub generic_docs: Vec<(String, String)>,
+    pub concrete: Vec<(String, Type)>,
     pub tag: Option<String>,
+    pub readonly: bool,
+    /// given by `#[ts(skip_private)]`, skipping the fields which are not `pub`
+    pub skip_private: bool,
+    pub reviver: bool,
+    /// given by `#[ts(as_display)]`, exporting the type as `string`
+    pub as_display: bool,
+    /// type given by `#[ts(as = "..")]`, which the struct is exported as an alias of
+    pub type_as: Option<String>,
+    /// name of the deserialize view, given by `#[ts(dual)]` or `#[ts(dual = "..")]`
+    pub dual: Option<Option<String>>,
+    /// given by `#[serde(default)]`
+    pub default: bool,
+    /// type given by `#[serde(from = "..")]` or `#[serde(try_from = "..")]`
+    pub from: Option<String>,
+    /// type given by `#[serde(into = "..")]`
+    pub into: Option<String>,
+    /// key and value type of an additional index signature, given by
+    /// `#[ts(index_signature = "string => JsonValue")]`
+    pub index_signature: Option<(String, String)>,
+    /// types referenced by `index_signature`, given by `#[ts(uses(..))]`, which are imported
+    pub uses: Vec<Type>,
+    /// set for the variants of enums, which reference the types flattened into them instead of
+    /// inlining them
+    pub flatten_by_reference: bool,
+    /// set for the newtype variants of adjacently tagged enums, whose content may be omitted if
+    /// its field is annotated with `#[ts(optional)]`
+    pub optional_content: bool,
     pub docs: String,
 }
 
@@ -24,13 +70,23 @@ pub struct SerdeStructAttr(StructAttr);
 impl StructAttr {
     pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut result = Self::default();
-        parse_attrs(attrs)?.for_each(|a| result.merge(a));
+        // a later `#[ts(rename)]` takes precedence, e.g. one selected by `cfg_attr`, while
+        // `#[ts(..)]` always takes precedence over serde
+        for mut attr in parse_attrs::<Self>(attrs)? {
+            result.rename = attr.rename.take().or(result.rename.take());
+            result.merge(attr);
+        }
 
         let docs = parse_docs(attrs)?;
         result.docs = docs;
 
         #[cfg(feature = "serde-compat")]
         crate::utils::parse_serde_attrs::<SerdeStructAttr>(attrs).for_each(|a| result.merge(a.0));
+
+        check_export_targets(&result.export_to, &result.also_export_to)?;
+        if result.index_signature.is_none() && !result.uses.is_empty() {
+            syn_err!("`uses` requires `index_signature`");
+        }
         Ok(result)
     }
 
@@ -41,15 +97,63 @@ impl StructAttr {
             rename,
             export,
             export_to,
+            also_export_to,
+            file_rename_all,
+            target,
+            export_cfg,
+            skip_generics,
+            generic_bounds,
+            bound,
+            generic_docs,
+            concrete,
             tag,
+            readonly,
+            skip_private,
+            reviver,
+            as_display,
+            type_as,
+            dual,
+            default,
+            from,
+            into,
+            index_signature,
+            uses,
+            flatten_by_reference,
+            optional_content,
             docs,
         }: StructAttr,
     ) {
         self.rename = self.rename.take().or(rename);
         self.rename_all = self.rename_all.take().or(rename_all);
-        self.export_to = self.export_to.take().or(export_to);
+        merge_export_targets(
+            &mut self.export_to,
+            &mut self.also_export_to,
+            export_to,
+            also_export_to,
+        );
+        self.file_rename_all = self.file_rename_all.take().or(file_rename_all);
+        self.target = self.target.take().or(target);
         self.export = self.export || export;
+        self.export_cfg = self.export_cfg.take().or(export_cfg);
+        self.skip_generics.extend(skip_generics);
+        self.generic_bounds.extend(generic_bounds);
+        self.bound = self.bound.take().or(bound);
+        self.generic_docs.extend(generic_docs);
+        self.concrete.extend(concrete);
         self.tag = self.tag.take().or(tag);
+        self.readonly = self.readonly || readonly;
+        self.skip_private = self.skip_private || skip_private;
+        self.reviver = self.reviver || reviver;
+        self.as_display = self.as_display || as_display;
+        self.type_as = self.type_as.take().or(type_as);
+        self.dual = self.dual.take().or(dual);
+        self.default = self.default || default;
+        self.from = self.from.take().or(from);
+        self.into = self.into.take().or(into);
+        self.index_signature = self.index_signature.take().or(index_signature);
+        self.uses.extend(uses);
+        self.flatten_by_reference |= flatten_by_reference;
+        self.optional_content |= optional_content;
         self.docs = docs;
     }
 }
@@ -69,12 +173,51 @@ impl From<VariantAttr> for StructAttr {
     }
 }
 
+impl From<&EnumAttr> for StructAttr {
+    fn from(attr: &EnumAttr) -> Self {
+        Self {
+            export_to: attr.export_to.clone(),
+            also_export_to: attr.also_export_to.clone(),
+            file_rename_all: attr.file_rename_all,
+            target: attr.target,
+            export: attr.export,
+            export_cfg: attr.export_cfg.clone(),
+            as_display: attr.as_display,
+            from: attr.from.clone(),
+            into: attr.into.clone(),
+            docs: attr.docs.clone(),
+            // only the attributes used when exporting a proxy are converted
+            ..Self::default()
+        }
+    }
+}
+
 impl_parse! {
     StructAttr(input, out) {
         "rename" => out.rename = Some(parse_assign_str(input)?),
-        "rename_all" => out.rename_all = Some(parse_assign_str(input).and_then(Inflection::try_from)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
         "export" => out.export = true,
-        "export_to" => out.export_to = Some(parse_assign_str(input)?)
+        "export_to" => add_export_target(
+            &mut out.export_to,
+            &mut out.also_export_to,
+            parse_export_to(input)?,
+        ),
+        "file_rename_all" => out.file_rename_all = Some(parse_assign_inflection(input)?),
+        "target" => out.target = Some(parse_assign_target(input)?),
+        "cfg" => out.export_cfg = Some(parse_cfg(input)?),
+        "skip_generic" => out.skip_generics.push(parse_assign_str(input)?),
+        "generic_bound" => out.generic_bounds.extend(parse_generic_assignments(input)?),
+        "bound" => out.bound = Some(parse_bound(input)?),
+        "doc_generic" => out.generic_docs.extend(parse_generic_assignments(input)?),
+        "concrete" => out.concrete.extend(parse_concrete(input)?),
+        "readonly" => out.readonly = true,
+        "skip_private" => out.skip_private = true,
+        "reviver" => out.reviver = true,
+        "as_display" => out.as_display = true,
+        "as" => out.type_as = Some(parse_assign_str(input)?),
+        "dual" => out.dual = Some(parse_optional_assign_str(input)?),
+        "index_signature" => out.index_signature = Some(parse_index_signature(input)?),
+        "uses" => out.uses.extend(parse_types(input)?),
     }
 }
 
@@ -82,15 +225,18 @@ impl_parse! {
 impl_parse! {
     SerdeStructAttr(input, out) {
         "rename" => out.0.rename = Some(parse_assign_str(input)?),
-        "rename_all" => out.0.rename_all = Some(parse_assign_str(input).and_then(Inflection::try_from)?),
+        "rename_all" => out.0.rename_all = Some(parse_assign_inflection(input)?),
         "tag" => out.0.tag = Some(parse_assign_str(input)?),
-        // parse #[serde(default)] to not emit a warning
-        "deny_unknown_fields" | "default" => {
+        "from" | "try_from" => out.0.from = Some(parse_assign_str(input)?),
+        "into" => out.0.into = Some(parse_assign_str(input)?),
+        "default" => {
             use syn::Token;
             if input.peek(Token![=]) {
-                input.parse::<Token![=]>()?;
                 parse_assign_str(input)?;
             }
+            out.0.default = true;
         },
+        // parse #[serde(deny_unknown_fields)] to not emit a warning
+        "deny_unknown_fields" => {},
     }
 }
@@ -3,7 +3,7 @@ use syn::{Attribute, Ident, Result};
 use super::EnumAttr;
 use crate::{
     attr::{parse_assign_inflection, parse_assign_str, Inflection},
-    utils::parse_attrs,
+    utils::{parse_attrs, parse_docs},
 };
 
 #[derive(Default)]
@@ -13,6 +13,9 @@ pub struct VariantAttr {
     pub inline: bool,
     pub skip: bool,
     pub untagged: bool,
+    /// additional names the variant is deserialized from
+    pub aliases: Vec<String>,
+    pub docs: String,
 }
 
 #[cfg(feature = "serde-compat")]
@@ -22,16 +25,56 @@ pub struct SerdeVariantAttr(VariantAttr);
 impl VariantAttr {
     pub fn new(attrs: &[Attribute], enum_attr: &EnumAttr) -> Result<Self> {
         let mut result = Self::default();
-        parse_attrs(attrs)?.for_each(|a| result.merge(a));
-        result.rename_all = result.rename_all.or(enum_attr.rename_all_fields);
+        // a later `#[ts(rename)]` takes precedence, e.g. one selected by `cfg_attr`, while
+        // `#[ts(..)]` always takes precedence over serde
+        for mut attr in parse_attrs::<Self>(attrs)? {
+            result.rename = attr.rename.take().or(result.rename.take());
+            result.merge(attr);
+        }
+        result.docs = parse_docs(attrs)?;
         #[cfg(feature = "serde-compat")]
         if !result.skip {
             crate::utils::parse_serde_attrs::<SerdeVariantAttr>(attrs)
                 .for_each(|a| result.merge(a.0));
         }
+        #[cfg(feature = "strum-interop")]
+        if !result.skip {
+            result.merge(Self::from_strum(attrs)?);
+        }
+        // `rename_all` on a variant takes precedence over `rename_all_fields` on the enum
+        result.rename_all = result.rename_all.or(enum_attr.rename_all_fields);
         Ok(result)
     }
 
+    /// Parses the names a variant is displayed as and parsed from, given by
+    /// `#[strum(to_string = "..")]` and `#[strum(serialize = "..")]`.
+    /// Like strum, the variant is renamed to `to_string`, or to the longest `serialize` value if
+    /// there is none. All other values are used as aliases.
+    #[cfg(feature = "strum-interop")]
+    fn from_strum(attrs: &[Attribute]) -> Result<Self> {
+        let mut to_string = None;
+        let mut serialize = Vec::new();
+        crate::utils::parse_strum_attrs(attrs, |key, input| {
+            match key {
+                "to_string" => to_string = Some(parse_assign_str(input)?),
+                "serialize" => serialize.push(parse_assign_str(input)?),
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+
+        if to_string.is_none() {
+            // of multiple values with the same length, strum uses the last one
+            let longest = (0..serialize.len()).max_by_key(|&i| serialize[i].len());
+            to_string = longest.map(|i| serialize.remove(i));
+        }
+        Ok(Self {
+            rename: to_string,
+            aliases: serialize,
+            ..Self::default()
+        })
+    }
+
     fn merge(
         &mut self,
         VariantAttr {
@@ -40,6 +83,8 @@ impl VariantAttr {
             inline,
             skip,
             untagged,
+            aliases,
+            docs,
         }: VariantAttr,
     ) {
         self.rename = self.rename.take().or(rename);
@@ -47,6 +92,8 @@ impl VariantAttr {
         self.inline = self.inline || inline;
         self.skip = self.skip || skip;
         self.untagged = self.untagged || untagged;
+        self.aliases.extend(aliases);
+        self.docs.push_str(&docs);
     }
 }
 
@@ -57,6 +104,7 @@ impl_parse! {
         "inline" => out.inline = true,
         "skip" => out.skip = true,
         "untagged" => out.untagged = true,
+        "alias" => out.aliases.push(parse_assign_str(input)?),
     }
 }
 
@@ -67,5 +115,13 @@ impl_parse! {
         "rename_all" => out.0.rename_all = Some(parse_assign_inflection(input)?),
         "skip" => out.0.skip = true,
         "untagged" => out.0.untagged = true,
+        "alias" => out.0.aliases.push(parse_assign_str(input)?),
+        // borrowing when deserializing doesn't affect the type
+        "borrow" => {
+            use syn::Token;
+            if input.peek(Token![=]) {
+                parse_assign_str(input)?;
+            }
+        },
     }
 }
@@ -0,0 +1,234 @@
+use std::{cell::RefCell, convert::TryFrom, path::PathBuf};
+
+use proc_macro2::Span;
+use syn::{Error, Result};
+use ts_rs_config::Config;
+
+use crate::attr::{self, Inflection, Optional};
+
+/// Crate-wide defaults, read from `ts-rs.toml`
+#[derive(Default, Clone)]
+pub struct Defaults {
+    /// default `rename_all` for the fields of structs and struct variants
+    pub rename_all: Option<Inflection>,
+    /// how `Option<T>` fields without `#[ts(optional)]` are exported
+    pub optional: Optional,
+    /// the TypeScript type of integers exported as `bigint`, if it's not `bigint`
+    pub large_integers: Option<&'static str>,
+    /// whether `large_integers` is set in `ts-rs.toml`, so that it's not overridden by the
+    /// `target` of a type
+    pub large_integers_set: bool,
+    /// the TypeScript type of `usize` and `isize`, if it's not `number`
+    pub pointer_sized_integers: Option<&'static str>,
+    /// whether the range of fields of bounded integer types is documented
+    pub numeric_bounds: bool,
+    /// template the names of types without `#[ts(rename = "..")]` are derived from, containing
+    /// `{name}`
+    pub type_names: Option<String>,
+    /// case of the names of exported files, which are named after the type by default
+    pub file_names: Option<Inflection>,
+    /// path of the file types without `#[ts(export_to = "..")]` are exported to within the export
+    /// directory, containing placeholders like `{snake_name}`
+    pub export_to: Option<String>,
+    /// whether types are exported to subdirectories mirroring the modules they're defined in
+    pub module_dirs: bool,
+    /// the number of variants and the length of a declaration, above which the union of an enum
+    /// is declared on multiple lines
+    pub union_max_members: Option<usize>,
+    pub union_max_width: Option<usize>,
+    /// the type of structs without fields, including structs whose fields are all skipped
+    pub empty_struct: Option<&'static str>,
+    /// whether types are exported to `.d.ts` files as ambient declarations
+    pub ambient: bool,
+}
+
+thread_local! {
+    static DEFAULTS: RefCell<Defaults> = RefCell::new(Defaults::default());
+}
+
+/// Loads `ts-rs.toml` of the crate being compiled, making it available through [`defaults`].
+/// Returns the path of the file if it exists.
+pub fn load() -> Result<Option<PathBuf>> {
+    let config = Config::load().map_err(|err| Error::new(Span::call_site(), format!("{err:#}")))?;
+
+    let large_integers = config
+        .large_integers_type()
+        .map(integer_type)
+        .transpose()
+        .map_err(|err| invalid("large_integers", err))?;
+    let pointer_sized_integers = config
+        .pointer_sized_integers
+        .as_deref()
+        .map(integer_type)
+        .transpose()
+        .map_err(|err| invalid("pointer_sized_integers", err))?;
+    if let Some(target) = config.target.as_deref() {
+        if !parse_target(target).map_err(|err| invalid("target", err))? {
+            let integers = [
+                ("large_integers", large_integers),
+                ("pointer_sized_integers", pointer_sized_integers),
+            ];
+            if let Some((key, _)) = integers.iter().find(|(_, ty)| *ty == Some("bigint")) {
+                let msg = format!("`bigint` is not supported by the `target` \"{}\"", target);
+                return Err(invalid(key, msg));
+            }
+        }
+    }
+    let rename_all = config
+        .rename_all
+        .map(Inflection::try_from)
+        .transpose()
+        .map_err(|err| invalid("rename_all", err))?;
+    let file_names = config
+        .file_names
+        .map(Inflection::try_from)
+        .transpose()
+        .map_err(|err| invalid("file_names", err))?;
+    if let Some(export_to) = config.export_to.as_deref() {
+        attr::check_path_placeholders(export_to).map_err(|err| invalid("export_to", err))?;
+    }
+    if let Some(type_names) = config.type_names.as_deref() {
+        if !type_names.contains("{name}") {
+            let msg = format!(
+                "expected a template containing `{{name}}`, found \"{}\"",
+                type_names
+            );
+            return Err(invalid("type_names", msg));
+        }
+    }
+    let optional = match config.optional.as_deref() {
+        None => Optional::default(),
+        Some("optional") => Optional {
+            optional: true,
+            nullable: false,
+        },
+        Some("nullable") => Optional {
+            optional: true,
+            nullable: true,
+        },
+        Some(other) => {
+            let msg = format!("expected \"optional\" or \"nullable\", found \"{}\"", other);
+            return Err(invalid("optional", msg));
+        }
+    };
+
+    let empty_struct = match config.empty_struct.as_deref() {
+        None => None,
+        Some("Record<string, never>") => Some("Record<string, never>"),
+        Some("Record<PropertyKey, never>") => Some("Record<PropertyKey, never>"),
+        Some(other) => {
+            let msg = format!(
+                "expected \"Record<string, never>\" or \"Record<PropertyKey, never>\", found \"{}\"",
+                other
+            );
+            return Err(invalid("empty_struct", msg));
+        }
+    };
+
+    DEFAULTS.with(|defaults| {
+        *defaults.borrow_mut() = Defaults {
+            rename_all,
+            optional,
+            large_integers: large_integers.filter(|ts_type| *ts_type != "bigint"),
+            large_integers_set: config.large_integers.is_some(),
+            pointer_sized_integers: pointer_sized_integers.filter(|ts_type| *ts_type != "number"),
+            numeric_bounds: config.numeric_bounds,
+            type_names: config.type_names,
+            file_names,
+            export_to: config.export_to,
+            module_dirs: config.module_dirs,
+            union_max_members: config.union_max_members,
+            union_max_width: config.union_max_width,
+            empty_struct,
+            ambient: config.ambient,
+        }
+    });
+    Config::path().map_err(|err| Error::new(Span::call_site(), format!("{err:#}")))
+}
+
+/// Returns the defaults of the crate being compiled, as loaded by [`load`].
+pub fn defaults() -> Defaults {
+    DEFAULTS.with(|defaults| defaults.borrow().clone())
+}
+
+/// Overrides `file_names` for the type being derived, given by `#[ts(file_rename_all = "..")]`.
+/// The defaults are reset when `ts-rs.toml` is loaded for the next type.
+pub fn override_file_names(inflection: Inflection) {
+    DEFAULTS.with(|defaults| defaults.borrow_mut().file_names = Some(inflection));
+}
+
+/// Overrides the `target` for the type being derived, given by `#[ts(target = "..")]` as whether it
+/// supports `bigint`. Unless `large_integers` is set in `ts-rs.toml`, integers are exported as
+/// `string` instead of `bigint` if it doesn't.
+pub fn override_target(bigint: bool) {
+    DEFAULTS.with(|defaults| {
+        let mut defaults = defaults.borrow_mut();
+        if !defaults.large_integers_set {
+            defaults.large_integers = (!bigint).then_some("string");
+        }
+    });
+}
+
+/// Runs `f` with `large_integers` and `pointer_sized_integers` set to the given types, which is how
+/// they're overridden for a single field. `None` keeps the default of the crate.
+pub fn with_integer_types<T>(
+    large_integers: Option<&'static str>,
+    pointer_sized_integers: Option<&'static str>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let previous = DEFAULTS.with(|defaults| {
+        let mut defaults = defaults.borrow_mut();
+        let previous = (
+            defaults.large_integers,
+            defaults.large_integers_set,
+            defaults.pointer_sized_integers,
+        );
+        if let Some(ts_type) = large_integers {
+            defaults.large_integers = Some(ts_type).filter(|ts_type| *ts_type != "bigint");
+            defaults.large_integers_set = true;
+        }
+        if let Some(ts_type) = pointer_sized_integers {
+            defaults.pointer_sized_integers = Some(ts_type).filter(|ts_type| *ts_type != "number");
+        }
+        previous
+    });
+    let result = f();
+    DEFAULTS.with(|defaults| {
+        let mut defaults = defaults.borrow_mut();
+        (
+            defaults.large_integers,
+            defaults.large_integers_set,
+            defaults.pointer_sized_integers,
+        ) = previous;
+    });
+    result
+}
+
+/// Parses the TypeScript type an integer is exported as, which is either "bigint", "number" or
+/// "string".
+pub fn integer_type(ts_type: &str) -> std::result::Result<&'static str, String> {
+    match ts_type {
+        "bigint" => Ok("bigint"),
+        "number" => Ok("number"),
+        "string" => Ok("string"),
+        other => Err(format!(
+            "expected \"bigint\", \"number\" or \"string\", found \"{}\"",
+            other
+        )),
+    }
+}
+
+/// Parses a language level like "ES2015" or "ES2020", returning whether it supports `bigint`.
+pub fn parse_target(target: &str) -> std::result::Result<bool, String> {
+    ts_rs_config::supports_bigint(target).ok_or_else(|| {
+        format!(
+            "expected a language level like \"ES2015\" or \"ES2020\", found \"{}\"",
+            target
+        )
+    })
+}
+
+fn invalid(key: &str, err: impl std::fmt::Display) -> Error {
+    let msg = format!("invalid `{}` in {}: {}", key, Config::FILE_NAME, err);
+    Error::new(Span::call_site(), msg)
+}
@@ -0,0 +1,107 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Attribute, Item, Result,
+};
+
+use crate::{
+    attr::ConstsAttr,
+    config, export_entry, file_name, test_hash,
+    utils::{invalid_ts_name, parse_docs, to_ts_ident},
+};
+
+/// The input of `export_consts!`: inner attributes, followed by `const` and `static` items
+pub(crate) struct Consts {
+    attrs: Vec<Attribute>,
+    items: Vec<Item>,
+}
+
+impl Parse for Consts {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_inner)?;
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            match input.parse::<Item>()? {
+                item @ (Item::Const(_) | Item::Static(_)) => items.push(item),
+                other => syn_err!(other.span(); "expected a `const` or `static` item"),
+            }
+        }
+        Ok(Self { attrs, items })
+    }
+}
+
+impl Consts {
+    /// Emits the items unchanged, followed by a test exporting them to a single file, see
+    /// [`export_entry`].
+    pub(crate) fn into_export(self) -> Result<TokenStream> {
+        let attr = ConstsAttr::from_attrs(&self.attrs)?;
+        let mut consts = Vec::new();
+        let mut names = Vec::new();
+        for item in &self.items {
+            let (attrs, ident) = match item {
+                Item::Const(item) => (&item.attrs, &item.ident),
+                Item::Static(item) => (&item.attrs, &item.ident),
+                _ => unreachable!(),
+            };
+            let docs = parse_docs(attrs)?;
+            let name = to_ts_ident(ident);
+            if let Some(reason) = invalid_ts_name(&name, false) {
+                syn_err!(ident.span(); "`{}` {}, so it cannot be exported", name, reason);
+            }
+            consts.push(quote!((#name, #docs, &#ident as &dyn ts_rs::__private::ConstValue)));
+            names.push(name);
+        }
+
+        // like types, constants are exported to `export_dir` unless `export_to` is given
+        let export_to = match &attr.export_to {
+            Some(dirname) if dirname.ends_with('/') => {
+                let path = format!("{}{}", dirname, file_name("constants"));
+                quote!(Some(#path.to_owned()))
+            }
+            Some(filename) => quote!(Some(#filename.to_owned())),
+            None => {
+                let file_name = file_name("constants");
+                let path = match config::defaults().module_dirs {
+                    true => quote!(&ts_rs::__private::module_file(module_path!(), #file_name)),
+                    false => quote!(#file_name),
+                };
+                quote!(ts_rs::__private::get_export_to_path(#path))
+            }
+        };
+
+        let stem = attr
+            .export_to
+            .as_deref()
+            .and_then(|path| path.rsplit('/').next())
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or("constants")
+            .trim_end_matches(".ts")
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+            .to_lowercase();
+        // like the tests exporting types, the name is suffixed with a hash, here of the path and
+        // the names of the constants, which are unique within the module, so that the tests of
+        // several `export_consts!` in one module don't collide, e.g. for `a/constants.ts` and
+        // `b/constants.ts`
+        let key = format!(
+            "{}:{}",
+            attr.export_to.as_deref().unwrap_or(""),
+            names.join(",")
+        );
+        let test_fn = format_ident!("export_consts_{}_{:08x}", stem, test_hash(&key));
+        let export = export_entry(
+            &test_fn,
+            attr.export_cfg.as_ref(),
+            quote!(|| ts_rs::__private::export_consts(&|| #export_to, &[#(#consts),*])),
+            "could not export constants",
+        );
+
+        let items = &self.items;
+        Ok(quote! {
+            #(#items)*
+
+            #export
+        })
+    }
+}
@@ -1,34 +1,93 @@
+use std::collections::HashSet;
+
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::Type;
+use syn::{visit_mut::VisitMut, Lifetime, Type};
 
+/// The dependencies of a type, which are deduplicated when they are added.
 #[derive(Default)]
-pub struct Dependencies(Vec<TokenStream>);
+pub struct Dependencies {
+    dependencies: Vec<Dependency>,
+    // the dependencies added so far, as `(is_push, type)`
+    seen: HashSet<(bool, String)>,
+}
+
+enum Dependency {
+    // a type which is itself a dependency
+    Push(Type),
+    // a type whose dependencies are dependencies
+    Append(Type),
+}
 
 impl Dependencies {
     /// Adds all dependencies from the given type
     pub fn append_from(&mut self, ty: &Type) {
-        self.0
-            .push(quote![.extend(<#ty as ts_rs::TS>::dependency_types())]);
+        self.add(Dependency::Append(ty.clone()));
     }
 
     /// Adds the given type if it's *not* transparent.
     /// If it is, all it's child dependencies are added instead.
     pub fn push_or_append_from(&mut self, ty: &Type) {
-        self.0.push(quote![.push::<#ty>()]);
+        self.add(Dependency::Push(ty.clone()));
     }
 
     pub fn append(&mut self, other: Dependencies) {
-        self.0.push(quote![.extend(#other)]);
+        for dependency in other.dependencies {
+            self.add(dependency);
+        }
+    }
+
+    fn add(&mut self, dependency: Dependency) {
+        let key = match &dependency {
+            Dependency::Push(ty) => (true, key(ty)),
+            Dependency::Append(ty) => (false, key(ty)),
+        };
+        if self.seen.insert(key) {
+            self.dependencies.push(dependency);
+        }
+    }
+}
+
+// Types are deduplicated using their tokens, ignoring lifetimes, since e.g. `&'a T` and `&'b T`
+// have the same dependencies.
+fn key(ty: &Type) -> String {
+    struct EraseLifetimes;
+    impl VisitMut for EraseLifetimes {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            *lifetime = Lifetime::new("'_", lifetime.span());
+        }
+    }
+
+    let mut ty = ty.clone();
+    EraseLifetimes.visit_type_mut(&mut ty);
+    ty.to_token_stream().to_string()
+}
+
+impl ToTokens for Dependency {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Dependency::Push(ty) => quote!((std::marker::PhantomData::<#ty>,)),
+            Dependency::Append(ty) => quote!(<#ty as ts_rs::TS>::dependency_types()),
+        })
+    }
+}
+
+// Builds a balanced tree of nested `TypeList`s, so the depth of the resulting type only grows
+// logarithmically with the number of dependencies.
+fn type_list(dependencies: &[Dependency]) -> TokenStream {
+    match dependencies {
+        [] => quote!(()),
+        [dependency] => dependency.to_token_stream(),
+        _ => {
+            let (left, right) = dependencies.split_at(dependencies.len() / 2);
+            let (left, right) = (type_list(left), type_list(right));
+            quote!((#left, #right))
+        }
     }
 }
 
 impl ToTokens for Dependencies {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let lines = &self.0;
-        tokens.extend(quote![{
-            use ts_rs::typelist::TypeList;
-            ()#(#lines)*
-        }])
+        tokens.extend(type_list(&self.dependencies))
     }
 }
@@ -1,19 +1,23 @@
 #![macro_use]
 #![deny(unused)]
+#![cfg_attr(ts_rs_tracked_path, feature(proc_macro_tracked_path))]
 
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
-    parse_quote, spanned::Spanned, ConstParam, GenericParam, Generics, Item, LifetimeParam, Result,
-    TypeParam, WhereClause,
+    parse_quote, spanned::Spanned, ConstParam, GenericParam, Generics, Item, LifetimeParam,
+    PredicateType, Result, TypeParam, WhereClause, WherePredicate,
 };
 
-use crate::deps::Dependencies;
+use crate::{attr::ExportTarget, deps::Dependencies};
 
 #[macro_use]
 mod utils;
 mod attr;
+mod config;
+mod consts;
 mod deps;
+mod module;
 mod types;
 
 struct DerivedTS {
@@ -22,15 +26,81 @@ struct DerivedTS {
     inline: TokenStream,
     decl: TokenStream,
     inline_flattened: Option<TokenStream>,
+    /// expression evaluating to the JSON schema of the type, see `TS::json_schema`
+    json_schema: TokenStream,
+    /// additional declarations exported alongside `decl`, each evaluating to a `String`
+    extra_decls: Vec<TokenStream>,
+    /// expression evaluating to the keys of the type and of every type flattened into it, as
+    /// `(name, keys)`, which are checked for conflicts when exporting
+    flattened_keys: Option<TokenStream>,
+    /// expression evaluating to the `TS::KIND` of the type
+    kind: TokenStream,
     dependencies: Dependencies,
 
     export: bool,
     export_to: Option<String>,
+    /// additional files the type is exported to, see `TS::ALSO_EXPORT_TO`
+    also_export_to: Vec<ExportTarget>,
+    export_cfg: Option<TokenStream>,
+}
+
+/// The name of the test exporting `rust_ty`, e.g. `export_bindings_user_id_409cc53f` for `UserId`.
+/// It's derived from the Rust identifier, which is unique within a module unlike the exported
+/// name, and suffixed with a hash of it, so it neither collides with the tests of types whose names
+/// only differ in case nor with functions of the user.
+fn export_test_name(rust_ty: &Ident) -> Ident {
+    let ident = utils::to_ts_ident(rust_ty);
+    let snake_case = attr::Inflection::Snake.apply(&ident);
+    format_ident!("export_bindings_{}_{:08x}", snake_case, test_hash(&ident))
+}
+
+// FNV-1a, which is stable across compiler versions unlike `DefaultHasher`
+fn test_hash(input: &str) -> u32 {
+    input.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+/// Emits a test named `test_fn` calling `export`, a function exporting bindings. If the
+/// `export-all` feature is enabled, `export` is also registered to be called by
+/// `ts_rs::export_all`.
+/// The export is only compiled if `export_cfg`, the predicate of `#[ts(cfg(..))]`, holds.
+fn export_entry(
+    test_fn: &Ident,
+    export_cfg: Option<&TokenStream>,
+    export: TokenStream,
+    message: &str,
+) -> TokenStream {
+    let registration = cfg!(feature = "export-all").then(|| {
+        let cfg = export_cfg.map(|predicate| quote!(#[cfg(#predicate)]));
+        quote! {
+            #cfg
+            const _: () = {
+                #[allow(deprecated)]
+                const EXPORT: ts_rs::__private::Export = ts_rs::__private::Export::new(#export);
+                ts_rs::__private::inventory::submit!(EXPORT);
+            };
+        }
+    });
+
+    let cfg = match export_cfg {
+        Some(predicate) => quote!(#[cfg(all(test, #predicate))]),
+        None => quote!(#[cfg(test)]),
+    };
+    quote! {
+        #cfg
+        #[test]
+        #[allow(deprecated)]
+        fn #test_fn() {
+            (#export)().expect(#message);
+        }
+        #registration
+    }
 }
 
 impl DerivedTS {
-    fn generate_export_test(&self, rust_ty: &Ident, generics: &Generics) -> Option<TokenStream> {
-        let test_fn = format_ident!("export_bindings_{}", &self.name.to_lowercase());
+    fn generate_export_test(&self, rust_ty: &Ident, generics: &Generics) -> TokenStream {
+        let test_fn = export_test_name(rust_ty);
         let generic_params = generics
             .params
             .iter()
@@ -38,57 +108,115 @@ impl DerivedTS {
             .map(|_| quote! { () });
         let ty = quote!(<#rust_ty<#(#generic_params),*> as ts_rs::TS>);
 
-        Some(quote! {
-            #[cfg(test)]
-            #[test]
-            fn #test_fn() {
-                #ty::export().expect("could not export type");
-            }
-        })
+        export_entry(
+            &test_fn,
+            self.export_cfg.as_ref(),
+            quote!(#ty::export),
+            "could not export type",
+        )
     }
 
-    fn into_impl(self, rust_ty: Ident, generics: Generics) -> TokenStream {
+    fn into_impl(
+        self,
+        rust_ty: Ident,
+        generics: Generics,
+        warnings: Vec<String>,
+        type_overrides: Vec<TokenStream>,
+    ) -> TokenStream {
         let mut get_export_to = quote! {};
         let export_to = match &self.export_to {
             Some(dirname) if dirname.ends_with('/') => {
-                format!("{}{}.ts", dirname, self.name)
+                let dirname = attr::expand_path(dirname, &self.name);
+                format!("{}{}", dirname, file_name(&self.name))
             }
-            Some(filename) => filename.clone(),
+            Some(filename) => attr::expand_path(filename, &self.name),
             None => {
+                let file_name = match config::defaults().export_to {
+                    Some(path) => attr::expand_path(&path, &self.name),
+                    None => file_name(&self.name),
+                };
+                // the module path is only known to the generated code
+                let path = match config::defaults().module_dirs {
+                    true => quote!(&ts_rs::__private::module_file(module_path!(), #file_name)),
+                    false => quote!(#file_name),
+                };
                 get_export_to = quote! {
                     fn get_export_to() -> Option<String> {
-                        ts_rs::__private::get_export_to_path::<Self>()
+                        ts_rs::__private::get_export_to_path(#path)
                     }
                 };
-                format!("bindings/{}.ts", self.name)
+                format!("bindings/{}", file_name)
             }
         };
 
+        let also_export_to = self.also_export_to.iter().map(|target| {
+            let name = target.rename.as_deref().unwrap_or(&self.name);
+            let path = attr::expand_path(&target.path, name);
+            let path = match path.ends_with('/') {
+                true => format!("{}{}", path, file_name(name)),
+                false => path,
+            };
+            quote!((#path, #name))
+        });
+        let also_export_to = match self.also_export_to.is_empty() {
+            true => None,
+            false => Some(quote! {
+                const ALSO_EXPORT_TO: &'static [(&'static str, &'static str)] = &[#(#also_export_to),*];
+            }),
+        };
+
         let export = match self.export {
             true => Some(self.generate_export_test(&rust_ty, &generics)),
             false => None,
         };
 
+        // with `export-all`, types are registered so `#[ts_rs::export_module]` can export glob
+        // imports
+        let declared = (cfg!(feature = "export-all") && generics.params.is_empty()).then(|| {
+            quote! {
+                const _: () = {
+                    #[allow(deprecated)]
+                    const DECLARED: ts_rs::__private::Declared =
+                        ts_rs::__private::Declared::new(module_path!(), <#rust_ty as ts_rs::TS>::export);
+                    ts_rs::__private::inventory::submit!(DECLARED);
+                };
+            }
+        });
+
         let DerivedTS {
             name,
             docs,
             inline,
             decl,
             inline_flattened,
+            json_schema,
+            extra_decls,
+            flattened_keys,
+            kind,
             dependencies,
             ..
         } = self;
 
+        let json_schema = cfg!(feature = "json-schema").then(|| {
+            quote! {
+                fn json_schema() -> String {
+                    #json_schema
+                }
+                fn json_schema_ref() -> String {
+                    ts_rs::__private::json_schema_ref::<Self>()
+                }
+            }
+        });
+
         let docs = match docs.is_empty() {
             true => None,
-            false => {
-                Some(quote!(const DOCS: Option<&'static str> = Some(#docs);))
-            }
+            false => Some(quote!(const DOCS: Option<&'static str> = Some(#docs);)),
         };
 
         let inline_flattened = inline_flattened
             .map(|t| {
                 quote! {
+                    const FLATTENABLE: bool = true;
                     fn inline_flattened() -> String {
                         #t
                     }
@@ -96,10 +224,54 @@ impl DerivedTS {
             })
             .unwrap_or_else(TokenStream::new);
 
+        let extra_decls = match extra_decls.is_empty() {
+            true => None,
+            false => Some(quote! {
+                fn extra_decls() -> Vec<String> {
+                    vec![#(#extra_decls),*]
+                }
+            }),
+        };
+
+        let check_flatten = flattened_keys.map(|keys| {
+            quote! {
+                fn check_flatten() -> Result<(), ts_rs::ExportError> {
+                    ts_rs::__private::check_flatten(&Self::name(), #keys)
+                }
+            }
+        });
+
+        let warnings = match warnings.is_empty() {
+            true => None,
+            false => Some(quote! {
+                fn warnings() -> Vec<String> {
+                    vec![#(#warnings.to_owned()),*]
+                }
+            }),
+        };
+
+        let type_overrides = match type_overrides.is_empty() {
+            true => None,
+            false => Some(quote! {
+                fn type_overrides() -> Vec<ts_rs::__private::TypeOverride> {
+                    vec![#(#type_overrides),*]
+                }
+            }),
+        };
+
+        let decl_concrete = generate_decl_concrete(&rust_ty, &generics, &name);
+
+        // `file!()` and `line!()` resolve to the location of the type's identifier
+        let source_location = quote_spanned!(rust_ty.span()=> concat!(file!(), ":", line!()));
+
+        let name_with_type_args = generate_name_with_type_args(&name, &generics);
         let impl_start = generate_impl(&rust_ty, &generics);
         quote! {
             #impl_start {
                 const EXPORT_TO: Option<&'static str> = Some(#export_to);
+                #also_export_to
+                const SOURCE_LOCATION: Option<&'static str> = Some(#source_location);
+                const KIND: ts_rs::TypeKind = #kind;
                 #get_export_to
 
                 #docs
@@ -107,13 +279,20 @@ impl DerivedTS {
                 fn decl() -> String {
                     #decl
                 }
+                #decl_concrete
                 fn name() -> String {
                     #name.to_owned()
                 }
+                #name_with_type_args
                 fn inline() -> String {
                     #inline
                 }
                 #inline_flattened
+                #extra_decls
+                #check_flatten
+                #warnings
+                #type_overrides
+                #json_schema
 
                 #[allow(clippy::unused_unit)]
                 fn dependency_types() -> impl ts_rs::typelist::TypeList
@@ -129,8 +308,114 @@ impl DerivedTS {
             }
 
             #export
+            #declared
+        }
+    }
+}
+
+// name of the file a type is exported to if only a directory is given, in the case configured by
+// `file_names` in `ts-rs.toml`, without characters which are invalid in file names
+fn file_name(ts_name: &str) -> String {
+    let name = match config::defaults().file_names {
+        Some(inflection) => inflection.apply(ts_name),
+        None => ts_name.to_owned(),
+    };
+    let extension = match config::defaults().ambient {
+        true => "d.ts",
+        false => "ts",
+    };
+    format!("{}.{}", ts_rs_config::sanitize_file_name(&name), extension)
+}
+
+// type arguments in the position of a generic parameter annotated with `#[ts(skip)]` are omitted
+fn generate_name_with_type_args(name: &str, generics: &Generics) -> Option<TokenStream> {
+    let skipped = generics
+        .type_params()
+        .enumerate()
+        .filter(|(_, param)| types::is_skipped(param))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    if skipped.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn name_with_type_args(args: Vec<String>) -> String {
+            let args = args
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| ![#(#skipped),*].contains(idx))
+                .map(|(_, arg)| arg)
+                .collect::<Vec<_>>();
+            match args.is_empty() {
+                true => #name.to_owned(),
+                false => format!("{}<{}>", #name, args.join(", ")),
+            }
         }
+    })
+}
+
+// generates `TS::decl_concrete` for generic types, substituting the generic parameters in the
+// definition of the type, which is rendered with `()` as their arguments, by the references to
+// the type arguments. Parameters with bounds are rendered by their name anyways.
+// `TS::reference` is generated alongside it, so that generic types used as type arguments are
+// referred to with their own type arguments.
+fn generate_decl_concrete(ty: &Ident, generics: &Generics, name: &str) -> Option<TokenStream> {
+    use GenericParam::*;
+
+    let substituted = generics
+        .type_params()
+        .filter(|param| !types::is_skipped(param) && !types::is_unbounded(param))
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    if substituted.is_empty() {
+        return None;
     }
+
+    let bounded_in_where_clause = |ident: &Ident| {
+        let predicates = generics.where_clause.iter().flat_map(|w| &w.predicates);
+        predicates.into_iter().any(|predicate| match predicate {
+            WherePredicate::Type(PredicateType {
+                bounded_ty: syn::Type::Path(path),
+                ..
+            }) => path.path.is_ident(ident),
+            _ => false,
+        })
+    };
+    let generic_args = generics.params.iter().map(|param| match param {
+        Type(TypeParam { ident, bounds, .. })
+            if substituted.contains(&ident)
+                && bounds.is_empty()
+                && !bounded_in_where_clause(ident) =>
+        {
+            quote!(())
+        }
+        Type(TypeParam { ident, .. }) | Const(ConstParam { ident, .. }) => quote!(#ident),
+        Lifetime(LifetimeParam { lifetime, .. }) => quote!(#lifetime),
+    });
+    let params = substituted.iter().map(|ident| ident.to_string());
+    // skipped and unbounded parameters don't implement `TS`
+    let type_args = generics.type_params().map(|param| {
+        let ident = &param.ident;
+        let ident_str = ident.to_string();
+        match substituted.contains(&ident) {
+            true => quote!(<#ident as ts_rs::TS>::reference()),
+            false => quote!(#ident_str.to_owned()),
+        }
+    });
+
+    Some(quote! {
+        fn decl_concrete() -> String {
+            ts_rs::__private::concrete_decl(
+                #name,
+                &<#ty<#(#generic_args),*> as ts_rs::TS>::inline(),
+                &[#((#params, <#substituted as ts_rs::TS>::reference())),*],
+            )
+        }
+        fn reference() -> String {
+            Self::name_with_type_args(vec![#(#type_args),*])
+        }
+    })
 }
 
 // generate start of the `impl TS for #ty` block, up to (excluding) the open brace
@@ -172,7 +457,9 @@ fn add_ts_to_where_clause(generics: &Generics) -> Option<WhereClause> {
         .params
         .iter()
         .filter_map(|gp| match gp {
-            GenericParam::Type(ty) => Some(ty.ident.clone()),
+            GenericParam::Type(ty) if !types::is_skipped(ty) && !types::is_unbounded(ty) => {
+                Some(ty.ident.clone())
+            }
             _ => None,
         })
         .collect::<Vec<_>>();
@@ -190,7 +477,24 @@ fn add_ts_to_where_clause(generics: &Generics) -> Option<WhereClause> {
 
 /// Derives [TS](./trait.TS.html) for a struct or enum.
 /// Please take a look at [TS](./trait.TS.html) for documentation.
-#[proc_macro_derive(TS, attributes(ts))]
+// with `strum-interop`, `#[strum(..)]` is accepted even if no strum trait is derived, and the same
+// goes for `#[validate(..)]` and `#[garde(..)]` with `validator-interop`
+#[cfg_attr(
+    all(feature = "strum-interop", feature = "validator-interop"),
+    proc_macro_derive(TS, attributes(ts, strum, validate, garde))
+)]
+#[cfg_attr(
+    all(feature = "strum-interop", not(feature = "validator-interop")),
+    proc_macro_derive(TS, attributes(ts, strum))
+)]
+#[cfg_attr(
+    all(not(feature = "strum-interop"), feature = "validator-interop"),
+    proc_macro_derive(TS, attributes(ts, validate, garde))
+)]
+#[cfg_attr(
+    not(any(feature = "strum-interop", feature = "validator-interop")),
+    proc_macro_derive(TS, attributes(ts))
+)]
 pub fn typescript(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match entry(input) {
         Err(err) => err.to_compile_error(),
@@ -199,13 +503,130 @@ pub fn typescript(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     .into()
 }
 
+/// Exports `const` and `static` items as TypeScript constants, e.g. `export const MAX_PAGE_SIZE = 100;`.
+///
+/// ```ignore
+/// ts_rs::export_consts! {
+///     #![ts(export_to = "limits.ts")]
+///
+///     /// The largest page which can be requested
+///     pub const MAX_PAGE_SIZE: u32 = 100;
+///     pub static API_VERSION: &str = "v2";
+/// }
+/// ```
+///
+/// The items are emitted unchanged, and like `#[ts(export)]`, a test is generated which exports
+/// them when running `cargo test`. All constants are exported to a single file, which is
+/// `constants.ts` in the export directory unless `#![ts(export_to = "..")]` is given, and their
+/// doc comments are kept.
+/// Numbers, booleans, strings, chars, options, slices and arrays are supported. Like their types,
+/// `u64`, `i64`, `u128` and `i128` are declared as `bigint` unless `large_integers` is set in
+/// `ts-rs.toml`, and `usize` and `isize` as numbers unless `pointer_sized_integers` is set.
+#[proc_macro]
+pub fn export_consts(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match consts_entry(input) {
+        Err(err) => err.to_compile_error(),
+        Ok(result) => result,
+    }
+    .into()
+}
+
+/// Exports every type a module brings into scope using `use` items, without annotating each
+/// of them with `#[ts(export)]`:
+///
+/// ```ignore
+/// #[ts_rs::export_module]
+/// mod bindings {
+///     pub use crate::api::{CreateUser, User};
+///     pub use crate::billing::Invoice as BillingInvoice;
+/// }
+/// ```
+///
+/// Like `#[ts(export)]`, a single test is generated within the module, which exports all of them
+/// together with their dependencies when running `cargo test`. The module can also be annotated
+/// with `#[cfg(test)]`, so it's only compiled for tests.
+/// Glob imports like `pub use crate::*;` require the `export-all` feature. They export every type
+/// deriving `TS` without generic parameters which is declared in the module they import from, but
+/// not those it only re-exports. Every type which is named has to implement `TS` and must not have
+/// generic parameters without defaults. Other items, like modules, functions and constants, are
+/// skipped, telling them apart from types by their case, e.g. `User` but not `user` or `MAX_USERS`.
+#[proc_macro_attribute]
+pub fn export_module(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match module_entry(args, input) {
+        Err(err) => err.to_compile_error(),
+        Ok(result) => result,
+    }
+    .into()
+}
+
+fn module_entry(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> Result<TokenStream> {
+    if let Some(arg) = TokenStream::from(args).into_iter().next() {
+        syn_err!(arg.span(); "`export_module` takes no arguments");
+    }
+    module::export_module(syn::parse::<syn::ItemMod>(input)?)
+}
+
+fn consts_entry(input: proc_macro::TokenStream) -> Result<TokenStream> {
+    let consts = syn::parse::<consts::Consts>(input)?;
+    let config_file = config::load()?;
+    let mut output = consts.into_export()?;
+    output.extend(track_config(config_file));
+    Ok(output)
+}
+
 fn entry(input: proc_macro::TokenStream) -> Result<TokenStream> {
     let input = syn::parse::<Item>(input)?;
+    // discard warnings left over from a previous invocation which failed
+    utils::take_warnings();
+    utils::take_type_overrides();
+    let config_file = config::load()?;
     let (ts, ident, generics) = match input {
-        Item::Struct(s) => (types::struct_def(&s)?, s.ident, s.generics),
-        Item::Enum(e) => (types::enum_def(&e)?, e.ident, e.generics),
+        Item::Struct(mut s) => (types::struct_def(&mut s)?, s.ident, s.generics),
+        Item::Enum(mut e) => (types::enum_def(&mut e)?, e.ident, e.generics),
         _ => syn_err!(input.span(); "unsupported item"),
     };
 
-    Ok(ts.into_impl(ident, generics))
+    // the type is declared and imported using its name, and may be exported as a copy
+    let renamed = ts
+        .also_export_to
+        .iter()
+        .filter_map(|target| target.rename.as_ref());
+    for name in std::iter::once(&ts.name).chain(renamed) {
+        if let Some(reason) = utils::invalid_ts_name(name, true) {
+            syn_err!(ident.span(); "`{}` {}, rename it with `#[ts(rename = \"..\")]`", name, reason);
+        }
+    }
+
+    let mut output = ts.into_impl(
+        ident,
+        generics,
+        utils::take_warnings(),
+        utils::take_type_overrides(),
+    );
+    output.extend(track_config(config_file));
+    Ok(output)
+}
+
+// rebuild the crate whenever the config changes. Stable Rust has no API to track files read by a
+// proc macro, so the config is included in the expanded code, which doesn't end up in the binary.
+// On nightly, `--cfg ts_rs_tracked_path` tracks it using `proc_macro::tracked` instead.
+#[cfg(not(ts_rs_tracked_path))]
+fn track_config(config_file: Option<std::path::PathBuf>) -> Option<TokenStream> {
+    let path = config_file?;
+    let path = path.to_string_lossy();
+    Some(quote!(
+        const _: &[u8] = include_bytes!(#path);
+    ))
+}
+
+#[cfg(ts_rs_tracked_path)]
+fn track_config(config_file: Option<std::path::PathBuf>) -> Option<TokenStream> {
+    proc_macro::tracked::path(config_file?);
+    None
 }
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Ident, Item, ItemMod, Result, UseTree};
+
+use crate::export_entry;
+
+/// Appends a test to the module annotated with `#[ts_rs::export_module]`, exporting every type
+/// brought into scope by its `use` items, see [`export_entry`].
+/// Glob imports require the `export-all` feature, with which every type deriving `TS` is
+/// registered together with its module, so those declared in the module they import from are
+/// exported.
+pub(crate) fn export_module(mut module: ItemMod) -> Result<TokenStream> {
+    let Some((_, items)) = &mut module.content else {
+        syn_err!(module.span(); "`export_module` requires a module with a body, e.g. `mod bindings {{ pub use crate::User; }}`");
+    };
+
+    let mut types = Vec::new();
+    let mut globs = Vec::new();
+    for item in items.iter() {
+        if let Item::Use(item) = item {
+            collect_names(&item.tree, &mut Vec::new(), &mut types, &mut globs)?;
+        }
+    }
+    if types.is_empty() && globs.is_empty() {
+        syn_err!(module.ident.span(); "`export_module` requires `use` items naming the types to export");
+    }
+
+    // spanned, so a type which doesn't implement `TS` or is generic is reported at its `use`
+    let exports = types
+        .iter()
+        .map(|ty| quote_spanned!(ty.span()=> <#ty as ts_rs::TS>::export()?;))
+        .chain(globs.iter().map(|glob| {
+            let segments = glob.iter().map(Ident::to_string);
+            quote!(ts_rs::__private::export_glob(module_path!(), &[#(#segments),*])?;)
+        }));
+    // module names are snake case already, and unique within their parent module
+    let test_fn = format_ident!("export_bindings_{}", module.ident);
+    let export = export_entry(
+        &test_fn,
+        None,
+        quote!(|| -> Result<(), ts_rs::ExportError> {
+            #(#exports)*
+            Ok(())
+        }),
+        "could not export module",
+    );
+    items.push(Item::Verbatim(export));
+
+    Ok(quote!(#module))
+}
+
+// whether `ident` names a type, which is UpperCamelCase. Modules, functions and macros are named in
+// snake case, and constants and statics in SCREAMING_SNAKE_CASE.
+fn is_type_name(ident: &Ident) -> bool {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    name.starts_with(char::is_uppercase) && (name.len() == 1 || name.contains(char::is_lowercase))
+}
+
+// collects the types `tree` brings into scope, e.g. `B` and `D` for `a::{B, C as D}`, and the
+// paths of the modules glob imports import from, e.g. `a::e` for `a::e::*`. `path` is the path
+// `tree` is nested in.
+fn collect_names(
+    tree: &UseTree,
+    path: &mut Vec<Ident>,
+    names: &mut Vec<Ident>,
+    globs: &mut Vec<Vec<Ident>>,
+) -> Result<()> {
+    match tree {
+        UseTree::Path(segment) => {
+            path.push(segment.ident.clone());
+            collect_names(&segment.tree, path, names, globs)?;
+            path.pop();
+        }
+        UseTree::Name(name) if name.ident == "self" => (),
+        UseTree::Name(name) if is_type_name(&name.ident) => names.push(name.ident.clone()),
+        UseTree::Rename(rename) if is_type_name(&rename.rename) => {
+            names.push(rename.rename.clone())
+        }
+        UseTree::Name(_) | UseTree::Rename(_) => (),
+        UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_names(tree, path, names, globs)?;
+            }
+        }
+        UseTree::Glob(glob) if !cfg!(feature = "export-all") => {
+            syn_err!(glob.span(); "glob imports can only be exported by `export_module` with the `export-all` feature, since the types they import are not known to the macro otherwise. Name the types instead, e.g. `pub use crate::{{User, Post}};`")
+        }
+        UseTree::Glob(_) => globs.push(path.clone()),
+    }
+    Ok(())
+}
@@ -1,45 +1,85 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Fields, Generics, ItemEnum, Type, Variant};
+use syn::{ext::IdentExt, spanned::Spanned, Field, Fields, Generics, ItemEnum, Type, Variant};
 
 use crate::{
-    attr::{EnumAttr, FieldAttr, StructAttr, Tagged, VariantAttr},
+    attr::{
+        EnumAttr, EnumRepr, FieldAttr, Inflection, StructAttr, Tagged, UnitVariant, VariantAttr,
+    },
+    config::with_integer_types,
     deps::Dependencies,
     types,
-    types::generics::{format_generics, format_type},
+    types::{
+        generics::{self, format_generics, format_type, format_type_override},
+        json_schema,
+        named::extract_option_argument,
+    },
+    utils::{
+        append_docs, member_docs, raw_name_to_ts_field, record_type_override, to_ts_ident,
+        type_name,
+    },
     DerivedTS,
 };
 
-pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
-    let enum_attr: EnumAttr = EnumAttr::from_attrs(&s.attrs)?;
+pub(crate) fn r#enum_def(s: &mut ItemEnum) -> syn::Result<DerivedTS> {
+    let mut enum_attr: EnumAttr = EnumAttr::from_attrs(&s.attrs)?;
+    if let Some(inflection) = enum_attr.file_rename_all {
+        crate::config::override_file_names(inflection);
+    }
+    if let Some(bigint) = enum_attr.target {
+        crate::config::override_target(bigint);
+    }
+    generics::annotate_generics(
+        &mut s.generics,
+        &enum_attr.skip_generics,
+        &enum_attr.generic_bounds,
+        enum_attr.bound.as_deref(),
+    )?;
+    generics::pin_generics(
+        &mut s.generics,
+        s.variants.iter_mut().map(|v| &mut v.fields),
+        &enum_attr.concrete,
+    )?;
+    let proxy_attr = StructAttr::from(&enum_attr);
+    if types::proxy_type(&proxy_attr).is_none() {
+        let mut variants = Vec::new();
+        for variant in &s.variants {
+            if !VariantAttr::new(&variant.attrs, &enum_attr)?.skip {
+                variants.push(&variant.fields);
+            }
+        }
+        generics::skip_phantom_generics(&mut s.generics, variants)?;
+    }
+    generics::document_generics(&mut enum_attr.docs, &s.generics, &enum_attr.generic_docs)?;
 
-    let name = match &enum_attr.rename {
-        Some(existing) => existing.clone(),
-        None => s.ident.to_string(),
-    };
+    let name = type_name(enum_attr.rename.as_deref(), &s.ident);
 
-    if s.variants.is_empty() {
-        return Ok(empty_enum(name, enum_attr));
+    if let Some(proxy) = types::proxy_type(&proxy_attr) {
+        return types::proxy(&proxy_attr, &name, proxy, &s.generics);
     }
 
     if s.variants.is_empty() {
-        return Ok(DerivedTS {
-            name,
-            docs: enum_attr.docs,
-            inline: quote!("never".to_owned()),
-            decl: quote!("type {} = never;"),
-            inline_flattened: None,
-            dependencies: Dependencies::default(),
-            export: enum_attr.export,
-            export_to: enum_attr.export_to,
-        });
+        let kind = kind(&enum_attr)?;
+        return Ok(empty_enum(name, enum_attr, kind));
+    }
+
+    if let Some(repr) = enum_attr.repr {
+        return ts_enum(s, name, repr, enum_attr);
+    }
+
+    if enum_attr.unit_variant.is_some() && !matches!(enum_attr.tagged()?, Tagged::Untagged) {
+        syn_err!("`unit_variant` can only be used with `untagged`");
     }
 
     let mut formatted_variants = Vec::new();
+    let mut schemas = Vec::new();
+    let mut tags = Vec::new();
     let mut dependencies = Dependencies::default();
     for variant in &s.variants {
         format_variant(
             &mut formatted_variants,
+            &mut schemas,
+            &mut tags,
             &mut dependencies,
             &enum_attr,
             variant,
@@ -47,23 +87,55 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
         )?;
     }
 
+    check_unique_variants(&tags)?;
+
     let generic_args = format_generics(&mut dependencies, &s.generics);
+    let mut extra_decls = extra_decls(&enum_attr, &name, &tags)?;
+    extra_decls.extend(variant_types(
+        &enum_attr,
+        &name,
+        &tags,
+        &generic_args,
+        &s.generics,
+    )?);
+    extra_decls.extend(from_string(s, &enum_attr, &name, None)?);
+    extra_decls.extend(variant_values(s, &enum_attr, &name, None)?);
+    let json_schema = match schemas.is_empty() {
+        true => json_schema::literal(r#"{"not":{}}"#),
+        false => json_schema::combine("anyOf", schemas),
+    };
+    let defaults = crate::config::defaults();
+    let max_members = defaults.union_max_members.unwrap_or(usize::MAX);
+    let max_width = defaults.union_max_width.unwrap_or(usize::MAX);
     Ok(DerivedTS {
         inline: quote!([#(#formatted_variants),*].join(" | ")),
-        decl: quote!(format!("type {}{} = {};", #name, #generic_args, Self::inline())),
+        json_schema,
+        decl: quote!(ts_rs::__private::union_decl(
+            &format!("type {}{}", #name, #generic_args),
+            &[#(#formatted_variants),*],
+            #max_members,
+            #max_width,
+        )),
         inline_flattened: Some(quote!(
             format!("({})", [#(#formatted_variants),*].join(" | "))
         )),
+        extra_decls,
+        flattened_keys: None,
+        kind: kind(&enum_attr)?,
         dependencies,
         name,
         docs: enum_attr.docs,
         export: enum_attr.export,
         export_to: enum_attr.export_to,
+        also_export_to: enum_attr.also_export_to,
+        export_cfg: enum_attr.export_cfg,
     })
 }
 
 fn format_variant(
     formatted_variants: &mut Vec<TokenStream>,
+    schemas: &mut Vec<TokenStream>,
+    tags: &mut Vec<(String, String)>,
     dependencies: &mut Dependencies,
     enum_attr: &EnumAttr,
     variant: &Variant,
@@ -76,14 +148,20 @@ fn format_variant(
     }
 
     let untagged_variant = variant_attr.untagged;
-    let name = match (variant_attr.rename.clone(), &enum_attr.rename_all) {
-        (Some(rn), _) => rn,
-        (None, None) => variant.ident.to_string(),
-        (None, Some(rn)) => rn.apply(&variant.ident.to_string()),
-    };
+    let name = variant_name(variant, &variant_attr, enum_attr);
+    if !untagged_variant {
+        tags.push((to_ts_ident(&variant.ident), name.clone()));
+    }
+    let mut docs = variant_attr.docs.clone();
 
+    let mut struct_attr = StructAttr::from(variant_attr);
+    // the types flattened into a variant are intersected with it, which works even if they're
+    // generic and keeps the union readable
+    struct_attr.flatten_by_reference = true;
+    struct_attr.optional_content =
+        !untagged_variant && matches!(enum_attr.tagged()?, Tagged::Adjacently { .. });
     let variant_type = types::type_def(
-        &StructAttr::from(variant_attr),
+        &struct_attr,
         // since we are generating the variant as a struct, it doesn't have a name
         &format_ident!("_"),
         &variant.fields,
@@ -91,8 +169,83 @@ fn format_variant(
     )?;
     let variant_dependencies = variant_type.dependencies;
     let inline_type = variant_type.inline;
+    // the docs of the field of a newtype variant
+    append_docs(&mut docs, &variant_type.docs);
+
+    // a newtype variant with a skipped field is represented like a unit variant
+    let skipped_newtype = match &variant.fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?.skip
+        }
+        _ => false,
+    };
+    // the content of an adjacently tagged newtype variant can be omitted if its field is optional
+    let optional_content = match &variant.fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 && struct_attr.optional_content => {
+            let optional = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?.optional;
+            optional.is_some_and(|optional| optional.optional)
+        }
+        _ => false,
+    };
+    // an internally tagged newtype variant is serialized as its tag alone if its field is a unit
+    // struct or a struct without fields
+    let payload_less = match (&variant.fields, untagged_variant, enum_attr.tagged()?) {
+        (Fields::Unnamed(unnamed), false, Tagged::Internally { .. })
+            if unnamed.unnamed.len() == 1 =>
+        {
+            payload_less_check(&unnamed.unnamed[0], generics)?
+        }
+        _ => None,
+    };
+    // the unit variants of an untagged enum may be represented by their names
+    let unit_as_string = matches!(variant.fields, Fields::Unit)
+        && enum_attr.unit_variant == Some(UnitVariant::String);
+    let variant_schema = variant_type.json_schema;
+    let schema = match (untagged_variant, enum_attr.tagged()?) {
+        (true, _) | (_, Tagged::Untagged) if unit_as_string => json_schema::constant(&name),
+        (true, _) | (_, Tagged::Untagged) => variant_schema,
+        (false, Tagged::Externally) => match &variant.fields {
+            Fields::Unit => json_schema::constant(&name),
+            _ if skipped_newtype => json_schema::constant(&name),
+            _ => json_schema::object(vec![(name.clone(), variant_schema, true)]),
+        },
+        (false, Tagged::Adjacently { tag, content }) => {
+            let tag = (tag.to_owned(), json_schema::constant(&name), true);
+            match &variant.fields {
+                Fields::Unit => json_schema::object(vec![tag]),
+                _ if skipped_newtype => json_schema::object(vec![tag]),
+                _ => json_schema::object(vec![
+                    tag,
+                    (content.to_owned(), variant_schema, !optional_content),
+                ]),
+            }
+        }
+        (false, Tagged::Internally { tag }) => {
+            let tag =
+                json_schema::object(vec![(tag.to_owned(), json_schema::constant(&name), true)]);
+            match &variant.fields {
+                Fields::Unit => tag,
+                Fields::Named(named) if named.named.is_empty() => tag,
+                _ if skipped_newtype => tag,
+                _ => {
+                    let combined = json_schema::combine("allOf", vec![tag.clone(), variant_schema]);
+                    match &payload_less {
+                        Some(payload_less) => quote! {
+                            match #payload_less {
+                                true => #tag,
+                                false => #combined,
+                            }
+                        },
+                        None => combined,
+                    }
+                }
+            }
+        }
+    };
+    schemas.push(schema);
 
     let formatted = match (untagged_variant, enum_attr.tagged()?) {
+        (true, _) | (_, Tagged::Untagged) if unit_as_string => quote!(format!("\"{}\"", #name)),
         (true, _) | (_, Tagged::Untagged) => quote!(#inline_type),
         (false, Tagged::Externally) => match &variant.fields {
             Fields::Unit => quote!(format!("\"{}\"", #name)),
@@ -107,51 +260,31 @@ fn format_variant(
             _ => quote!(format!("{{ \"{}\": {} }}", #name, #inline_type)),
         },
         (false, Tagged::Adjacently { tag, content }) => match &variant.fields {
-            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
-                let FieldAttr {
-                    type_as,
-                    type_override,
-                    skip,
-                    ..
-                } = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?;
-
-
-
-                if skip {
-                    quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name))
-                } else {
-                    let ty = match (type_override, type_as) {
-                        (Some(_), Some(_)) => syn_err!("`type` is not compatible with `as`"),
-                        (Some(type_override), None) => quote! { #type_override },
-                        (None, Some(type_as)) => {
-                            format_type(&syn::parse_str::<Type>(&type_as)?, dependencies, generics)
-                        }
-                        (None, None) => format_type(&unnamed.unnamed[0].ty, dependencies, generics),
-                    };
-
-                    quote!(format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #name, #content, #ty))
-                }
-            }
             Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name)),
-            _ => quote!(
-                format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #name, #content, #inline_type)
-            ),
+            _ if skipped_newtype => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name)),
+            _ => {
+                let optional_annotation = if optional_content { "?" } else { "" };
+                quote!(format!(
+                    "{{ \"{}\": \"{}\", \"{}\"{}: {} }}",
+                    #tag, #name, #content, #optional_annotation, #inline_type
+                ))
+            }
         },
         (false, Tagged::Internally { tag }) => match variant_type.inline_flattened {
             Some(inline_flattened) => quote! {
-                format!(
-                    "{{ \"{}\": \"{}\", {} }}",
-                    #tag,
-                    #name,
-                    // At this point inline_flattened looks like
-                    // { /* ...data */ }
-                    //
-                    // To be flattened, an internally tagged enum must not be
-                    // surrounded by braces, otherwise each variant will look like
-                    // { "tag": "name", { /* ...data */ } }
-                    // when we want it to look like
-                    // { "tag": "name", /* ...data */ }
-                    #inline_flattened.trim_matches(&['{', '}', ' '])
+                // At this point inline_flattened looks like
+                // { /* ...data */ }
+                // or, if the variant contains flattened enums,
+                // { /* ...data */ } & ({ /* ...variant data */ } | { /* ...variant data */ })
+                //
+                // To be flattened, the tag is merged into the leading object, so that each variant
+                // looks like
+                // { "tag": "name", /* ...data */ }
+                // A variant without properties, e.g. because all of its fields are skipped, is
+                // serialized as the tag alone.
+                ts_rs::__private::internally_tagged(
+                    format!("{{ \"{}\": \"{}\" }}", #tag, #name),
+                    #inline_flattened,
                 )
             },
             None => match &variant.fields {
@@ -160,25 +293,53 @@ fn format_variant(
                         type_as,
                         skip,
                         type_override,
+                        large_integers,
+                        pointer_sized_integers,
                         ..
                     } = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?;
 
                     if skip {
                         quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name))
                     } else {
-                        let ty = match (type_override, type_as) {
+                        let tag = quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name));
+                        match (type_override, type_as) {
                             (Some(_), Some(_)) => syn_err!("`type` is not compatible with `as`"),
-                            (Some(type_override), None) => quote! { #type_override },
-                            (None, Some(type_as)) => {
-                                format_type(&syn::parse_str::<Type>(&type_as)?, dependencies, generics)
+                            (Some(type_override), None) => {
+                                let ty = format_type_override(&type_override, generics);
+                                let field = format!("{}::0", variant.ident.unraw());
+                                record_type_override(&field, &ty, &unnamed.unnamed[0].ty);
+                                quote!(format!("{} & {}", #tag, #ty))
                             }
-                            (None, None) => format_type(&unnamed.unnamed[0].ty, dependencies, generics),
-                        };
-
-                        quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #name, #ty))
+                            (None, type_as) => {
+                                let ty = match type_as {
+                                    Some(type_as) => syn::parse_str::<Type>(&type_as)?,
+                                    None => unnamed.unnamed[0].ty.clone(),
+                                };
+                                // serde can't serialize `None` within an internally tagged
+                                // variant, so `Some` is the only value it can hold
+                                let ty = extract_option_argument(&ty).unwrap_or(&ty);
+                                let formatted = with_integer_types(
+                                    large_integers,
+                                    pointer_sized_integers,
+                                    || format_type(ty, dependencies, generics),
+                                );
+                                match &payload_less {
+                                    Some(payload_less) => quote! {
+                                        match #payload_less {
+                                            true => #tag,
+                                            false => format!("{} & {}", #tag, #formatted),
+                                        }
+                                    },
+                                    None => quote!(format!("{} & {}", #tag, #formatted)),
+                                }
+                            }
+                        }
                     }
                 }
                 Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name)),
+                Fields::Named(named) if named.named.is_empty() => {
+                    quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name))
+                }
                 _ => {
                     quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #name, #inline_type))
                 }
@@ -187,21 +348,325 @@ fn format_variant(
     };
 
     dependencies.append(variant_dependencies);
-    formatted_variants.push(formatted);
+    formatted_variants.push(match docs.is_empty() {
+        true => formatted,
+        false => {
+            let docs = member_docs(&docs);
+            quote!(format!("{}{}", #docs, #formatted))
+        }
+    });
     Ok(())
 }
 
+// an expression evaluating to whether the type of `field`, the field of an internally tagged newtype
+// variant, has no properties, see `ts_rs::__private::is_payload_less`. Returns `None` if the type
+// is overridden or a generic parameter, which defaults to `()` when exporting.
+fn payload_less_check(field: &Field, generics: &Generics) -> syn::Result<Option<TokenStream>> {
+    let FieldAttr {
+        type_as,
+        type_override,
+        ..
+    } = FieldAttr::from_attrs(&field.attrs)?;
+    if type_override.is_some() {
+        return Ok(None);
+    }
+    let ty = match type_as {
+        Some(type_as) => syn::parse_str::<Type>(&type_as)?,
+        None => field.ty.clone(),
+    };
+    let ty = extract_option_argument(&ty).unwrap_or(&ty);
+    let is_generic = generics.type_params().any(|param| {
+        matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(&param.ident))
+    });
+    Ok((!is_generic).then(|| quote!(ts_rs::__private::is_payload_less::<#ty>())))
+}
+
+// variants are told apart by their names, given as `(ident, name)`, which must be unique, e.g.
+// `r#type` and `Type` with `rename_all = "lowercase"`
+fn check_unique_variants(tags: &[(String, String)]) -> syn::Result<()> {
+    for (i, (_, name)) in tags.iter().enumerate() {
+        if let Some((ident, _)) = tags[..i].iter().find(|(_, other)| other == name) {
+            syn_err!(
+                "`{}` is the name of more than one variant, including `{}` and `{}`",
+                name,
+                ident,
+                tags[i].0
+            );
+        }
+    }
+    Ok(())
+}
+
+fn variant_name(variant: &Variant, variant_attr: &VariantAttr, enum_attr: &EnumAttr) -> String {
+    match (variant_attr.rename.clone(), &enum_attr.rename_all) {
+        (Some(rn), _) => rn,
+        (None, None) => to_ts_ident(&variant.ident),
+        (None, Some(rn)) => rn.apply(&to_ts_ident(&variant.ident)),
+    }
+}
+
+// bindings for a fieldless enum annotated with `#[ts(enum)]` or `#[ts(const_enum)]`
+fn ts_enum(
+    s: &ItemEnum,
+    name: String,
+    repr: EnumRepr,
+    enum_attr: EnumAttr,
+) -> syn::Result<DerivedTS> {
+    if !matches!(enum_attr.tagged()?, Tagged::Externally) {
+        syn_err!("`enum` and `const_enum` cannot be used with `tag`, `content` or `untagged`");
+    }
+
+    let mut members = Vec::new();
+    let mut values = Vec::new();
+    let mut schema_values = Vec::new();
+    let mut tags = Vec::new();
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::new(&variant.attrs, &enum_attr)?;
+        if variant_attr.skip {
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) || variant_attr.untagged {
+            syn_err!(variant.span(); "`enum` and `const_enum` can only be used on enums with unit variants");
+        }
+
+        let value = variant_name(variant, &variant_attr, &enum_attr);
+        members.push(format!(
+            "{}{} = \"{}\"",
+            member_docs(&variant_attr.docs),
+            to_ts_ident(&variant.ident),
+            value
+        ));
+        values.push(format!("\"{}\"", value));
+        schema_values.push(json_schema::string(&value));
+        tags.push((to_ts_ident(&variant.ident), value));
+    }
+    check_unique_variants(&tags)?;
+
+    let keyword = match repr {
+        EnumRepr::Enum => "enum",
+        EnumRepr::ConstEnum => "const enum",
+    };
+    let members = members.join(", ");
+    let inline = match values.is_empty() {
+        true => "never".to_owned(),
+        false => values.join(" | "),
+    };
+
+    let json_schema = match schema_values.is_empty() {
+        true => json_schema::literal(r#"{"not":{}}"#),
+        false => json_schema::literal(format!(r#"{{"enum":[{}]}}"#, schema_values.join(","))),
+    };
+
+    let mut extra_decls = extra_decls(&enum_attr, &name, &tags)?;
+    extra_decls.extend(from_string(s, &enum_attr, &name, Some(repr))?);
+    extra_decls.extend(variant_values(s, &enum_attr, &name, Some(repr))?);
+    let kind = kind(&enum_attr)?;
+    Ok(DerivedTS {
+        inline: quote!(#inline.to_owned()),
+        json_schema,
+        decl: quote!(format!("{} {} {{ {} }}", #keyword, #name, #members)),
+        docs: enum_attr.docs,
+        inline_flattened: None,
+        extra_decls,
+        flattened_keys: None,
+        kind,
+        dependencies: Dependencies::default(),
+        name,
+        export: enum_attr.export,
+        export_to: enum_attr.export_to,
+        also_export_to: enum_attr.also_export_to,
+        export_cfg: enum_attr.export_cfg,
+    })
+}
+
+// additional declarations exported alongside the enum, given the identifier and tag of every variant
+fn extra_decls(
+    enum_attr: &EnumAttr,
+    name: &str,
+    tags: &[(String, String)],
+) -> syn::Result<Vec<TokenStream>> {
+    let mut decls = Vec::new();
+
+    if let Some(const_name) = &enum_attr.tag_values {
+        if let Tagged::Untagged = enum_attr.tagged()? {
+            syn_err!("`tag_values` cannot be used with `untagged`");
+        }
+
+        let const_name = const_name.clone().unwrap_or_else(|| format!("{name}Kind"));
+        let members = tags
+            .iter()
+            .map(|(ident, tag)| format!("{ident}: \"{tag}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let decl = format!("const {const_name} = {{ {members} }} as const;");
+        decls.push(quote!(#decl.to_owned()));
+    }
+
+    Ok(decls)
+}
+
+// the aliases generated by `#[ts(variant_types)]`, narrowing the union of a tagged enum to one of
+// its variants, e.g. `type EventClick = Extract<Event, { type: "Click" }>;`
+fn variant_types(
+    enum_attr: &EnumAttr,
+    name: &str,
+    tags: &[(String, String)],
+    generic_args: &TokenStream,
+    enum_generics: &Generics,
+) -> syn::Result<Vec<TokenStream>> {
+    if !enum_attr.variant_types {
+        return Ok(Vec::new());
+    }
+    let tag = match enum_attr.tagged()? {
+        Tagged::Internally { tag } | Tagged::Adjacently { tag, .. } => {
+            raw_name_to_ts_field(tag.to_owned())
+        }
+        _ => syn_err!("`variant_types` can only be used with `tag`"),
+    };
+
+    // the enum is referenced with the generic parameters of the alias as its arguments
+    let params = enum_generics
+        .type_params()
+        .filter(|param| !generics::is_skipped(param))
+        .map(|param| param.ident.to_string())
+        .collect::<Vec<_>>();
+    let type_args = match params.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", params.join(", ")),
+    };
+    Ok(tags
+        .iter()
+        .map(|(ident, value)| {
+            let head = format!("type {name}{ident}");
+            let narrowed = format!("Extract<{name}{type_args}, {{ {tag}: \"{value}\" }}>;");
+            quote!(format!("{}{} = {}", #head, #generic_args, #narrowed))
+        })
+        .collect())
+}
+
+// a constant generated by `#[ts(from_string)]`, mapping the name and every alias of a unit variant
+// to its value. `repr` is given if the enum is exported as a TypeScript enum.
+fn from_string(
+    s: &ItemEnum,
+    enum_attr: &EnumAttr,
+    name: &str,
+    repr: Option<EnumRepr>,
+) -> syn::Result<Option<TokenStream>> {
+    let Some(const_name) = &enum_attr.from_string else {
+        return Ok(None);
+    };
+    if !matches!(enum_attr.tagged()?, Tagged::Externally) {
+        syn_err!("`from_string` cannot be used with `tag`, `content` or `untagged`");
+    }
+    if s.generics.type_params().next().is_some() {
+        syn_err!("`from_string` cannot be used on generic enums");
+    }
+
+    let mut keys = Vec::new();
+    let mut members = Vec::new();
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::new(&variant.attrs, enum_attr)?;
+        if variant_attr.skip {
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) || variant_attr.untagged {
+            syn_err!(variant.span(); "`from_string` can only be used on enums with unit variants");
+        }
+
+        let value = variant_name(variant, &variant_attr, enum_attr);
+        let member = match repr {
+            Some(_) => format!("{}.{}", name, to_ts_ident(&variant.ident)),
+            None => format!("\"{}\"", value),
+        };
+        for key in std::iter::once(value).chain(variant_attr.aliases) {
+            // duplicate keys are an error in an object literal
+            if !keys.contains(&key) {
+                members.push(format!("\"{}\": {}", key, member));
+                keys.push(key);
+            }
+        }
+    }
+
+    let const_name = const_name
+        .clone()
+        .unwrap_or_else(|| format!("{name}FromString"));
+    let decl = format!(
+        "const {const_name}: Record<string, {name}> = {{ {} }};",
+        members.join(", ")
+    );
+    Ok(Some(quote!(#decl.to_owned())))
+}
+
+// a constant generated by `#[ts(values)]`, listing the value of every unit variant which isn't
+// skipped. `repr` is given if the enum is exported as a TypeScript enum.
+fn variant_values(
+    s: &ItemEnum,
+    enum_attr: &EnumAttr,
+    name: &str,
+    repr: Option<EnumRepr>,
+) -> syn::Result<Option<TokenStream>> {
+    let Some(const_name) = &enum_attr.values else {
+        return Ok(None);
+    };
+    if !matches!(enum_attr.tagged()?, Tagged::Externally) {
+        syn_err!("`values` cannot be used with `tag`, `content` or `untagged`");
+    }
+
+    let mut values = Vec::new();
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::new(&variant.attrs, enum_attr)?;
+        if variant_attr.skip {
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) || variant_attr.untagged {
+            syn_err!(variant.span(); "`values` can only be used on enums with unit variants");
+        }
+        values.push(match repr {
+            Some(_) => format!("{}.{}", name, to_ts_ident(&variant.ident)),
+            None => format!("\"{}\"", variant_name(variant, &variant_attr, enum_attr)),
+        });
+    }
+
+    let const_name = const_name
+        .clone()
+        .unwrap_or_else(|| format!("{}_VALUES", Inflection::ScreamingSnake.apply(name)));
+    let decl = format!("const {const_name} = [{}] as const;", values.join(", "));
+    Ok(Some(quote!(#decl.to_owned())))
+}
+
+// the `TS::KIND` of the enum, which includes how its variants are represented
+fn kind(enum_attr: &EnumAttr) -> syn::Result<TokenStream> {
+    let representation = match enum_attr.tagged()? {
+        Tagged::Externally => quote!(External),
+        Tagged::Internally { tag } => quote!(Internal { tag: #tag }),
+        Tagged::Adjacently { tag, content } => quote!(Adjacent {
+            tag: #tag,
+            content: #content
+        }),
+        Tagged::Untagged => quote!(Untagged),
+    };
+    Ok(quote!(ts_rs::TypeKind::Enum {
+        representation: ts_rs::EnumRepresentation::#representation
+    }))
+}
+
 // bindings for an empty enum (`never` in TS)
-fn empty_enum(name: impl Into<String>, enum_attr: EnumAttr) -> DerivedTS {
+fn empty_enum(name: impl Into<String>, enum_attr: EnumAttr, kind: TokenStream) -> DerivedTS {
     let name = name.into();
     DerivedTS {
         inline: quote!("never".to_owned()),
         decl: quote!(format!("type {} = never;", #name)),
+        json_schema: json_schema::literal(r#"{"not":{}}"#),
         name,
         docs: enum_attr.docs,
         inline_flattened: None,
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind,
         dependencies: Dependencies::default(),
         export: enum_attr.export,
         export_to: enum_attr.export_to,
+        also_export_to: enum_attr.also_export_to,
+        export_cfg: enum_attr.export_cfg,
     }
 }
@@ -1,11 +1,230 @@
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
 use syn::{
-    GenericArgument, GenericParam, Generics, ItemStruct, PathArguments, Type, TypeGroup,
-    TypeReference, TypeSlice, TypeTuple,
+    parse::ParseStream,
+    parse_quote,
+    visit_mut::{self, VisitMut},
+    Fields, GenericArgument, GenericParam, Generics, Ident, LitStr, PathArguments, Result, Token,
+    Type, TypeGroup, TypeParam, TypePath, TypeReference, TypeSlice, WherePredicate,
+};
+
+use super::json_schema;
+use crate::{
+    attr::{FieldAttr, StructAttr},
+    deps::Dependencies,
+    utils::{append_doc_tags, parse_attrs, record_warning},
 };
 
-use crate::{attr::StructAttr, deps::Dependencies};
+/// Annotates the generic parameters named by `#[ts(skip_generic = "..")]` with `#[ts(skip)]`,
+/// and those named by `#[ts(generic_bound(..))]` with `#[ts(extends = "..")]`.
+/// If `#[ts(bound = "..")]` is given, every parameter is annotated with `#[ts(unbounded)]` and
+/// the predicates are added to the where clause instead.
+pub fn annotate_generics(
+    generics: &mut Generics,
+    skip: &[String],
+    bounds: &[(String, String)],
+    where_predicates: Option<&[WherePredicate]>,
+) -> Result<()> {
+    for name in skip {
+        type_param(generics, name, "skip_generic")?
+            .attrs
+            .push(parse_quote!(#[ts(skip)]));
+    }
+    for (name, bound) in bounds {
+        type_param(generics, name, "generic_bound")?
+            .attrs
+            .push(parse_quote!(#[ts(extends = #bound)]));
+    }
+    if let Some(where_predicates) = where_predicates {
+        for param in generics.type_params_mut() {
+            param.attrs.push(parse_quote!(#[ts(unbounded)]));
+        }
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(where_predicates.iter().cloned());
+    }
+    Ok(())
+}
+
+/// Replaces the generic parameters pinned by `#[ts(concrete(..))]` with their concrete type in
+/// the types of all `fields`. The parameters are then omitted, just like skipped ones.
+pub fn pin_generics<'a>(
+    generics: &mut Generics,
+    fields: impl IntoIterator<Item = &'a mut Fields>,
+    concrete: &[(String, Type)],
+) -> Result<()> {
+    if concrete.is_empty() {
+        return Ok(());
+    }
+    for (name, _) in concrete {
+        type_param(generics, name, "concrete")?
+            .attrs
+            .push(parse_quote!(#[ts(skip)]));
+    }
+
+    struct Pin<'a>(&'a [(String, Type)]);
+    impl VisitMut for Pin<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            let pinned = match ty {
+                Type::Path(path) if path.qself.is_none() => {
+                    self.0.iter().find(|(name, _)| path.path.is_ident(name))
+                }
+                _ => None,
+            };
+            match pinned {
+                Some((_, concrete)) => *ty = concrete.clone(),
+                None => visit_mut::visit_type_mut(self, ty),
+            }
+        }
+    }
+    for fields in fields {
+        Pin(concrete).visit_fields_mut(fields);
+    }
+    Ok(())
+}
+
+/// Annotates the generic parameters which are only used within `PhantomData` with `#[ts(skip)]`,
+/// like `M` in `struct Id<M> { id: u64, marker: PhantomData<M> }`. Since `PhantomData` is
+/// exported as `null`, these parameters would never appear in TypeScript.
+pub fn skip_phantom_generics<'a>(
+    generics: &mut Generics,
+    fields: impl IntoIterator<Item = &'a Fields>,
+) -> Result<()> {
+    #[derive(Default)]
+    struct Uses {
+        phantom: HashSet<Ident>,
+        other: HashSet<Ident>,
+        in_phantom: bool,
+    }
+    impl VisitMut for Uses {
+        fn visit_type_path_mut(&mut self, ty: &mut TypePath) {
+            if let Some(first) = ty.path.segments.first().filter(|_| ty.qself.is_none()) {
+                match self.in_phantom {
+                    true => self.phantom.insert(first.ident.clone()),
+                    false => self.other.insert(first.ident.clone()),
+                };
+            }
+            let in_phantom = self.in_phantom;
+            self.in_phantom |= is_phantom_data(ty);
+            visit_mut::visit_type_path_mut(self, ty);
+            self.in_phantom = in_phantom;
+        }
+    }
+
+    let mut uses = Uses::default();
+    for field in fields.into_iter().flatten() {
+        // only `#[ts(..)]` is considered here, since parsing serde attributes records warnings
+        let (mut skip, mut type_as, mut type_override) = (false, None, None);
+        for attr in parse_attrs::<FieldAttr>(&field.attrs)? {
+            skip |= attr.skip;
+            type_as = attr.type_as.or(type_as);
+            type_override = attr.type_override.or(type_override);
+        }
+        if let Some(type_override) = type_override.filter(|_| !skip) {
+            // parameters referred to as `{T}` are used
+            for param in generics.type_params() {
+                if type_override.contains(&format!("{{{}}}", param.ident)) {
+                    uses.other.insert(param.ident.clone());
+                }
+            }
+            continue;
+        }
+        let mut ty = match type_as {
+            Some(type_as) => syn::parse_str(&type_as)?,
+            None => field.ty.clone(),
+        };
+        match skip {
+            // skipped fields are not exported, so only their `PhantomData` is taken into account
+            true => {
+                let mut skipped = Uses::default();
+                skipped.visit_type_mut(&mut ty);
+                uses.phantom.extend(skipped.phantom);
+            }
+            false => uses.visit_type_mut(&mut ty),
+        }
+    }
+
+    for param in generics.type_params_mut() {
+        if uses.phantom.contains(&param.ident) && !uses.other.contains(&param.ident) {
+            param.attrs.push(parse_quote!(#[ts(skip)]));
+        }
+    }
+    Ok(())
+}
+
+fn type_param<'a>(generics: &'a mut Generics, name: &str, attr: &str) -> Result<&'a mut TypeParam> {
+    match generics.type_params_mut().find(|param| param.ident == name) {
+        Some(param) => Ok(param),
+        None => syn_err!("`{}`: unknown generic parameter `{}`", attr, name),
+    }
+}
+
+/// Appends a `@template` JSDoc tag to `docs` for every generic parameter documented with
+/// `#[ts(doc_generic(..))]`, in the order in which the parameters are declared.
+pub fn document_generics(
+    docs: &mut String,
+    generics: &Generics,
+    param_docs: &[(String, String)],
+) -> Result<()> {
+    if let Some((name, _)) = param_docs
+        .iter()
+        .find(|(name, _)| !generics.type_params().any(|param| param.ident == name))
+    {
+        syn_err!("`doc_generic`: unknown generic parameter `{}`", name);
+    }
+
+    let tags = generics
+        .type_params()
+        .filter(|param| !is_skipped(param))
+        .filter_map(|param| param_docs.iter().find(|(name, _)| param.ident == name))
+        .map(|(name, doc)| format!(" * @template {} {}", name, doc))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if !tags.is_empty() {
+        append_doc_tags(docs, &tags);
+    }
+    Ok(())
+}
+
+/// Returns the TypeScript constraint of the generic parameter, given by `#[ts(extends = "..")]`.
+pub fn extends(param: &TypeParam) -> Option<String> {
+    param
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("ts"))
+        .find_map(|attr| {
+            attr.parse_args_with(|input: ParseStream| {
+                let key = input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                let bound = input.parse::<LitStr>()?;
+                match key == "extends" {
+                    true => Ok(bound.value()),
+                    false => Err(input.error("expected `extends`")),
+                }
+            })
+            .ok()
+        })
+}
+
+/// Returns `true` if the generic parameter is annotated with `#[ts(unbounded)]`, in which case
+/// `TS` is not required to be implemented for it unless the where clause says so.
+pub fn is_unbounded(param: &TypeParam) -> bool {
+    param.attrs.iter().any(|attr| {
+        attr.path().is_ident("ts")
+            && matches!(attr.parse_args::<Ident>(), Ok(i) if i == "unbounded")
+    })
+}
+
+/// Returns `true` if the generic parameter is annotated with `#[ts(skip)]`.
+/// Skipped parameters are omitted in TypeScript, and are not required to implement `TS`.
+pub fn is_skipped(param: &TypeParam) -> bool {
+    param.attrs.iter().any(|attr| {
+        attr.path().is_ident("ts") && matches!(attr.parse_args::<Ident>(), Ok(i) if i == "skip")
+    })
+}
 
 /// formats the generic arguments (like A, B in struct X<A, B>{..}) as "<X>" where x is a comma
 /// seperated list of generic arguments, or an empty string if there are no type generics (lifetime/const generics are ignored).
@@ -17,8 +236,11 @@ pub fn format_generics(deps: &mut Dependencies, generics: &Generics) -> TokenStr
         .params
         .iter()
         .filter_map(|param| match param {
-            GenericParam::Type(type_param) => Some({
-                let ty = type_param.ident.to_string();
+            GenericParam::Type(type_param) if !is_skipped(type_param) => Some({
+                let ty = match extends(type_param) {
+                    Some(bound) => format!("{} extends {}", type_param.ident, bound),
+                    None => type_param.ident.to_string(),
+                };
                 if let Some(default) = &type_param.default {
                     let default = format_type(default, deps, generics);
                     quote!(format!("{} = {}", #ty, #default))
@@ -59,6 +281,11 @@ pub fn format_type(ty: &Type, dependencies: &mut Dependencies, generics: &Generi
         let generic_ident = generic.ident.clone();
         let generic_ident_str = generic_ident.to_string();
 
+        if is_skipped(generic) {
+            let msg = format!("the skipped generic parameter `{generic_ident_str}` cannot be used as a type in TypeScript");
+            return quote!(compile_error!(#msg));
+        }
+
         if !generic.bounds.is_empty() {
             return quote!(#generic_ident_str.to_owned());
         }
@@ -77,6 +304,11 @@ pub fn format_type(ty: &Type, dependencies: &mut Dependencies, generics: &Generi
         );
     }
 
+    if let Some(ts_type) = large_integer(ty) {
+        return quote!(#ts_type.to_owned());
+    }
+    warn_about_bigint(ty);
+
     // special treatment for arrays and tuples
     match ty {
         // Arrays have their own implementation that needs to be handle separetly
@@ -102,17 +334,11 @@ pub fn format_type(ty: &Type, dependencies: &mut Dependencies, generics: &Generi
                     .inline;
             }
 
-            // we convert the tuple field to a struct: `(A, B, C)` => `struct A(A, B, C)`
-            let tuple_struct = super::type_def(
-                &StructAttr::default(),
-                &format_ident!("_"),
-                &tuple_type_to_tuple_struct(tuple).fields,
-                generics,
-            )
-            .unwrap();
-            // now, we return the inline definition
-            dependencies.append(tuple_struct.dependencies);
-            return tuple_struct.inline;
+            let elems = tuple
+                .elems
+                .iter()
+                .map(|elem| format_type(elem, dependencies, generics));
+            return quote!(format!("[{}]", [#(#elems),*].join(", ")));
         }
         Type::Reference(syn::TypeReference { ref elem, .. }) => {
             return format_type(elem, dependencies, generics)
@@ -121,11 +347,15 @@ pub fn format_type(ty: &Type, dependencies: &mut Dependencies, generics: &Generi
     };
 
     dependencies.push_or_append_from(ty);
+    if matches!(ty, Type::Path(path) if is_phantom_data(path)) {
+        return quote!(<#ty as ts_rs::TS>::name());
+    }
     match extract_type_args(ty) {
         None => quote!(<#ty as ts_rs::TS>::name()),
         Some(type_args) => {
             let args = type_args
                 .iter()
+                .filter(|ty| !is_skipped_generic(ty, generics))
                 .map(|ty| format_type(ty, dependencies, generics))
                 .collect::<Vec<_>>();
             let args = quote!(vec![#(#args),*]);
@@ -134,6 +364,174 @@ pub fn format_type(ty: &Type, dependencies: &mut Dependencies, generics: &Generi
     }
 }
 
+/// Builds an expression evaluating to the type given by `#[ts(type = "..")]`, in which a generic
+/// parameter of the container can be referred to as `{T}`.
+pub fn format_type_override(ty: &str, generics: &Generics) -> TokenStream {
+    let mut parts = Vec::new();
+    let mut rest = ty;
+    while let Some(start) = rest.find('{') {
+        let param = rest[start + 1..]
+            .split_once('}')
+            .and_then(|(name, _)| generics.type_params().find(|p| p.ident == name));
+        match param {
+            Some(param) => {
+                let (literal, ident) = (&rest[..start], &param.ident);
+                parts.push(quote!(#literal.to_owned()));
+                let param_ty = parse_quote!(#ident);
+                parts.push(format_type(
+                    &param_ty,
+                    &mut Dependencies::default(),
+                    generics,
+                ));
+                rest = &rest[start + ident.to_string().len() + 2..];
+            }
+            None => {
+                let literal = &rest[..=start];
+                parts.push(quote!(#literal.to_owned()));
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+
+    match parts.is_empty() {
+        true => quote!(#ty.to_owned()),
+        false => quote!([#(#parts,)* #rest.to_owned()].concat()),
+    }
+}
+
+/// Returns `true` if `ty` refers to a generic parameter or lifetime of the type being derived, or
+/// to `Self`, meaning it can only be used within the impl.
+pub fn mentions_generics(ty: &Type, generics: &Generics) -> bool {
+    fn mentions(tokens: TokenStream, generics: &Generics) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Group(group) => mentions(group.stream(), generics),
+            TokenTree::Ident(ident) => {
+                ident == "Self"
+                    || generics.type_params().any(|param| param.ident == ident)
+                    || generics.const_params().any(|param| param.ident == ident)
+            }
+            TokenTree::Punct(punct) => punct.as_char() == '\'',
+            TokenTree::Literal(_) => false,
+        })
+    }
+    mentions(ty.to_token_stream(), generics)
+}
+
+/// Builds an expression which evaluates to the JSON schema of `ty`, as referenced from the schema
+/// of the type being derived. Generic parameters accept any value.
+pub fn format_schema(ty: &Type, generics: &Generics) -> TokenStream {
+    let is_generic = generics.type_params().any(
+        |param| matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident(&param.ident)),
+    );
+    if is_generic {
+        return json_schema::literal("{}");
+    }
+    if let Some(ts_type) = large_integer(ty) {
+        return json_schema::literal(match ts_type {
+            "string" => r#"{"type":"string"}"#,
+            _ => r#"{"type":"integer"}"#,
+        });
+    }
+
+    match ty {
+        Type::Array(type_array) => {
+            let elem = format_schema(&type_array.elem, generics);
+            return quote!(<#type_array as ts_rs::TS>::json_schema_ref_with_type_args(vec![#elem]));
+        }
+        Type::Slice(TypeSlice { ref elem, .. }) => {
+            let vec_ty = syn::parse2::<Type>(quote!(Vec::<#elem>)).unwrap();
+            return format_schema(&vec_ty, generics);
+        }
+        Type::Tuple(tuple) if tuple.elems.is_empty() => {
+            return json_schema::literal(r#"{"type":"null"}"#);
+        }
+        Type::Tuple(tuple) => {
+            let items = tuple
+                .elems
+                .iter()
+                .map(|elem| format_schema(elem, generics))
+                .collect();
+            return json_schema::tuple(items, tuple.elems.len());
+        }
+        Type::Reference(syn::TypeReference { ref elem, .. }) => {
+            return format_schema(elem, generics)
+        }
+        _ => (),
+    };
+
+    match extract_type_args(ty) {
+        None => quote!(<#ty as ts_rs::TS>::json_schema_ref()),
+        Some(type_args) => {
+            let args = type_args.iter().map(|ty| format_schema(ty, generics));
+            quote!(<#ty as ts_rs::TS>::json_schema_ref_with_type_args(vec![#(#args),*]))
+        }
+    }
+}
+
+// the TypeScript type of `ty` if it's an integer whose type is configured in `ts-rs.toml`, i.e.
+// one exported as `bigint` by default if `large_integers` is set, or `usize` and `isize` if
+// `pointer_sized_integers` is set
+const LARGE_INTEGERS: [&str; 8] = [
+    "u64",
+    "i64",
+    "u128",
+    "i128",
+    "NonZeroU64",
+    "NonZeroI64",
+    "NonZeroU128",
+    "NonZeroI128",
+];
+
+// records a warning if `ty` is exported as `bigint` only because neither `ts-rs.toml` nor the
+// field set `large_integers`, since serde_json serializes it as a number
+fn warn_about_bigint(ty: &Type) {
+    let defaults = crate::config::defaults();
+    if defaults.large_integers_set || defaults.large_integers.is_some() {
+        return;
+    }
+    let last = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+        _ => None,
+    };
+    if let Some(last) = last.filter(|last| LARGE_INTEGERS.iter().any(|int| last.ident == int)) {
+        record_warning(format!(
+            "`{}` is exported as `bigint`, but serde_json serializes it as a number",
+            last.ident
+        ));
+    }
+}
+
+fn large_integer(ty: &Type) -> Option<&'static str> {
+    const POINTER_SIZED_INTEGERS: [&str; 4] = ["usize", "isize", "NonZeroUsize", "NonZeroIsize"];
+
+    let last = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    let defaults = crate::config::defaults();
+    match () {
+        _ if LARGE_INTEGERS.iter().any(|int| last.ident == int) => defaults.large_integers,
+        _ if POINTER_SIZED_INTEGERS.iter().any(|int| last.ident == int) => {
+            defaults.pointer_sized_integers
+        }
+        _ => None,
+    }
+}
+
+// `true` if `ty` is a path to `PhantomData`, whose type argument is not exported
+fn is_phantom_data(ty: &TypePath) -> bool {
+    ty.qself.is_none()
+        && matches!(ty.path.segments.last(), Some(segment) if segment.ident == "PhantomData")
+}
+
+// `true` if `ty` is a generic parameter annotated with `#[ts(skip)]`
+fn is_skipped_generic(ty: &Type, generics: &Generics) -> bool {
+    generics.type_params().any(|param| {
+        is_skipped(param)
+            && matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident(&param.ident))
+    })
+}
+
 fn extract_type_args(ty: &Type) -> Option<Vec<&Type>> {
     let last_segment = match ty {
         Type::Group(TypeGroup { elem, .. }) | Type::Reference(TypeReference { elem, .. }) => {
@@ -162,11 +560,3 @@ fn extract_type_args(ty: &Type) -> Option<Vec<&Type>> {
 
     Some(type_args)
 }
-
-// convert a [`TypeTuple`],  e.g `(A, B, C)`
-//      to a [`ItemStruct`], e.g `struct A(A, B, C)`
-fn tuple_type_to_tuple_struct(tuple: &TypeTuple) -> ItemStruct {
-    let elements = tuple.elems.iter();
-    syn::parse2(quote!(struct A( #(#elements),* );))
-        .expect("could not convert tuple to tuple struct")
-}
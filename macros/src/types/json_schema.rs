@@ -0,0 +1,75 @@
+// helpers for building expressions which evaluate to a JSON schema, serialized as a `String`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Lit;
+
+/// Serializes `value` as a JSON string literal.
+pub fn string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A schema which is known when expanding the macro.
+pub fn literal(json: impl AsRef<str>) -> TokenStream {
+    let json = json.as_ref();
+    quote!(#json.to_owned())
+}
+
+/// Serializes a string, number or boolean literal as JSON, which is a TypeScript literal type as
+/// well.
+pub fn value(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(s) => string(&s.value()),
+        Lit::Int(i) => i.base10_digits().to_owned(),
+        Lit::Float(f) => f.base10_digits().to_owned(),
+        Lit::Bool(b) => b.value.to_string(),
+        _ => unreachable!("literals are validated when parsing"),
+    }
+}
+
+/// A schema which only matches the given string.
+pub fn constant(value: &str) -> TokenStream {
+    literal(format!(r#"{{"const":{}}}"#, string(value)))
+}
+
+/// An object with the given properties, given as `(name, schema, required)`.
+pub fn object(properties: Vec<(String, TokenStream, bool)>) -> TokenStream {
+    let required = properties
+        .iter()
+        .filter(|(_, _, required)| *required)
+        .map(|(name, ..)| string(name))
+        .collect::<Vec<_>>()
+        .join(",");
+    let properties = properties.into_iter().map(|(name, schema, _)| {
+        let key = format!("{}:", string(&name));
+        quote!(format!("{}{}", #key, #schema))
+    });
+
+    let start = r#"{"type":"object","properties":{"#;
+    let end = format!(r#"}},"required":[{}]}}"#, required);
+    quote!(format!("{}{}{}", #start, <[String]>::join(&[#(#properties),*], ","), #end))
+}
+
+/// Combines the schemas using `keyword`, e.g. `{"anyOf":[..]}`.
+pub fn combine(keyword: &str, schemas: Vec<TokenStream>) -> TokenStream {
+    let start = format!(r#"{{"{}":["#, keyword);
+    quote!(format!("{}{}]}}", #start, <[String]>::join(&[#(#schemas),*], ",")))
+}
+
+/// An array containing the given items, of which the first `required` have to be present.
+pub fn tuple(items: Vec<TokenStream>, required: usize) -> TokenStream {
+    let start = r#"{"type":"array","prefixItems":["#;
+    let end = format!(r#"],"minItems":{},"maxItems":{}}}"#, required, items.len());
+    quote!(format!("{}{}{}", #start, <[String]>::join(&[#(#items),*], ","), #end))
+}
@@ -1,22 +1,65 @@
-use syn::{Fields, Generics, Ident, ItemStruct, Result};
+use syn::{parse_quote, spanned::Spanned, Fields, Generics, Ident, ItemStruct, Result, Visibility};
 
-use crate::{attr::StructAttr, utils::to_ts_ident, DerivedTS};
+use crate::{
+    attr::StructAttr,
+    utils::{to_ts_ident, type_name},
+    DerivedTS,
+};
 
 mod r#enum;
 mod generics;
+mod json_schema;
 mod named;
 mod newtype;
+mod proxy;
+mod reviver;
 mod tuple;
 mod unit;
 
+pub(crate) use generics::{is_skipped, is_unbounded};
+pub(crate) use proxy::{proxy, proxy_type};
 pub(crate) use r#enum::r#enum_def;
 
-pub(crate) fn struct_def(s: &ItemStruct) -> Result<DerivedTS> {
-    let attr = StructAttr::from_attrs(&s.attrs)?;
+pub(crate) fn struct_def(s: &mut ItemStruct) -> Result<DerivedTS> {
+    let mut attr = StructAttr::from_attrs(&s.attrs)?;
+    if let Some(inflection) = attr.file_rename_all {
+        crate::config::override_file_names(inflection);
+    }
+    if let Some(bigint) = attr.target {
+        crate::config::override_target(bigint);
+    }
+    if attr.skip_private {
+        skip_private_fields(&mut s.fields)?;
+    }
+    generics::annotate_generics(
+        &mut s.generics,
+        &attr.skip_generics,
+        &attr.generic_bounds,
+        attr.bound.as_deref(),
+    )?;
+    generics::pin_generics(&mut s.generics, [&mut s.fields], &attr.concrete)?;
+    if proxy::proxy_type(&attr).is_none() {
+        generics::skip_phantom_generics(&mut s.generics, [&s.fields])?;
+    }
+    generics::document_generics(&mut attr.docs, &s.generics, &attr.generic_docs)?;
+    attr.rename = Some(type_name(attr.rename.as_deref(), &s.ident));
 
     type_def(&attr, &s.ident, &s.fields, &s.generics)
 }
 
+// marks the fields which are not `pub` with `#[ts(skip)]`, given `#[ts(skip_private)]`
+fn skip_private_fields(fields: &mut Fields) -> Result<()> {
+    let Fields::Named(named) = fields else {
+        syn_err!(fields.span(); "`skip_private` is only applicable to structs with named fields");
+    };
+    for field in &mut named.named {
+        if !matches!(field.vis, Visibility::Public(_)) {
+            field.attrs.push(parse_quote!(#[ts(skip)]));
+        }
+    }
+    Ok(())
+}
+
 fn type_def(
     attr: &StructAttr,
     ident: &Ident,
@@ -24,9 +67,12 @@ fn type_def(
     generics: &Generics,
 ) -> Result<DerivedTS> {
     let name = attr.rename.clone().unwrap_or_else(|| to_ts_ident(ident));
+    if let Some(proxy) = proxy::proxy_type(attr) {
+        return proxy::proxy(attr, &name, proxy, generics);
+    }
     match fields {
         Fields::Named(named) => match named.named.len() {
-            0 => unit::empty_object(attr, &name),
+            0 if attr.index_signature.is_none() => unit::empty_object(attr, &name),
             _ => named::named(attr, &name, named, generics),
         },
         Fields::Unnamed(unnamed) => match unnamed.unnamed.len() {
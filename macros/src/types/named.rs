@@ -1,12 +1,24 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Field, FieldsNamed, GenericArgument, Generics, PathArguments, Result, Type};
+use quote::{quote, ToTokens};
+use syn::{
+    ext::IdentExt, Field, FieldsNamed, GenericArgument, Generics, PathArguments, Result, Type,
+};
 
 use crate::{
-    attr::{FieldAttr, Inflection, Optional, StructAttr},
+    attr::{FieldAttr, Optional, StructAttr},
+    config::with_integer_types,
     deps::Dependencies,
-    types::generics::{format_generics, format_type},
-    utils::{raw_name_to_ts_field, to_ts_ident},
+    types::{
+        generics::{
+            self, format_generics, format_schema, format_type, format_type_override, is_skipped,
+        },
+        json_schema,
+        reviver::{self, Revivers},
+    },
+    utils::{
+        append_doc_tags, member_docs, numeric_bounds, raw_name_to_ts_field, record_type_override,
+        record_warning, to_ts_ident,
+    },
     DerivedTS,
 };
 
@@ -17,50 +29,174 @@ pub(crate) fn named(
     generics: &Generics,
 ) -> Result<DerivedTS> {
     let mut formatted_fields = Vec::new();
+    // the fields of the deserialize view, generated with `#[ts(dual)]`
+    let mut input_fields = Vec::new();
     let mut flattened_fields = Vec::new();
+    let mut schema = Schema::default();
+    let mut revivers = Revivers::default();
+    let mut keys = Keys::default();
     let mut dependencies = Dependencies::default();
     if let Some(tag) = &attr.tag {
+        keys.own.push(tag.clone());
         let formatted = format!("{}: \"{}\",", tag, name);
         formatted_fields.push(quote! {
             #formatted.to_string()
         });
+        input_fields.push(quote!(#formatted.to_string()));
+        revivers.raw_fields.push(quote!(#formatted.to_string()));
+        schema
+            .properties
+            .push((tag.clone(), json_schema::constant(name), true));
     }
 
     for field in &fields.named {
         format_field(
             &mut formatted_fields,
+            &mut input_fields,
             &mut flattened_fields,
+            &mut schema,
+            &mut revivers,
+            &mut keys,
             &mut dependencies,
             field,
-            &attr.rename_all,
+            attr,
             generics,
         )?;
     }
 
+    // e.g. `r#type` and a field renamed to `type`
+    for (i, key) in keys.own.iter().enumerate() {
+        if keys.own[..i].contains(key) {
+            syn_err!("`{}` is the name of more than one field", key);
+        }
+    }
+
+    if let Some((key, value)) = &attr.index_signature {
+        let formatted = format!("[key: {}]: {},", key, value);
+        formatted_fields.push(quote!(#formatted.to_string()));
+        input_fields.push(quote!(#formatted.to_string()));
+        revivers.raw_fields.push(quote!(#formatted.to_string()));
+        for ty in &attr.uses {
+            dependencies.push_or_append_from(ty);
+        }
+    }
+
     let fields = quote!(<[String]>::join(&[#(#formatted_fields),*], " "));
     let flattened = quote!(<[String]>::join(&[#(#flattened_fields),*], " & "));
     let generic_args = format_generics(&mut dependencies, generics);
 
-    let inline = match (formatted_fields.len(), flattened_fields.len()) {
-        (0, 0) => quote!("{  }".to_owned()),
+    let inline = format_inline(&formatted_fields, &flattened_fields);
+
+    // when flattened into another type, fields which are themselves flattened must be kept
+    let inline_flattened = match (formatted_fields.len(), flattened_fields.len()) {
         (_, 0) => quote!(format!("{{ {} }}", #fields)),
-        (0, 1) => quote!(#flattened.trim_matches(|c| c == '(' || c == ')').to_owned()),
-        (0, _) => quote!(#flattened),
-        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+        (0, _) => quote!(#flattened.replace(" } & { ", " ")),
+        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened).replace(" } & { ", " ")),
+    };
+
+    let object = json_schema::object(schema.properties);
+    let json_schema = match schema.flattened.is_empty() {
+        true => object,
+        false => json_schema::combine("allOf", [vec![object], schema.flattened].concat()),
+    };
+
+    let mut extra_decls = match attr.reviver {
+        false => Vec::new(),
+        true => {
+            if generics.type_params().any(|param| !is_skipped(param)) {
+                syn_err!("`reviver` is not supported on generic types");
+            }
+            let raw_name = format!("{}Raw", name);
+            let raw_inline = format_inline(&revivers.raw_fields, &flattened_fields);
+            let [revive, serialize] = reviver::functions(name, &raw_name, &revivers.revived);
+            vec![
+                quote!(format!("type {} = {};", #raw_name, #raw_inline)),
+                quote!(#revive.to_owned()),
+                quote!(#serialize.to_owned()),
+            ]
+        }
     };
+    if let Some(input_name) = &attr.dual {
+        let input_name = input_name
+            .clone()
+            .unwrap_or_else(|| format!("{}Input", name));
+        let input_inline = format_inline(&input_fields, &flattened_fields);
+        extra_decls.push(quote!(
+            format!("type {}{} = {};", #input_name, #generic_args, #input_inline)
+        ));
+    }
 
     Ok(DerivedTS {
-        inline: quote!(#inline.replace(" } & { ", " ")),
+        inline,
+        json_schema,
         decl: quote!(format!("type {}{} = {}", #name, #generic_args, Self::inline())),
-        inline_flattened: Some(quote!(format!("{{ {} }}", #fields))),
+        inline_flattened: Some(inline_flattened),
+        extra_decls,
+        flattened_keys: keys.check(),
+        kind: quote!(ts_rs::TypeKind::Struct),
         name: name.to_owned(),
         docs: attr.docs.clone(),
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
 
+// build an expression which expands to the inline definition of a struct, given its formatted and
+// flattened fields
+fn format_inline(
+    formatted_fields: &[TokenStream],
+    flattened_fields: &[TokenStream],
+) -> TokenStream {
+    let fields = quote!(<[String]>::join(&[#(#formatted_fields),*], " "));
+    let flattened = quote!(<[String]>::join(&[#(#flattened_fields),*], " & "));
+
+    let inline = match (formatted_fields.len(), flattened_fields.len()) {
+        (0, 0) => {
+            // a struct whose fields are all skipped
+            let empty = crate::config::defaults().empty_struct.unwrap_or("{  }");
+            quote!(#empty.to_owned())
+        }
+        (_, 0) => quote!(format!("{{ {} }}", #fields)),
+        (0, 1) => quote!(#flattened.trim_matches(|c| c == '(' || c == ')').to_owned()),
+        (0, _) => quote!(#flattened),
+        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+    };
+    quote!(#inline.replace(" } & { ", " "))
+}
+
+// the JSON schema of a struct, collected while formatting its fields
+#[derive(Default)]
+struct Schema {
+    // name, schema and whether the property is required
+    properties: Vec<(String, TokenStream, bool)>,
+    flattened: Vec<TokenStream>,
+}
+
+// the keys of a struct and of the types flattened into it, which must not conflict
+#[derive(Default)]
+struct Keys {
+    own: Vec<String>,
+    // expressions evaluating to `(name, keys)` of every flattened type
+    flattened: Vec<TokenStream>,
+}
+
+impl Keys {
+    // keys can only conflict if a type is flattened
+    fn check(self) -> Option<TokenStream> {
+        let Keys { own, flattened } = self;
+        match flattened.is_empty() {
+            true => None,
+            false => Some(quote!(vec![
+                (Self::name(), vec![#(#own.to_owned()),*]),
+                #(#flattened),*
+            ])),
+        }
+    }
+}
+
 // build an expresion which expands to a string, representing a single field of a struct.
 //
 // formatted_fields will contain all the fields that do not contain the flatten
@@ -71,26 +207,53 @@ pub(crate) fn named(
 // in their respective formats, which for a named struct is the same as formatted_fields,
 // but for enums is
 // ({ /* variant data */ } | { /* variant data */ })
+//
+// input_fields will contain the fields of the deserialize view, which only differs from
+// formatted_fields with `#[ts(dual)]`
+#[allow(clippy::too_many_arguments)]
 fn format_field(
     formatted_fields: &mut Vec<TokenStream>,
+    input_fields: &mut Vec<TokenStream>,
     flattened_fields: &mut Vec<TokenStream>,
+    schema: &mut Schema,
+    revivers: &mut Revivers,
+    keys: &mut Keys,
     dependencies: &mut Dependencies,
     field: &Field,
-    rename_all: &Option<Inflection>,
+    attr: &StructAttr,
     generics: &Generics,
 ) -> Result<()> {
     let FieldAttr {
         type_as,
         type_override,
+        uses,
+        literal,
+        large_integers,
+        pointer_sized_integers,
         rename,
+        rename_all: rename_all_inlined,
         inline,
         skip,
         optional,
         flatten,
-        docs,
+        readonly,
+        revive,
+        directional,
+        mut docs,
     } = FieldAttr::from_attrs(&field.attrs)?;
 
-    if skip {
+    // attributes affecting only one direction are ignored unless both views are generated
+    let dual = attr.dual.is_some();
+    if let (false, Some(ignored)) = (dual, directional.any()) {
+        record_warning(format!(
+            "`{}` is ignored unless the struct is annotated with `#[ts(dual)]`",
+            ignored
+        ));
+    }
+    let skip_serializing = dual && directional.skip_serializing;
+    let skip_deserializing = dual && directional.skip_deserializing;
+
+    if skip || (skip_serializing && skip_deserializing) {
         return Ok(());
     }
 
@@ -98,12 +261,25 @@ fn format_field(
         syn_err!("`type` is not compatible with `as`")
     }
 
+    if rename_all_inlined.is_some() && !inline {
+        syn_err!("`rename_all` can only be used on fields annotated with `inline`")
+    }
+
     let parsed_ty = if let Some(ref type_as) = type_as {
         syn::parse_str::<Type>(type_as)?
     } else {
         field.ty.clone()
     };
 
+    // `Option<T>` fields not annotated with `#[ts(optional)]` use the default from `ts-rs.toml`
+    let optional =
+        match optional.is_some() || type_override.is_some() || literal.is_some() || flatten {
+            false if extract_option_argument(&parsed_ty).is_ok() => {
+                crate::config::defaults().optional
+            }
+            _ => optional.unwrap_or_default(),
+        };
+
     let (ty, optional_annotation) = match optional {
         Optional {
             optional: true,
@@ -120,50 +296,185 @@ fn format_field(
         } => (&parsed_ty, ""),
     };
 
+    let bounds = match (&type_override, &literal) {
+        (None, None) => numeric_bounds(ty),
+        _ => None,
+    };
+
     if flatten {
         match (&type_as, &type_override, &rename, inline) {
             (Some(_), _, _, _) => syn_err!("`as` is not compatible with `flatten`"),
             (_, Some(_), _, _) => syn_err!("`type` is not compatible with `flatten`"),
             (_, _, Some(_), _) => syn_err!("`rename` is not compatible with `flatten`"),
             (_, _, _, true) => syn_err!("`inline` is not compatible with `flatten`"),
+            _ if readonly => syn_err!("`readonly` is not compatible with `flatten`"),
+            _ if revive.is_some() => syn_err!("`revive` is not compatible with `flatten`"),
+            _ if skip_serializing || skip_deserializing => {
+                syn_err!(
+                    "`skip_serializing` and `skip_deserializing` are not compatible with `flatten`"
+                )
+            }
             _ => {}
         }
 
-        flattened_fields.push(quote!(<#ty as ts_rs::TS>::inline_flattened()));
-        dependencies.append_from(ty);
+        // if the type doesn't depend on a generic parameter, check that it can be flattened
+        // when compiling instead of panicking when exporting
+        let check = match generics::mentions_generics(ty, generics) {
+            true => None,
+            false => {
+                let msg = format!(
+                    "`{}` cannot be flattened, only structs with named fields, enums and maps can",
+                    ty.to_token_stream().to_string().replace(' ', "")
+                );
+                Some(quote!(
+                    const _: () = assert!(<#ty as ts_rs::TS>::FLATTENABLE, #msg);
+                ))
+            }
+        };
+        let flattened = extract_option_argument(ty).unwrap_or(ty);
+        keys.flattened.push(quote!((
+            <#flattened as ts_rs::TS>::name(),
+            ts_rs::__private::flattened_keys::<#flattened>(),
+        )));
+        if attr.flatten_by_reference {
+            // the type is intersected with a reference to the flattened type, which works even if
+            // the flattened type is generic
+            let reference = format_type(flattened, dependencies, generics);
+            flattened_fields.push(match extract_option_argument(ty) {
+                Ok(_) => quote!({
+                    #check
+                    format!("Partial<{}>", #reference)
+                }),
+                Err(_) => quote!({
+                    #check
+                    #reference
+                }),
+            });
+        } else {
+            flattened_fields.push(quote!({
+                #check
+                <#ty as ts_rs::TS>::inline_flattened()
+            }));
+            dependencies.append_from(flattened);
+        }
+        // serde deserializes a flattened `Option` as `None` if its fields don't match, so it
+        // doesn't constrain the object
+        if extract_option_argument(ty).is_err() {
+            schema
+                .flattened
+                .push(quote!(<#ty as ts_rs::TS>::json_schema_ref()));
+        }
         return Ok(());
     }
 
-    let formatted_ty = type_override.map(|t| quote!(#t)).unwrap_or_else(|| {
-        if inline {
-            dependencies.append_from(ty);
-            quote!(<#ty as ts_rs::TS>::inline())
-        } else {
-            format_type(ty, dependencies, generics)
-        }
-    });
+    let literal = literal.as_ref().map(json_schema::value);
+    let field_schema = match (&literal, &type_override, inline) {
+        (Some(literal), ..) => json_schema::literal(format!(r#"{{"const":{}}}"#, literal)),
+        (_, Some(_), _) => json_schema::literal("{}"),
+        (_, None, true) => quote!(<#ty as ts_rs::TS>::json_schema()),
+        (_, None, false) => with_integer_types(large_integers, pointer_sized_integers, || {
+            format_schema(ty, generics)
+        }),
+    };
+
+    for ty in &uses {
+        dependencies.push_or_append_from(ty);
+    }
+    let formatted_ty = literal
+        .map(|literal| quote!(#literal.to_owned()))
+        .or_else(|| {
+            let ts_type = format_type_override(&type_override?, generics);
+            let field_name = field.ident.as_ref().map(|ident| ident.unraw().to_string());
+            record_type_override(&field_name.unwrap_or_default(), &ts_type, ty);
+            Some(ts_type)
+        })
+        .unwrap_or_else(|| {
+            if inline {
+                dependencies.append_from(ty);
+                match rename_all_inlined {
+                    Some(inflection) => {
+                        let case = inflection.as_str();
+                        quote!(ts_rs::__private::rename_fields(&<#ty as ts_rs::TS>::inline(), #case))
+                    }
+                    None => quote!(<#ty as ts_rs::TS>::inline()),
+                }
+            } else {
+                with_integer_types(large_integers, pointer_sized_integers, || {
+                    format_type(ty, dependencies, generics)
+                })
+            }
+        });
     let field_name = to_ts_ident(field.ident.as_ref().unwrap());
+    let rename_all = attr.rename_all.or(crate::config::defaults().rename_all);
     let name = match (rename, rename_all) {
         (Some(rn), _) => rn,
         (None, Some(rn)) => rn.apply(&field_name),
         (None, None) => field_name,
     };
+    // a field is optional in the serialize view if it's skipped conditionally, and in the
+    // deserialize view if it has a default value
+    let serialize_optional = match dual && directional.skip_serializing_if {
+        true => "?",
+        false => optional_annotation,
+    };
+    let deserialize_optional = match dual && (directional.default || attr.default) {
+        true => "?",
+        false => optional_annotation,
+    };
+    if !skip_serializing {
+        schema
+            .properties
+            .push((name.clone(), field_schema, serialize_optional.is_empty()));
+    }
     let valid_name = raw_name_to_ts_field(name);
-
-    // Start every doc string with a newline, because when other characters are in front, it is not "understood" by VSCode
-    let docs = match docs.is_empty() {
-        true => "".to_string(),
-        false => format!("\n{}", &docs),
+    let readonly_annotation = match readonly || attr.readonly {
+        true => "readonly ",
+        false => "",
     };
 
-    formatted_fields.push(quote! {
-        format!("{}{}{}: {},", #docs, #valid_name, #optional_annotation, #formatted_ty)
-    });
+    if let Some(revive) = revive {
+        append_doc_tags(&mut docs, &format!(" * @revive {}", revive.ts_type()));
+    }
+    if let Some(bounds) = bounds {
+        append_doc_tags(&mut docs, &bounds);
+    }
+
+    let docs = member_docs(&docs);
+
+    let format = |optional_annotation: &str, ty: &TokenStream| {
+        quote! {
+            format!("{}{}{}{}: {},", #docs, #readonly_annotation, #valid_name, #optional_annotation, #ty)
+        }
+    };
+    if !skip_deserializing {
+        input_fields.push(format(deserialize_optional, &formatted_ty));
+    }
+    if skip_serializing {
+        return Ok(());
+    }
+    keys.own.push(valid_name.clone());
+    revivers
+        .raw_fields
+        .push(format(serialize_optional, &formatted_ty));
+    match revive {
+        Some(revive) if attr.reviver => {
+            let nullable = extract_option_argument(ty).is_ok();
+            formatted_fields.push(format(
+                serialize_optional,
+                &reviver::revived_type(revive, nullable),
+            ));
+            let optional = nullable || !serialize_optional.is_empty();
+            revivers
+                .revived
+                .push((valid_name.clone(), revive, optional));
+        }
+        _ => formatted_fields.push(format(serialize_optional, &formatted_ty)),
+    }
 
     Ok(())
 }
 
-fn extract_option_argument(ty: &Type) -> Result<&Type> {
+pub(crate) fn extract_option_argument(ty: &Type) -> Result<&Type> {
     match ty {
         Type::Path(type_path)
             if type_path.qself.is_none()
@@ -2,9 +2,15 @@ use quote::quote;
 use syn::{FieldsUnnamed, Generics, Result, Type};
 
 use crate::{
-    attr::{FieldAttr, StructAttr},
+    attr::{FieldAttr, Optional, StructAttr},
+    config::with_integer_types,
     deps::Dependencies,
-    types::generics::{format_generics, format_type},
+    types::{
+        generics::{format_generics, format_schema, format_type, format_type_override},
+        json_schema,
+        named::extract_option_argument,
+    },
+    utils::{append_doc_tags, append_docs, numeric_bounds, record_type_override},
     DerivedTS,
 };
 
@@ -20,26 +26,70 @@ pub(crate) fn newtype(
     if attr.tag.is_some() {
         syn_err!("`tag` is not applicable to newtype structs");
     }
+    if attr.readonly {
+        syn_err!("`readonly` is not applicable to newtype structs");
+    }
+    if attr.reviver {
+        syn_err!("`reviver` is not applicable to newtype structs");
+    }
+    if attr.dual.is_some() {
+        syn_err!("`dual` is not applicable to newtype structs");
+    }
+    if attr.index_signature.is_some() {
+        syn_err!("`index_signature` is not applicable to newtype structs");
+    }
     let inner = fields.unnamed.first().unwrap();
     let FieldAttr {
         type_as,
         type_override,
+        uses,
+        literal,
+        large_integers,
+        pointer_sized_integers,
         rename: rename_inner,
         inline,
         skip,
         optional,
         flatten,
-        docs: _,
+        readonly,
+        revive,
+        rename_all,
+        directional: _,
+        docs: field_docs,
     } = FieldAttr::from_attrs(&inner.attrs)?;
 
+    let optional = optional.unwrap_or_default();
     match (&rename_inner, skip, optional.optional, flatten) {
         (Some(_), ..) => syn_err!("`rename` is not applicable to newtype fields"),
-        (_, true, ..) => return super::unit::null(attr, name),
-        (_, _, true, ..) => syn_err!("`optional` is not applicable to newtype fields"),
+        (_, true, ..) => {
+            let kind = quote!(ts_rs::TypeKind::Newtype);
+            return super::unit::null(attr, name).map(|derived| DerivedTS { kind, ..derived });
+        }
+        (_, _, true, ..) if !attr.optional_content => {
+            syn_err!(
+                "`optional` is only applicable to the fields of adjacently tagged newtype variants"
+            )
+        }
         (_, _, _, true) => syn_err!("`flatten` is not applicable to newtype fields"),
         _ => {}
     };
 
+    if readonly {
+        syn_err!("`readonly` is not applicable to newtype fields")
+    }
+
+    if rename_all.is_some() {
+        syn_err!("`rename_all` is not applicable to newtype fields")
+    }
+
+    if revive.is_some() {
+        syn_err!("`revive` is not applicable to newtype fields")
+    }
+
+    if literal.is_some() {
+        syn_err!("`literal` is not applicable to newtype fields")
+    }
+
     if type_as.is_some() && type_override.is_some() {
         syn_err!("`type` is not compatible with `as`")
     }
@@ -49,30 +99,78 @@ pub(crate) fn newtype(
     } else {
         inner.ty.clone()
     };
+    // the content of an adjacently tagged variant is omitted instead of being `null`
+    let inner_ty = match optional {
+        Optional {
+            optional: true,
+            nullable,
+        } => {
+            let option_argument = extract_option_argument(&inner_ty)?;
+            match nullable {
+                true => inner_ty.clone(),
+                false => option_argument.clone(),
+            }
+        }
+        Optional {
+            optional: false, ..
+        } => inner_ty,
+    };
 
     let mut dependencies = Dependencies::default();
 
     match (type_override.is_none(), inline) {
-        (false, _) => (),
+        (false, _) => {
+            for ty in &uses {
+                dependencies.push_or_append_from(ty);
+            }
+        }
         (true, true) => dependencies.append_from(&inner_ty),
         (true, false) => dependencies.push_or_append_from(&inner_ty),
     };
 
     let inline_def = match type_override {
-        Some(ref o) => quote!(#o.to_owned()),
+        Some(ref o) => {
+            let ts_type = format_type_override(o, generics);
+            record_type_override("0", &ts_type, &inner_ty);
+            ts_type
+        }
         None if inline => quote!(<#inner_ty as ts_rs::TS>::inline()),
-        None => format_type(&inner_ty, &mut dependencies, generics),
+        None => with_integer_types(large_integers, pointer_sized_integers, || {
+            format_type(&inner_ty, &mut dependencies, generics)
+        }),
     };
 
+    let json_schema = match type_override {
+        Some(_) => json_schema::literal("{}"),
+        None if inline => quote!(<#inner_ty as ts_rs::TS>::json_schema()),
+        None => with_integer_types(large_integers, pointer_sized_integers, || {
+            format_schema(&inner_ty, generics)
+        }),
+    };
+
+    // the newtype is declared as an alias of its field, so the docs of the field are appended to
+    // the docs of the type
+    let mut docs = attr.docs.clone();
+    append_docs(&mut docs, &field_docs);
+    if let (None, Some(bounds)) = (&type_override, numeric_bounds(&inner_ty)) {
+        append_doc_tags(&mut docs, &bounds);
+    }
+
     let generic_args = format_generics(&mut dependencies, generics);
     Ok(DerivedTS {
         decl: quote!(format!("type {}{} = {};", #name, #generic_args, #inline_def)),
         inline: inline_def,
         inline_flattened: None,
+        json_schema,
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(ts_rs::TypeKind::Newtype),
         name: name.to_owned(),
-        docs: attr.docs.clone(),
+        docs,
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
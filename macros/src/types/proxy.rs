@@ -0,0 +1,55 @@
+use quote::quote;
+use syn::{Generics, Result, Type};
+
+use crate::{
+    attr::StructAttr,
+    deps::Dependencies,
+    types::generics::{format_generics, format_schema, format_type},
+    DerivedTS,
+};
+
+/// Returns the type a struct or enum is (de)serialized as, given by `#[serde(into = "..")]`,
+/// `#[serde(from = "..")]` or `#[serde(try_from = "..")]`.
+/// If both are present, the type it is serialized as takes precedence.
+/// Types annotated with `#[ts(as_display)]` are serialized as a `String`, and `#[ts(as = "..")]`
+/// takes precedence over serde's attributes.
+pub(crate) fn proxy_type(attr: &StructAttr) -> Option<&str> {
+    match attr.as_display {
+        true => Some("String"),
+        false => attr
+            .type_as
+            .as_deref()
+            .or(attr.into.as_deref())
+            .or(attr.from.as_deref()),
+    }
+}
+
+/// Exports a type as an alias of the type it is (de)serialized as.
+pub(crate) fn proxy(
+    attr: &StructAttr,
+    name: &str,
+    proxy: &str,
+    generics: &Generics,
+) -> Result<DerivedTS> {
+    let proxy = syn::parse_str::<Type>(proxy)?;
+
+    let mut dependencies = Dependencies::default();
+    let inline = format_type(&proxy, &mut dependencies, generics);
+    let generic_args = format_generics(&mut dependencies, generics);
+    Ok(DerivedTS {
+        decl: quote!(format!("type {}{} = {};", #name, #generic_args, #inline)),
+        inline,
+        inline_flattened: None,
+        json_schema: format_schema(&proxy, generics),
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(<#proxy as ts_rs::TS>::KIND),
+        name: name.to_owned(),
+        docs: attr.docs.clone(),
+        dependencies,
+        export: attr.export,
+        export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
+    })
+}
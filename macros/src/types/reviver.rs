@@ -0,0 +1,89 @@
+// helpers for `#[ts(reviver)]`, which generates functions converting between the JSON
+// representation of a struct and its revived form, see `#[ts(revive = "..")]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attr::Revive;
+
+/// The fields of a struct, as they are declared by its `Raw` type, and the fields which are
+/// revived, collected while formatting its fields.
+#[derive(Default)]
+pub struct Revivers {
+    pub raw_fields: Vec<TokenStream>,
+    // the field as declared in TypeScript, how it's revived and whether it may be `null`
+    pub revived: Vec<(String, Revive, bool)>,
+}
+
+/// Returns declarations of `function revive<name>(raw: <name>Raw): <name>` and
+/// `function serialize<name>(value: <name>): <name>Raw`.
+pub fn functions(name: &str, raw_name: &str, revived: &[(String, Revive, bool)]) -> [String; 2] {
+    let revive = revived
+        .iter()
+        .map(|(field, revive, nullable)| convert(field, "raw", *nullable, revive_expr(*revive)))
+        .collect::<String>();
+    let serialize = revived
+        .iter()
+        .map(|(field, revive, nullable)| {
+            convert(field, "value", *nullable, serialize_expr(*revive))
+        })
+        .collect::<String>();
+
+    [
+        format!(
+            "function revive{0}(raw: {1}): {0} {{ return {{ ...raw, {2}}}; }}",
+            name, raw_name, revive
+        ),
+        format!(
+            "function serialize{0}(value: {0}): {1} {{ return {{ ...value, {2}}}; }}",
+            name, raw_name, serialize
+        ),
+    ]
+}
+
+/// The TypeScript type of a revived field.
+pub fn revived_type(revive: Revive, nullable: bool) -> TokenStream {
+    let ty = match nullable {
+        true => format!("{} | null", revive.ts_type()),
+        false => revive.ts_type().to_owned(),
+    };
+    quote!(#ty)
+}
+
+// `field: convert(object.field), `, skipping `null` and `undefined` if the field is nullable
+fn convert(field: &str, object: &str, nullable: bool, convert: impl Fn(&str) -> String) -> String {
+    let access = match field.starts_with('"') {
+        true => format!("{}[{}]", object, field),
+        false => format!("{}.{}", object, field),
+    };
+    match nullable {
+        true => format!(
+            "{}: {1} == null ? {1} : {2}, ",
+            field,
+            access,
+            convert(&access)
+        ),
+        false => format!("{}: {}, ", field, convert(&access)),
+    }
+}
+
+fn revive_expr(revive: Revive) -> impl Fn(&str) -> String {
+    move |value| match revive {
+        Revive::Date => format!("new Date({})", value),
+        Revive::BigInt => format!("BigInt({})", value),
+        Revive::Bytes => format!("Uint8Array.from(atob({}), (c) => c.charCodeAt(0))", value),
+    }
+}
+
+fn serialize_expr(revive: Revive) -> impl Fn(&str) -> String {
+    move |value| match revive {
+        Revive::Date => format!("{}.toISOString()", value),
+        Revive::BigInt => format!("{}.toString()", value),
+        // spreading the bytes into `String.fromCharCode` exceeds the maximum number of arguments
+        // for large payloads
+        Revive::Bytes => format!(
+            "btoa(Array.from({}, (b) => String.fromCharCode(b)).join(\"\"))",
+            value
+        ),
+    }
+}
@@ -1,11 +1,17 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Field, FieldsUnnamed, Generics, Result, Type};
+use syn::{spanned::Spanned, Field, FieldsUnnamed, Generics, Result, Type};
 
 use crate::{
-    attr::{FieldAttr, StructAttr},
+    attr::{FieldAttr, Optional, StructAttr},
+    config::with_integer_types,
     deps::Dependencies,
-    types::generics::{format_generics, format_type},
+    types::{
+        generics::{format_generics, format_schema, format_type, format_type_override},
+        json_schema,
+        named::extract_option_argument,
+    },
+    utils::{append_doc_tags, member_docs, numeric_bounds, record_type_override},
     DerivedTS,
 };
 
@@ -21,12 +27,35 @@ pub(crate) fn tuple(
     if attr.tag.is_some() {
         syn_err!("`tag` is not applicable to tuple structs");
     }
+    if attr.readonly {
+        syn_err!("`readonly` is not applicable to tuple structs");
+    }
+    if attr.reviver {
+        syn_err!("`reviver` is not applicable to tuple structs");
+    }
+    if attr.dual.is_some() {
+        syn_err!("`dual` is not applicable to tuple structs");
+    }
+    if attr.index_signature.is_some() {
+        syn_err!("`index_signature` is not applicable to tuple structs");
+    }
 
     let mut formatted_fields = Vec::new();
+    let mut schemas = Vec::new();
     let mut dependencies = Dependencies::default();
-    for field in &fields.unnamed {
-        format_field(&mut formatted_fields, &mut dependencies, field, generics)?;
+    let mut optional_fields = 0;
+    for (index, field) in fields.unnamed.iter().enumerate() {
+        format_field(
+            &mut formatted_fields,
+            &mut schemas,
+            &mut dependencies,
+            &mut optional_fields,
+            index,
+            field,
+            generics,
+        )?;
     }
+    let required_fields = schemas.len() - optional_fields;
 
     let generic_args = format_generics(&mut dependencies, generics);
     Ok(DerivedTS {
@@ -45,36 +74,53 @@ pub(crate) fn tuple(
             )
         },
         inline_flattened: None,
+        json_schema: json_schema::tuple(schemas, required_fields),
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(ts_rs::TypeKind::Tuple),
         name: name.to_owned(),
         docs: attr.docs.clone(),
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
 
 fn format_field(
     formatted_fields: &mut Vec<TokenStream>,
+    schemas: &mut Vec<TokenStream>,
     dependencies: &mut Dependencies,
+    optional_fields: &mut usize,
+    index: usize,
     field: &Field,
     generics: &Generics,
 ) -> Result<()> {
     let FieldAttr {
         type_as,
         type_override,
+        uses,
+        literal,
+        large_integers,
+        pointer_sized_integers,
         rename,
         inline,
         skip,
         optional,
         flatten,
-        docs: _,
+        readonly,
+        revive,
+        rename_all,
+        directional: _,
+        mut docs,
     } = FieldAttr::from_attrs(&field.attrs)?;
 
     if skip {
         return Ok(());
     }
 
-    let ty = if let Some(ref type_as) = type_as {
+    let parsed_ty = if let Some(ref type_as) = type_as {
         syn::parse_str::<Type>(type_as)?
     } else {
         field.ty.clone()
@@ -88,22 +134,91 @@ fn format_field(
         syn_err!("`rename` is not applicable to tuple structs")
     }
 
-    if optional.optional {
-        syn_err!("`optional` is not applicable to tuple fields")
+    if rename_all.is_some() {
+        syn_err!("`rename_all` is not applicable to tuple fields")
     }
 
+    // like named fields, an optional `Option<T>` becomes `T?`, or `(T | null)?` if it's nullable.
+    // Since TypeScript doesn't allow required elements after optional ones, only trailing fields
+    // can be optional.
+    let optional = optional.unwrap_or_default();
+    let ty = match optional {
+        Optional {
+            optional: true,
+            nullable,
+        } => {
+            *optional_fields += 1;
+            let inner_type = extract_option_argument(&parsed_ty)?;
+            match nullable {
+                true => parsed_ty.clone(),
+                false => inner_type.clone(),
+            }
+        }
+        Optional {
+            optional: false, ..
+        } if *optional_fields > 0 => {
+            syn_err!(field.span(); "a tuple field can't follow an optional field unless it's optional too")
+        }
+        Optional {
+            optional: false, ..
+        } => parsed_ty,
+    };
+
     if flatten {
         syn_err!("`flatten` is not applicable to tuple fields")
     }
 
-    formatted_fields.push(match type_override {
-        Some(ref o) => quote!(#o.to_owned()),
+    if readonly {
+        syn_err!("`readonly` is not applicable to tuple fields")
+    }
+
+    if revive.is_some() {
+        syn_err!("`revive` is not applicable to tuple fields")
+    }
+
+    if literal.is_some() {
+        syn_err!("`literal` is not applicable to tuple fields")
+    }
+
+    let formatted_ty = match type_override {
+        Some(ref o) => {
+            let ts_type = format_type_override(o, generics);
+            record_type_override(&index.to_string(), &ts_type, &ty);
+            ts_type
+        }
         None if inline => quote!(<#ty as ts_rs::TS>::inline()),
-        None => format_type(&ty, dependencies, generics),
+        None => with_integer_types(large_integers, pointer_sized_integers, || {
+            format_type(&ty, dependencies, generics)
+        }),
+    };
+    let formatted_ty = match optional.optional {
+        true => quote!(ts_rs::__private::optional_element(&#formatted_ty)),
+        false => formatted_ty,
+    };
+    if let (None, Some(bounds)) = (&type_override, numeric_bounds(&ty)) {
+        append_doc_tags(&mut docs, &bounds);
+    }
+    formatted_fields.push(match docs.is_empty() {
+        true => formatted_ty,
+        false => {
+            let docs = member_docs(&docs);
+            quote!(format!("{}{}", #docs, #formatted_ty))
+        }
+    });
+    schemas.push(match type_override {
+        Some(_) => json_schema::literal("{}"),
+        None if inline => quote!(<#ty as ts_rs::TS>::json_schema()),
+        None => with_integer_types(large_integers, pointer_sized_integers, || {
+            format_schema(&ty, generics)
+        }),
     });
 
     match (inline, type_override) {
-        (_, Some(_)) => (),
+        (_, Some(_)) => {
+            for ty in &uses {
+                dependencies.push_or_append_from(ty);
+            }
+        }
         (false, _) => {
             dependencies.push_or_append_from(&ty);
         }
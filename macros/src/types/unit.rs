@@ -1,20 +1,30 @@
 use quote::quote;
 use syn::Result;
 
+use super::json_schema;
 use crate::{attr::StructAttr, deps::Dependencies, DerivedTS};
 
 pub(crate) fn empty_object(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
     check_attributes(attr)?;
 
+    let ty = crate::config::defaults()
+        .empty_struct
+        .unwrap_or("Record<string, never>");
     Ok(DerivedTS {
-        inline: quote!("Record<string, never>".to_owned()),
-        decl: quote!(format!("type {} = Record<string, never>;", #name)),
+        inline: quote!(#ty.to_owned()),
+        json_schema: json_schema::literal(r#"{"type":"object","maxProperties":0}"#),
+        decl: quote!(format!("type {} = {};", #name, #ty)),
         inline_flattened: None,
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(ts_rs::TypeKind::Unit),
         name: name.to_owned(),
         docs: attr.docs.clone(),
         dependencies: Dependencies::default(),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
 
@@ -23,13 +33,19 @@ pub(crate) fn empty_array(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
 
     Ok(DerivedTS {
         inline: quote!("never[]".to_owned()),
+        json_schema: json_schema::literal(r#"{"type":"array","maxItems":0}"#),
         decl: quote!(format!("type {} = never[];", #name)),
         inline_flattened: None,
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(ts_rs::TypeKind::Unit),
         name: name.to_owned(),
         docs: attr.docs.clone(),
         dependencies: Dependencies::default(),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
 
@@ -38,13 +54,19 @@ pub(crate) fn null(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
 
     Ok(DerivedTS {
         inline: quote!("null".to_owned()),
+        json_schema: json_schema::literal(r#"{"type":"null"}"#),
         decl: quote!(format!("type {} = null;", #name)),
         inline_flattened: None,
+        extra_decls: Vec::new(),
+        flattened_keys: None,
+        kind: quote!(ts_rs::TypeKind::Unit),
         name: name.to_owned(),
         docs: attr.docs.clone(),
         dependencies: Dependencies::default(),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        also_export_to: attr.also_export_to.clone(),
+        export_cfg: attr.export_cfg.clone(),
     })
 }
 
@@ -57,5 +79,19 @@ fn check_attributes(attr: &StructAttr) -> Result<()> {
         syn_err!("`tag` is not applicable to unit structs");
     }
 
+    if attr.readonly {
+        syn_err!("`readonly` is not applicable to unit structs");
+    }
+
+    if attr.reviver {
+        syn_err!("`reviver` is not applicable to unit structs");
+    }
+    if attr.dual.is_some() {
+        syn_err!("`dual` is not applicable to unit structs");
+    }
+    if attr.index_signature.is_some() {
+        syn_err!("`index_signature` is not applicable to unit structs");
+    }
+
     Ok(())
 }
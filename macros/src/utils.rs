@@ -1,7 +1,11 @@
-use std::convert::TryFrom;
+use std::{cell::RefCell, convert::TryFrom};
 
-use proc_macro2::Ident;
-use syn::{spanned::Spanned, Attribute, Error, Expr, ExprLit, Lit, Meta, Result};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    spanned::Spanned, Attribute, Error, Expr, ExprLit, GenericArgument, Lit, Meta, PathArguments,
+    Result, Type,
+};
 
 macro_rules! syn_err {
     ($l:literal $(, $a:expr)*) => {
@@ -55,6 +59,98 @@ pub fn to_ts_ident(ident: &Ident) -> String {
     }
 }
 
+// words which cannot be used as the name of a declaration in JavaScript
+const RESERVED_WORDS: [&str; 46] = [
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+// types predefined by TypeScript, which cannot be used as the name of a type alias
+const PREDEFINED_TYPES: [&str; 10] = [
+    "any",
+    "bigint",
+    "boolean",
+    "never",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+    "unknown",
+];
+
+/// Returns the name of the type `ident`, which is given by `#[ts(rename = "..")]` or by
+/// `type_names` in `ts-rs.toml`. `{name}` within them is replaced by the name of the type in Rust,
+/// e.g. `#[ts(rename = "Api{name}")]`.
+pub fn type_name(rename: Option<&str>, ident: &Ident) -> String {
+    let type_names = crate::config::defaults().type_names;
+    match rename.or(type_names.as_deref()) {
+        Some(template) => template.replace("{name}", &to_ts_ident(ident)),
+        None => to_ts_ident(ident),
+    }
+}
+
+/// Returns why `name` cannot be the name of a declaration, e.g. because it's a reserved word.
+/// Types additionally cannot be named after a predefined type, like `string`.
+pub fn invalid_ts_name(name: &str, is_type: bool) -> Option<&'static str> {
+    let is_ident = name.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    match () {
+        _ if !is_ident => Some("is not a valid identifier in TypeScript"),
+        _ if RESERVED_WORDS.contains(&name) => Some("is a reserved word in TypeScript"),
+        _ if is_type && PREDEFINED_TYPES.contains(&name) => {
+            Some("is the name of a type predefined by TypeScript")
+        }
+        _ => None,
+    }
+}
+
 /// Convert an arbitrary name to a valid Typescript field name.
 ///
 /// If the name contains special characters it will be wrapped in quotes.
@@ -99,9 +195,13 @@ pub fn parse_serde_attrs<'a, A: TryFrom<&'a Attribute, Error = Error>>(
         .flat_map(|attr| match A::try_from(attr) {
             Ok(attr) => Some(attr),
             Err(_) => {
-                #[cfg(not(feature = "no-serde-warnings"))]
                 use quote::ToTokens;
 
+                record_warning(format!(
+                    "failed to parse serde attribute `{}`, it was ignored",
+                    attr.to_token_stream()
+                ));
+
                 #[cfg(not(feature = "no-serde-warnings"))]
                 warning::print_warning(
                     "failed to parse serde attribute",
@@ -116,9 +216,169 @@ pub fn parse_serde_attrs<'a, A: TryFrom<&'a Attribute, Error = Error>>(
         .into_iter()
 }
 
+/// Parse all `#[strum(..)]` attributes from the given slice.
+/// `f` is called with every key and the input following it, and returns whether it consumed the
+/// value of the key. The values of all other keys are skipped, since most of them don't affect
+/// the names of variants.
+#[cfg(feature = "strum-interop")]
+pub fn parse_strum_attrs(
+    attrs: &[Attribute],
+    mut f: impl FnMut(&str, syn::parse::ParseStream) -> Result<bool>,
+) -> Result<()> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("strum")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(ToString::to_string);
+            if f(key.as_deref().unwrap_or_default(), meta.input)? {
+                return Ok(());
+            }
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Parse all `#[validate(..)]` and `#[garde(..)]` attributes of a field, used by the `validator`
+/// and `garde` crates, into JSDoc tags like ` * @minLength 1`.
+/// Only rules with literal arguments are supported, others are skipped.
+#[cfg(feature = "validator-interop")]
+pub fn parse_validation_attrs(attrs: &[Attribute]) -> Result<Vec<String>> {
+    use syn::{parse::ParseStream, LitStr, Token, UnOp};
+
+    // formats a numeric argument, like `1` or `-1.5`
+    fn number(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => Some(int.base10_digits().to_owned()),
+            Expr::Lit(ExprLit {
+                lit: Lit::Float(float),
+                ..
+            }) => Some(float.base10_digits().to_owned()),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                number(&unary.expr).map(|number| format!("-{}", number))
+            }
+            _ => None,
+        }
+    }
+
+    // skips the value of a key which doesn't translate to a tag
+    fn skip(input: ParseStream) -> Result<()> {
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            input.parse::<Expr>()?;
+        } else if input.peek(syn::token::Paren) {
+            input.parse::<proc_macro2::Group>()?;
+        }
+        Ok(())
+    }
+
+    let mut tags = Vec::new();
+    let attrs = attrs
+        .iter()
+        .filter(|a| a.path().is_ident("validate") || a.path().is_ident("garde"));
+    for attr in attrs {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(ToString::to_string);
+            match key.as_deref().unwrap_or_default() {
+                format @ ("email" | "url" | "ip" | "ipv4" | "ipv6") => {
+                    tags.push(format!(" * @format {}", format));
+                    skip(meta.input)
+                }
+                rule @ ("length" | "range") if meta.input.peek(syn::token::Paren) => {
+                    let (min, max) = match rule {
+                        "length" => ("minLength", "maxLength"),
+                        _ => ("minimum", "maximum"),
+                    };
+                    meta.parse_nested_meta(|arg| {
+                        let key = arg.path.get_ident().map(ToString::to_string);
+                        let tag = match key.as_deref().unwrap_or_default() {
+                            "min" => vec![min],
+                            "max" => vec![max],
+                            "equal" => vec![min, max],
+                            "exclusive_min" => vec!["exclusiveMinimum"],
+                            "exclusive_max" => vec!["exclusiveMaximum"],
+                            _ => return skip(arg.input),
+                        };
+                        if !arg.input.peek(Token![=]) {
+                            return Ok(());
+                        }
+                        if let Some(value) = number(&arg.value()?.parse::<Expr>()?) {
+                            tags.extend(tag.iter().map(|tag| format!(" * @{} {}", tag, value)));
+                        }
+                        Ok(())
+                    })
+                }
+                // garde's `pattern(r"..")`
+                "pattern" if meta.input.peek(syn::token::Paren) => {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    if content.peek(LitStr) {
+                        let pattern = content.parse::<LitStr>()?.value();
+                        tags.push(format!(" * @pattern {}", pattern.replace("*/", "*\\/")));
+                    }
+                    content.parse::<proc_macro2::TokenStream>()?;
+                    Ok(())
+                }
+                _ => skip(meta.input),
+            }
+        })?;
+    }
+    Ok(tags)
+}
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static TYPE_OVERRIDES: RefCell<Vec<TokenStream>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records a warning about the type currently being derived, unless it was recorded before.
+/// Warnings are made available through `TS::warnings`, and written to `ts-rs-warnings.json`
+/// when exporting.
+pub fn record_warning(message: String) {
+    WARNINGS.with(|warnings| {
+        let mut warnings = warnings.borrow_mut();
+        if !warnings.contains(&message) {
+            warnings.push(message);
+        }
+    });
+}
+
+/// Returns all warnings recorded since the last call.
+pub fn take_warnings() -> Vec<String> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+/// Records that the type of `field`, which is `ty`, is overridden by `ts_type`, an expression
+/// evaluating to the type given by `#[ts(type = "..")]`.
+/// Overrides are made available through `TS::type_overrides`, and checked for being stale when
+/// exporting.
+pub fn record_type_override(field: &str, ts_type: &TokenStream, ty: &Type) {
+    let type_override = quote! {
+        ts_rs::__private::TypeOverride {
+            field: #field,
+            ts_type: #ts_type,
+            actual: {
+                use ts_rs::__private::{ImplementsTs as _, NotTs as _};
+                (&&ts_rs::__private::FieldType::<#ty>(std::marker::PhantomData)).ts_type()
+            },
+        }
+    };
+    TYPE_OVERRIDES.with(|overrides| overrides.borrow_mut().push(type_override));
+}
+
+/// Returns all type overrides recorded since the last call.
+pub fn take_type_overrides() -> Vec<TokenStream> {
+    TYPE_OVERRIDES.with(|overrides| overrides.take())
+}
+
 /// Return doc comments parsed and formatted as JSDoc.
 pub fn parse_docs(attrs: &[Attribute]) -> Result<String> {
-    let lines = attrs
+    let docs = attrs
         .iter()
         .filter_map(|a| match a.meta {
             Meta::NameValue(ref x) if x.path.is_ident("doc") => Some(x),
@@ -131,20 +391,159 @@ pub fn parse_docs(attrs: &[Attribute]) -> Result<String> {
             }) => Ok(str.value()),
             _ => syn_err!(attr.span(); "doc attribute with non literal expression found"),
         })
-        .map(|attr| {
-            attr.map(|line| match line.trim() {
-                "" => " *".to_owned(),
-                _ => format!(" *{}", line.trim_end())
-            })
-        })
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(match lines.is_empty() {
+    // block comments like `/** .. */` span multiple lines
+    let lines = jsdoc_lines(docs.iter().flat_map(|doc| doc.split('\n')));
+    let mut docs = match lines.is_empty() {
         true => "".to_owned(),
         false => format!("/**\n{}\n */\n", lines.join("\n")),
+    };
+    if let Some(note) = parse_deprecated(attrs)? {
+        let tag = match note {
+            Some(note) => format!(" * @deprecated {}", note.replace("*/", "*\\/")),
+            None => " * @deprecated".to_owned(),
+        };
+        append_doc_tags(&mut docs, &tag);
+    }
+    Ok(docs)
+}
+
+// parses `#[deprecated]`, `#[deprecated = ".."]` or `#[deprecated(since = "..", note = "..")]`,
+// returning the note, if any
+fn parse_deprecated(attrs: &[Attribute]) -> Result<Option<Option<String>>> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+    let note = match &attr.meta {
+        Meta::Path(_) => None,
+        Meta::NameValue(meta) => match &meta.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(note),
+                ..
+            }) => Some(note.value()),
+            _ => syn_err!(meta.value.span(); "expected string"),
+        },
+        Meta::List(_) => {
+            let mut note = None;
+            attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<syn::LitStr>()?;
+                if meta.path.is_ident("note") {
+                    note = Some(value.value());
+                }
+                Ok(())
+            })?;
+            note
+        }
+    };
+    Ok(Some(note))
+}
+
+// formats lines of rustdoc markdown as lines of a JSDoc comment. Code blocks are labeled with
+// their language, and lines which rustdoc hides from Rust code blocks, starting with `# `, are
+// removed. `*/` is escaped, since it would end the comment.
+fn jsdoc_lines<'a>(docs: impl Iterator<Item = &'a str>) -> Vec<String> {
+    // whether the current line is within a code block, and if it contains Rust code
+    let mut code_block: Option<bool> = None;
+    let mut lines = Vec::new();
+    for line in docs {
+        let trimmed = line.trim();
+        let line = match (trimmed.strip_prefix("```"), code_block) {
+            (Some(info), None) => {
+                let rust = is_rust_code_block(info);
+                code_block = Some(rust);
+                match rust {
+                    true => format!("{}```rust", &line[..line.find("```").unwrap()]),
+                    false => line.to_owned(),
+                }
+            }
+            (Some(_), Some(_)) => {
+                code_block = None;
+                line.to_owned()
+            }
+            (None, Some(true)) if trimmed == "#" || trimmed.starts_with("# ") => continue,
+            (None, Some(true)) if trimmed.starts_with("##") => line.replacen("##", "#", 1),
+            (None, _) => line.to_owned(),
+        };
+        lines.push(match line.trim() {
+            "" => " *".to_owned(),
+            _ => format!(" *{}", line.trim_end().replace("*/", "*\\/")),
+        });
+    }
+    lines
+}
+
+// code blocks without a language, or annotated with rustdoc attributes like `ignore`, are Rust
+fn is_rust_code_block(info: &str) -> bool {
+    info.split(',').map(str::trim).all(|attr| {
+        matches!(
+            attr,
+            "" | "rust" | "ignore" | "should_panic" | "no_run" | "compile_fail" | "test_harness"
+        ) || attr.starts_with("edition")
     })
 }
 
+/// Appends JSDoc tags, given as lines like ` * @template T`, to docs returned by [`parse_docs`].
+pub fn append_doc_tags(docs: &mut String, tags: &str) {
+    *docs = match docs.strip_suffix("\n */\n") {
+        Some(lines) => format!("{}\n *\n{}\n */\n", lines, tags),
+        None => format!("/**\n{}\n */\n", tags),
+    };
+}
+
+/// Returns `@minimum` and `@maximum` tags documenting the range of `ty`, or of `T` if it's an
+/// `Option<T>`, if it's an integer narrower than 64 bits and `numeric_bounds` is set in
+/// `ts-rs.toml`.
+pub fn numeric_bounds(ty: &Type) -> Option<String> {
+    if !crate::config::defaults().numeric_bounds {
+        return None;
+    }
+    let last = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        Type::Reference(reference) => return numeric_bounds(&reference.elem),
+        _ => return None,
+    };
+    if let PathArguments::AngleBracketed(args) = &last.arguments {
+        return match (last.ident == "Option", args.args.first()) {
+            (true, Some(GenericArgument::Type(inner))) => numeric_bounds(inner),
+            _ => None,
+        };
+    }
+    let (min, max) = match last.ident.to_string().as_str() {
+        "u8" => ("0", "255"),
+        "NonZeroU8" => ("1", "255"),
+        "i8" | "NonZeroI8" => ("-128", "127"),
+        "u16" => ("0", "65535"),
+        "NonZeroU16" => ("1", "65535"),
+        "i16" | "NonZeroI16" => ("-32768", "32767"),
+        "u32" => ("0", "4294967295"),
+        "NonZeroU32" => ("1", "4294967295"),
+        "i32" | "NonZeroI32" => ("-2147483648", "2147483647"),
+        _ => return None,
+    };
+    Some(format!(" * @minimum {}\n * @maximum {}", min, max))
+}
+
+/// Appends the lines of `other` to `docs`, both returned by [`parse_docs`].
+pub fn append_docs(docs: &mut String, other: &str) {
+    if let Some(lines) = other
+        .strip_prefix("/**\n")
+        .and_then(|other| other.strip_suffix("\n */\n"))
+    {
+        append_doc_tags(docs, lines);
+    }
+}
+
+/// Formats docs returned by [`parse_docs`] to be placed in front of a member of a type, like a
+/// field or a variant.
+/// They start with a newline, because VSCode doesn't pick them up if other characters precede them.
+pub fn member_docs(docs: &str) -> String {
+    match docs.is_empty() {
+        true => String::new(),
+        false => format!("\n{}", docs),
+    }
+}
+
 #[cfg(feature = "serde-compat")]
 mod warning {
     use std::{fmt::Display, io::Write};
@@ -0,0 +1,90 @@
+//! A line-based diff between the contents of a file on disk and the contents it would be exported
+//! with, used to describe out-of-date files when checking the bindings.
+
+/// Lines of unchanged context shown around every change
+const CONTEXT: usize = 2;
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Describes how `old` differs from `new`, line by line. Removed lines are prefixed with `-`,
+/// added lines with `+`, and every group of changes starts with `@@ line N @@`, `N` being the line
+/// in `old` it starts at.
+pub(crate) fn diff(old: &str, new: &str) -> String {
+    let lines = diff_lines(
+        &old.lines().collect::<Vec<_>>(),
+        &new.lines().collect::<Vec<_>>(),
+    );
+    let changed = |line: &Line| !matches!(line, Line::Same(_));
+
+    let mut out = String::new();
+    let mut old_line = 1;
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(start) = lines[i..].iter().position(changed).map(|pos| i + pos) else {
+            break;
+        };
+        // a group ends when more than twice the context separates it from the next change
+        let mut end = start;
+        while let Some(next) = lines[end + 1..].iter().position(changed) {
+            if next > 2 * CONTEXT {
+                break;
+            }
+            end += next + 1;
+        }
+        let from = start.saturating_sub(CONTEXT).max(i);
+        let to = (end + CONTEXT + 1).min(lines.len());
+
+        old_line += lines[i..from]
+            .iter()
+            .filter(|line| !matches!(line, Line::Added(_)))
+            .count();
+        out.push_str(&format!("@@ line {} @@\n", old_line));
+        for line in &lines[from..to] {
+            let (prefix, text) = match line {
+                Line::Same(text) => (' ', text),
+                Line::Removed(text) => ('-', text),
+                Line::Added(text) => ('+', text),
+            };
+            out.push_str(&format!("{}{}\n", prefix, text));
+            if !matches!(line, Line::Added(_)) {
+                old_line += 1;
+            }
+        }
+        i = to;
+    }
+    out
+}
+
+// aligns `old` and `new` along their longest common subsequence of lines
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    // `common[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
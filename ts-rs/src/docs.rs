@@ -0,0 +1,108 @@
+//! Conversion of rustdoc intra-doc links within exported doc comments, which can only be resolved
+//! once it's known which types are declared in or imported into a file.
+
+/// Rewrites the intra-doc links within the JSDoc comments of `decls`, e.g. ``[`User`]`` or
+/// `[the user](crate::User)`. Links to types which are in scope become `{@link User}`, other
+/// links are replaced by their text, e.g. `` `User` ``. Code is left untouched.
+pub(crate) fn resolve_links(decls: &str, in_scope: &dyn Fn(&str) -> bool) -> String {
+    let mut out = String::with_capacity(decls.len());
+    let mut rest = decls;
+    while let Some(start) = rest.find("/**") {
+        let end = rest[start..]
+            .find("*/")
+            .map_or(rest.len(), |end| start + end + 2);
+        out.push_str(&rest[..start]);
+        out.push_str(&resolve_comment(&rest[start..end], in_scope));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn resolve_comment(comment: &str, in_scope: &dyn Fn(&str) -> bool) -> String {
+    let mut code_block = false;
+    let lines = comment.split('\n').map(|line| {
+        let text = line.trim_start().trim_start_matches('*').trim_start();
+        if text.starts_with("```") {
+            code_block = !code_block;
+            return line.to_owned();
+        }
+        match code_block {
+            true => line.to_owned(),
+            false => resolve_line(line, in_scope),
+        }
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+fn resolve_line(line: &str, in_scope: &dyn Fn(&str) -> bool) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(i) = rest.find(['[', '`']) {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with('`') {
+            // inline code is copied verbatim
+            let end = rest[1..].find('`').map_or(rest.len(), |end| end + 2);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        match link(rest) {
+            Some((len, text, target)) => {
+                out.push_str(&format_link(text, target, in_scope));
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// parses an intra-doc link at the start of `s`, returning its length, its text if it differs
+// from the target, and the path it points to
+fn link(s: &str) -> Option<(usize, Option<&str>, &str)> {
+    let close = s.find(']')?;
+    let text = &s[1..close];
+    let after = &s[close + 1..];
+    let (len, text, target) = match after.chars().next() {
+        Some(open @ ('(' | '[')) => {
+            let end = after.find(if open == '(' { ')' } else { ']' })?;
+            (close + end + 2, Some(text), &after[1..end])
+        }
+        _ => (close + 1, None, text.trim_matches('`')),
+    };
+    is_path(target).then_some((len, text, target))
+}
+
+// whether `target` is a path to an item, like `User`, `crate::User` or `struct@User`, as
+// opposed to a URL
+fn is_path(target: &str) -> bool {
+    let is_ident = |s: &str| {
+        s.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+    };
+    let path = strip_disambiguator(target);
+    path.split("::").all(is_ident)
+}
+
+// removes prefixes like `struct@` and suffixes like `()` and `!`
+fn strip_disambiguator(target: &str) -> &str {
+    let target = target.split_once('@').map_or(target, |(_, path)| path);
+    target.trim_end_matches("()").trim_end_matches('!')
+}
+
+fn format_link(text: Option<&str>, target: &str, in_scope: &dyn Fn(&str) -> bool) -> String {
+    let path = strip_disambiguator(target);
+    let name = path.rsplit("::").next().unwrap_or(path);
+    match (in_scope(name), text) {
+        (true, None) => format!("{{@link {}}}", name),
+        (true, Some(text)) => format!("{{@link {} | {}}}", name, text),
+        (false, None) => format!("`{}`", path),
+        (false, Some(text)) => text.to_owned(),
+    }
+}
@@ -1,16 +1,19 @@
 use std::{
-    any::TypeId,
-    collections::BTreeMap,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, HashSet},
     fmt::Write,
     path::{Component, Path, PathBuf},
-    sync::Mutex,
-    sync::OnceLock,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, OnceLock,
+    },
 };
 
 use thiserror::Error;
+use ts_rs_config::{Config, Profile};
 use ExportError::*;
 
-use crate::TS;
+use crate::{style::Style, TS};
 
 const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
 
@@ -26,20 +29,89 @@ pub enum ExportError {
     Io(#[from] std::io::Error),
     #[error("the environment variable CARGO_MANIFEST_DIR is not set")]
     ManifestDirNotSet,
+    #[error("the profile `{0}` selected by TS_RS_PROFILE is not defined in ts-rs.toml")]
+    UnknownProfile(String),
+    #[error("`{key}` of `{ts_name}` is defined by both `{first}` and `{second}`")]
+    FlattenConflict {
+        ts_name: String,
+        key: String,
+        first: String,
+        second: String,
+    },
+    #[error("`{first}` and `{second}` are both exported as `{ts_name}` to `{export_to}`")]
+    NameCollision {
+        ts_name: String,
+        export_to: String,
+        first: &'static str,
+        second: &'static str,
+    },
+    #[error("`{}` is out of date, export the bindings again to update it:\n{diff}", path.display())]
+    OutOfDate { path: PathBuf, diff: String },
+    #[error("`{0}` declares values, which cannot be exported as ambient declarations")]
+    AmbientValue(String),
+    #[error("the files of `{first}` and `{second}` collide at `{export_to}`")]
+    FileNameCollision {
+        export_to: String,
+        first: &'static str,
+        second: &'static str,
+    },
 }
 
 pub(crate) use recursive_export::export_type_with_dependencies;
 mod recursive_export {
-    use std::{any::TypeId, collections::HashSet};
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        sync::Mutex,
+    };
 
-    use super::export_type;
+    use super::{
+        check_ambient, for_each_profile, generate_decl, generate_imports, manifest, merge_cycles,
+        output_path, push_header, write_file, write_manifest, write_warnings,
+    };
     use crate::{
+        docs::resolve_links,
+        export::__private::{same_type, TypeOverride},
         typelist::{TypeList, TypeVisitor},
-        ExportError, TS,
+        ExportError, TypeKind, TS,
     };
 
+    /// Identifies a declaration by its name and the file it is exported to.
+    /// Different instantiations of a generic type share the same declaration.
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub(super) struct Key {
+        pub(super) export_to: String,
+        pub(super) ts_name: String,
+    }
+
+    struct Node {
+        /// Path of the Rust type, without generic arguments
+        rust_path: &'static str,
+        kind: TypeKind,
+        decl: String,
+        /// `TS::inline`, which is only called to compare it to a type overriding it
+        inline: fn() -> String,
+        dependencies: Vec<Key>,
+        /// Copies of the declaration, exported to `T::ALSO_EXPORT_TO`
+        copies: Vec<(Key, String)>,
+        #[cfg(feature = "json-schema")]
+        json_schema: String,
+    }
+
+    /// All exportable types reachable from the type being exported
+    #[derive(Default)]
+    struct Graph {
+        nodes: BTreeMap<Key, Node>,
+        /// Warnings about the declarations, see `TS::warnings`
+        warnings: BTreeSet<String>,
+        /// The fields of the declarations whose type is overridden, which are checked once all
+        /// declarations are collected
+        type_overrides: Vec<(Key, TypeOverride)>,
+    }
+
     struct Visit<'a> {
-        seen: &'a mut HashSet<TypeId>,
+        graph: &'a mut Graph,
+        // the declaration whose dependencies are visited
+        parent: Key,
         error: Option<ExportError>,
     }
 
@@ -47,38 +119,227 @@ mod recursive_export {
         fn visit<T: TS + 'static + ?Sized>(&mut self) {
             // if an error occurred previously, or the type cannot be exported (it's a primitive),
             // we return
-            if self.error.is_some() || T::EXPORT_TO.is_none() {
+            if self.error.is_some() {
+                return;
+            }
+            if T::EXPORT_TO.is_none() {
+                // warnings about types which cannot be exported are reported for the type using them
+                for warning in T::warnings() {
+                    self.graph.warn(&self.parent, &warning);
+                }
                 return;
             }
 
-            self.error = export_recursive::<T>(self.seen).err();
+            self.error = collect::<T>(self.graph).err();
         }
     }
 
     /// Exports `T` to the file specified by the `#[ts(export_to = ..)]` attribute.
     /// Additionally, all dependencies of `T` will be exported as well.
     ///
-    /// TODO: This might cause a race condition:
-    ///       If two types `A` and `B` are `#[ts(export)]` and depend on type `C`,
-    ///       then both tests for exporting `A` and `B` will try to write `C` to `C.ts`.
-    ///       Since rust, by default, executes tests in paralell, this might cause `C.ts` to be corrupted.
+    /// Types which depend on each other across files form an import cycle. Since all imports are
+    /// emitted as `import type`, these cycles do not exist at runtime. If `TS_RS_MERGE_CYCLES` is
+    /// set, all types of such a cycle are instead exported into a single file.
+    /// `transform` is called with the contents of every TypeScript file before it's written.
     pub(crate) fn export_type_with_dependencies<T: TS + ?Sized + 'static>(
+        transform: &mut dyn FnMut(&mut String),
     ) -> Result<(), ExportError> {
-        let mut seen = HashSet::new();
-        export_recursive::<T>(&mut seen)
+        for_each_profile(&mut || export_profile::<T>(transform))
     }
 
-    // exports T, then recursively calls itself with all of its dependencies
-    fn export_recursive<T: TS + ?Sized + 'static>(
-        seen: &mut HashSet<TypeId>,
+    fn export_profile<T: TS + ?Sized + 'static>(
+        transform: &mut dyn FnMut(&mut String),
     ) -> Result<(), ExportError> {
-        if !seen.insert(TypeId::of::<T>()) {
-            return Ok(());
+        let mut graph = Graph::default();
+        collect::<T>(&mut graph)?;
+        graph.register()?;
+        graph.check_type_overrides();
+
+        let file_of = graph.files(merge_cycles());
+        let mut manifest_entries = Vec::new();
+        let mut files = BTreeMap::<&str, Vec<&Key>>::new();
+        for (key, export_to) in &file_of {
+            files.entry(export_to).or_default().push(key);
+        }
+
+        for (export_to, keys) in files {
+            let mut buffer = String::with_capacity(1024);
+            push_header(&mut buffer);
+
+            let imports = keys
+                .iter()
+                .flat_map(|key| &graph.nodes[key].dependencies)
+                .filter(|dep| file_of[dep] != export_to)
+                .map(|dep| (dep.ts_name.as_str(), file_of[dep]))
+                .collect::<Vec<_>>();
+            generate_imports(&mut buffer, export_to, imports.iter().copied());
+
+            // doc comments can link to the types declared in or imported into the file
+            let in_scope = keys
+                .iter()
+                .map(|key| key.ts_name.as_str())
+                .chain(imports.iter().map(|(ts_name, _)| *ts_name))
+                .collect::<BTreeSet<_>>();
+            let decls = keys
+                .iter()
+                .map(|key| graph.nodes[key].decl.as_str())
+                .collect::<Vec<_>>();
+            buffer.push_str(&resolve_links(&decls.join("\n\n"), &|name| {
+                in_scope.contains(name)
+            }));
+
+            let contents = write_file(output_path(export_to)?, buffer, transform)?;
+            for key in keys {
+                let node = &graph.nodes[key];
+                manifest_entries.push(manifest_entry(
+                    key,
+                    export_to,
+                    node.kind.as_str(),
+                    &node.dependencies,
+                    &contents,
+                ));
+            }
+        }
+
+        #[cfg(feature = "json-schema")]
+        for (key, node) in &graph.nodes {
+            let path = crate::json_schema::path(&key.export_to);
+            super::write_unformatted(output_path(&path)?, &node.json_schema)?;
+        }
+
+        for (key, node) in &graph.nodes {
+            for (copy, decl) in &node.copies {
+                let mut buffer = String::with_capacity(1024);
+                push_header(&mut buffer);
+
+                // a reference to the type itself only refers to the copy if it wasn't renamed
+                let imports = node
+                    .dependencies
+                    .iter()
+                    .filter(|dep| *dep != key || copy.ts_name != key.ts_name)
+                    .filter(|dep| file_of[dep] != copy.export_to)
+                    .map(|dep| (dep.ts_name.as_str(), file_of[dep]))
+                    .collect::<Vec<_>>();
+                generate_imports(&mut buffer, &copy.export_to, imports.iter().copied());
+                buffer.push_str(&resolve_links(decl, &|name| {
+                    name == copy.ts_name || imports.iter().any(|(ts_name, _)| *ts_name == name)
+                }));
+
+                let contents = write_file(output_path(&copy.export_to)?, buffer, transform)?;
+                manifest_entries.push(manifest_entry(
+                    copy,
+                    &copy.export_to,
+                    node.kind.as_str(),
+                    &node.dependencies,
+                    &contents,
+                ));
+            }
+        }
+
+        if manifest() {
+            write_manifest(manifest_entries)?;
+        }
+        write_warnings(&graph.warnings)
+    }
+
+    /// Describes the declaration `key`, written to `file` with the given contents, as a JSON
+    /// object for the manifest. `kind` is the name of the [`TypeKind`] of a type, or `"const"`.
+    pub(super) fn manifest_entry(
+        key: &Key,
+        file: &str,
+        kind: &str,
+        dependencies: &[Key],
+        contents: &str,
+    ) -> String {
+        use crate::json_schema::string;
+
+        let dependencies = dependencies
+            .iter()
+            .map(|dep| {
+                format!(
+                    r#"{{"type":{},"file":{}}}"#,
+                    string(&dep.ts_name),
+                    string(&dep.export_to)
+                )
+            })
+            .collect::<BTreeSet<_>>();
+        format!(
+            r#"{{"file":{},"type":{},"dependencies":[{}],"kind":{},"hash":"{:016x}"}}"#,
+            string(file),
+            string(&key.ts_name),
+            dependencies.into_iter().collect::<Vec<_>>().join(","),
+            string(kind),
+            super::fnv1a(contents.as_bytes())
+        )
+    }
+
+    // adds T to the graph, then recursively calls itself with all of its dependencies
+    fn collect<T: TS + ?Sized + 'static>(graph: &mut Graph) -> Result<(), ExportError> {
+        let key = Key {
+            export_to: T::get_export_to()
+                .ok_or(ExportError::CannotBeExported(std::any::type_name::<T>()))?,
+            ts_name: T::name(),
+        };
+        let rust_path = rust_path::<T>();
+        T::check_flatten()?;
+        if let Some(node) = graph.nodes.get(&key) {
+            // different instantiations of a generic type share their declaration
+            return match node.rust_path == rust_path {
+                true => Ok(()),
+                false => Err(collision(key, node.rust_path, rust_path)),
+            };
+        }
+
+        for warning in T::warnings() {
+            graph.warn(&key, &warning);
         }
+        for type_override in T::type_overrides() {
+            graph.type_overrides.push((key.clone(), type_override));
+        }
+        check_ambient::<T>()?;
 
-        export_type::<T>()?;
+        let mut decl = String::new();
+        generate_decl::<T>(&mut decl, &key.ts_name);
+        let dependencies = T::dependencies()
+            .into_iter()
+            .map(|dep| Key {
+                export_to: dep.exported_to,
+                ts_name: dep.ts_name,
+            })
+            .collect();
+        let copies = T::ALSO_EXPORT_TO
+            .iter()
+            .map(|(export_to, ts_name)| {
+                let mut decl = String::new();
+                generate_decl::<T>(&mut decl, ts_name);
+                let key = Key {
+                    export_to: export_to.to_string(),
+                    ts_name: ts_name.to_string(),
+                };
+                (key, decl)
+            })
+            .collect();
+        #[cfg(feature = "json-schema")]
+        let json_schema = crate::json_schema::document::<T>(&key.export_to);
+        graph.nodes.insert(
+            key.clone(),
+            Node {
+                rust_path,
+                kind: T::KIND,
+                decl,
+                inline: T::inline,
+                dependencies,
+                copies,
+                #[cfg(feature = "json-schema")]
+                json_schema,
+            },
+        );
 
-        let mut visitor = Visit { seen, error: None };
+        let mut visitor = Visit {
+            graph,
+            parent: key,
+            error: None,
+        };
         T::dependency_types().for_each(&mut visitor);
 
         if let Some(e) = visitor.error {
@@ -87,90 +348,1198 @@ mod recursive_export {
             Ok(())
         }
     }
-}
 
-/// Export `T` to the file specified by the `#[ts(export_to = ..)]` attribute
-pub(crate) fn export_type<T: TS + ?Sized + 'static>() -> Result<(), ExportError> {
-    let path = output_path::<T>()?;
-    export_type_to::<T, _>(&path)
+    // the path of `T` without its generic arguments, e.g. `my_crate::Page` for `my_crate::Page<u8>`
+    fn rust_path<T: ?Sized + 'static>() -> &'static str {
+        let name = std::any::type_name::<T>();
+        name.split('<').next().unwrap_or(name)
+    }
+
+    // whether the file `key` is exported to is named after it, in any case and possibly sanitized
+    fn is_named_after(key: &Key) -> bool {
+        let normalize = |name: &str| {
+            name.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        };
+        let stem = std::path::Path::new(&key.export_to)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy());
+        stem.is_some_and(|stem| normalize(&stem) == normalize(&key.ts_name))
+    }
+
+    fn collision(key: Key, first: &'static str, second: &'static str) -> ExportError {
+        ExportError::NameCollision {
+            ts_name: key.ts_name,
+            export_to: key.export_to,
+            first,
+            second,
+        }
+    }
+
+    impl Graph {
+        /// Remembers the Rust type of every declaration exported by this process, so that a
+        /// different type exported under the same name to the same file is an error instead of
+        /// silently overwriting it.
+        ///
+        /// Likewise, different declarations may not be exported to files named after them whose
+        /// paths only differ in case, or are equal after the names were sanitized, e.g. `Foo$Bar`
+        /// and `Foo_Bar`, which are both exported to `Foo_Bar.ts`.
+        fn register(&self) -> Result<(), ExportError> {
+            static EXPORTED: Mutex<BTreeMap<Key, &'static str>> = Mutex::new(BTreeMap::new());
+            // the declarations exported to files named after them, by their lowercase path
+            static FILES: Mutex<BTreeMap<String, (Key, &'static str)>> =
+                Mutex::new(BTreeMap::new());
+
+            let mut exported = EXPORTED.lock().unwrap();
+            let mut files = FILES.lock().unwrap();
+            let keys = self.nodes.iter().flat_map(|(key, node)| {
+                let copies = node.copies.iter().map(|(copy, _)| copy);
+                std::iter::once(key)
+                    .chain(copies)
+                    .map(|key| (key, node.rust_path))
+            });
+            for (key, rust_path) in keys {
+                match exported.get(key) {
+                    Some(first) if *first != rust_path => {
+                        return Err(collision(key.clone(), first, rust_path))
+                    }
+                    Some(_) => (),
+                    None => {
+                        exported.insert(key.clone(), rust_path);
+                    }
+                }
+
+                if !is_named_after(key) {
+                    continue;
+                }
+                match files.get(&key.export_to.to_lowercase()) {
+                    Some((first, first_path)) if first != key => {
+                        return Err(ExportError::FileNameCollision {
+                            export_to: key.export_to.clone(),
+                            first: first_path,
+                            second: rust_path,
+                        })
+                    }
+                    Some(_) => (),
+                    None => {
+                        files.insert(key.export_to.to_lowercase(), (key.clone(), rust_path));
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Warns about types overridden by `#[ts(type = "..")]` which are likely stale, since the
+        /// type of the field is already exported as the given type, or the given type is declared
+        /// alongside with a different definition than the type of the field.
+        fn check_type_overrides(&mut self) {
+            for (key, type_override) in std::mem::take(&mut self.type_overrides) {
+                let TypeOverride {
+                    field,
+                    ts_type,
+                    actual: Some((name, inline)),
+                } = &type_override
+                else {
+                    continue;
+                };
+                if same_type(name, ts_type) {
+                    let message = format!(
+                        "`#[ts(type = \"{}\")]` on `{}` has no effect, since its type is already exported as `{}`",
+                        ts_type, field, name
+                    );
+                    self.warn(&key, &message);
+                    continue;
+                }
+
+                let declared = self
+                    .nodes
+                    .iter()
+                    .find(|(key, _)| same_type(&key.ts_name, ts_type));
+                if let Some((_, node)) = declared {
+                    let (declared, actual) = ((node.inline)(), inline());
+                    if !same_type(&declared, &actual) {
+                        let message = format!(
+                            "`#[ts(type = \"{}\")]` on `{}` may be stale, since `{}` is declared as `{}`, but its type `{}` is exported as `{}`",
+                            ts_type, field, ts_type, declared, name, actual
+                        );
+                        self.warn(&key, &message);
+                    }
+                }
+            }
+        }
+
+        /// Records a warning about the declaration `key`, serialized as a JSON object.
+        fn warn(&mut self, key: &Key, message: &str) {
+            use crate::json_schema::string;
+
+            self.warnings.insert(format!(
+                r#"{{"type":{},"file":{},"message":{}}}"#,
+                string(&key.ts_name),
+                string(&key.export_to),
+                string(message)
+            ));
+        }
+
+        /// Returns the file every declaration will be written to.
+        /// If `merge_cycles` is true, all declarations of a cycle are written to the file of the
+        /// declaration which comes first.
+        fn files(&self, merge_cycles: bool) -> BTreeMap<&Key, &str> {
+            let mut file_of = self
+                .nodes
+                .keys()
+                .map(|key| (key, key.export_to.as_str()))
+                .collect::<BTreeMap<_, _>>();
+
+            if merge_cycles {
+                for cycle in self.cycles() {
+                    let target = cycle
+                        .iter()
+                        .map(|key| key.export_to.as_str())
+                        .min()
+                        .unwrap();
+                    for key in cycle {
+                        file_of.insert(key, target);
+                    }
+                }
+            }
+
+            file_of
+        }
+
+        /// Finds all groups of declarations which (transitively) depend on each other, using
+        /// [Tarjan's algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm).
+        fn cycles(&self) -> Vec<Vec<&Key>> {
+            let mut tarjan = Tarjan {
+                graph: self,
+                index: BTreeMap::new(),
+                stack: Vec::new(),
+                on_stack: BTreeSet::new(),
+                components: Vec::new(),
+            };
+            for key in self.nodes.keys() {
+                if !tarjan.index.contains_key(key) {
+                    tarjan.connect(key);
+                }
+            }
+
+            tarjan
+                .components
+                .into_iter()
+                .filter(|component| component.len() > 1)
+                .collect()
+        }
+    }
+
+    struct Tarjan<'a> {
+        graph: &'a Graph,
+        // index and lowlink of every visited declaration
+        index: BTreeMap<&'a Key, (usize, usize)>,
+        stack: Vec<&'a Key>,
+        on_stack: BTreeSet<&'a Key>,
+        components: Vec<Vec<&'a Key>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn connect(&mut self, key: &'a Key) {
+            let index = self.index.len();
+            self.index.insert(key, (index, index));
+            self.stack.push(key);
+            self.on_stack.insert(key);
+
+            for dep in &self.graph.nodes[key].dependencies {
+                let lowlink = match self.index.get(dep) {
+                    None => {
+                        self.connect(dep);
+                        self.index[dep].1
+                    }
+                    Some(&(dep_index, _)) if self.on_stack.contains(dep) => dep_index,
+                    Some(_) => continue,
+                };
+                let entry = self.index.get_mut(key).unwrap();
+                entry.1 = entry.1.min(lowlink);
+            }
+
+            let (index, lowlink) = self.index[key];
+            if index == lowlink {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == key {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
 }
 
 /// Export `T` to the file specified by the `path` argument.
 pub(crate) fn export_type_to<T: TS + ?Sized + 'static, P: AsRef<Path>>(
     path: P,
 ) -> Result<(), ExportError> {
-    // Lock to make sure only one file will be written at a time.
-    // In the future, it might make sense to replace this with something more clever to only prevent
-    // two threads from writing the **same** file concurrently.
-    static FILE_LOCK: Mutex<()> = Mutex::new(());
-
-    #[allow(unused_mut)]
-    let mut buffer = export_type_to_string::<T>()?;
+    let buffer = export_type_to_string::<T>()?;
+    write_file(path, buffer, &mut |_| {})?;
+    Ok(())
+}
 
+/// Format `buffer` if the `format` feature is enabled, apply the style from `ts-rs.toml` and
+/// `transform`, and write it to `path`.
+/// Returns the contents which were written.
+fn write_file(
+    path: impl AsRef<Path>,
+    buffer: String,
+    transform: &mut dyn FnMut(&mut String),
+) -> Result<String, ExportError> {
     // format output
     #[cfg(feature = "format")]
-    {
-        use dprint_plugin_typescript::{configuration::ConfigurationBuilder, format_text};
+    let buffer = format_text(path.as_ref(), buffer)?;
+
+    let mut buffer = crate::style::apply(&buffer, &style());
+    transform(&mut buffer);
+    write_unformatted(path, &buffer)?;
+    Ok(buffer)
+}
+
+/// Formats TypeScript source code, e.g. a declaration or the output of
+/// [`TS::export_to_string`], the same way exported files are formatted, without writing anything.
+#[cfg(feature = "format")]
+pub fn format_decl(decl: &str) -> Result<String, ExportError> {
+    format_text(Path::new("decl.ts"), decl.to_owned())
+}
+
+// `path` is only used to tell which language `buffer` is written in
+#[cfg(feature = "format")]
+fn format_text(path: &Path, buffer: String) -> Result<String, ExportError> {
+    use dprint_plugin_typescript::configuration::ConfigurationBuilder;
+
+    let fmt_cfg = ConfigurationBuilder::new().deno().build();
+    Ok(
+        dprint_plugin_typescript::format_text(path, &buffer, &fmt_cfg)
+            .map_err(|e| Formatting(e.to_string()))?
+            .unwrap_or(buffer),
+    )
+}
 
-        let fmt_cfg = ConfigurationBuilder::new().deno().build();
-        if let Some(formatted) =
-            format_text(path.as_ref(), &buffer, &fmt_cfg).map_err(|e| Formatting(e.to_string()))?
-        {
-            buffer = formatted;
+/// Write `contents` to `path`, creating its parent directories if necessary.
+/// When checking the bindings, nothing is written. Instead, an error describing the difference is
+/// returned if `path` doesn't contain `contents`.
+fn write_unformatted(path: impl AsRef<Path>, contents: &str) -> Result<(), ExportError> {
+    if checking() {
+        let existing = match std::fs::read_to_string(path.as_ref()) {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            existing => existing?,
+        };
+        if existing != contents {
+            return Err(OutOfDate {
+                path: path.as_ref().to_owned(),
+                diff: crate::diff::diff(&existing, contents),
+            });
         }
+        return Ok(());
     }
 
-    if let Some(parent) = path.as_ref().parent() {
+    // the file is written next to `path` first and then renamed, which replaces `path` atomically,
+    // so tests exporting the same file in parallel, even from different processes, never
+    // interleave their writes or observe a partially written file
+    static TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    let lock = FILE_LOCK.lock().unwrap();
-    std::fs::write(path.as_ref(), buffer)?;
-    drop(lock);
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp = path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+    let written = std::fs::write(&temp, contents).and_then(|()| std::fs::rename(&temp, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    Ok(written?)
+}
+
+/// Runs `f` while holding an exclusive file lock, which is also respected by other processes,
+/// e.g. other test binaries merging entries into the same manifest.
+/// Every process uses the same lock file `ts-rs.lock` in the temporary directory, so nothing is
+/// left next to the bindings and no more than this one empty file is ever created. It's not
+/// removed afterwards, since a process waiting for the lock would then hold a lock on a file which
+/// no longer exists, while another process locks a new one.
+fn with_file_lock<T>(f: impl FnOnce() -> Result<T, ExportError>) -> Result<T, ExportError> {
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(std::env::temp_dir().join("ts-rs.lock"))?;
+    let mut lock = fd_lock::RwLock::new(file);
+    // the lock is released when the guard is dropped
+    let _guard = lock.write()?;
+    f()
+}
+
+const WARNINGS_FILE: &str = "ts-rs-warnings.json";
+
+/// Merges `warnings` into `ts-rs-warnings.json`, located in `TS_RS_EXPORT_DIR` or `bindings/`.
+/// Every warning is a JSON object on its own line. They are sorted and deduplicated, so that the
+/// file does not depend on the order in which types were exported.
+fn write_warnings(warnings: &std::collections::BTreeSet<String>) -> Result<(), ExportError> {
+    // warnings are not part of the bindings, so they're not checked
+    if warnings.is_empty() || checking() {
+        return Ok(());
+    }
+    merge_json_lines(WARNINGS_FILE, warnings.iter().cloned(), |warning| warning)
+}
+
+const MANIFEST_FILE: &str = "ts-rs-manifest.json";
+
+/// Merges `entries` into `ts-rs-manifest.json`, located in `TS_RS_EXPORT_DIR` or `bindings/`.
+/// Every entry describes one exported declaration and replaces a previous entry for the same
+/// declaration in the same file.
+fn write_manifest(entries: Vec<String>) -> Result<(), ExportError> {
+    merge_json_lines(MANIFEST_FILE, entries, |entry| {
+        // entries start with `{"file":..,"type":..`, which identifies the declaration
+        let end = entry.find(r#","dependencies":"#).unwrap_or(entry.len());
+        &entry[..end]
+    })
+}
+
+/// Merges `entries`, which are JSON objects, into the JSON array in `file_name`, located in
+/// `TS_RS_EXPORT_DIR` or `bindings/`. Every object is written on its own line, sorted by `key`.
+/// Objects with the same `key` replace existing ones.
+fn merge_json_lines(
+    file_name: &str,
+    entries: impl IntoIterator<Item = String>,
+    key: fn(&str) -> &str,
+) -> Result<(), ExportError> {
+    let dir = export_dir();
+    let path = output_path(&format!("{}/{}", dir, file_name))?;
+    with_file_lock(|| merge_json_lines_locked(&path, entries, key))
+}
+
+fn merge_json_lines_locked(
+    path: &Path,
+    entries: impl IntoIterator<Item = String>,
+    key: fn(&str) -> &str,
+) -> Result<(), ExportError> {
+    let mut all = BTreeMap::new();
+    if let Ok(existing) = std::fs::read_to_string(path) {
+        let lines = existing
+            .lines()
+            .map(|line| line.trim().trim_end_matches(','))
+            .filter(|line| line.starts_with('{'));
+        for line in lines {
+            all.insert(key(line).to_owned(), line.to_owned());
+        }
+    }
+    for entry in entries {
+        all.insert(key(&entry).to_owned(), entry);
+    }
+
+    let lines = all
+        .values()
+        .map(|entry| format!("  {}", entry))
+        .collect::<Vec<_>>();
+    write_unformatted(path, &format!("[\n{}\n]\n", lines.join(",\n")))
+}
+
+/// Exports every type annotated with `#[ts(export)]` and the constants of every
+/// `export_consts!` which are linked into the current binary, together with their dependencies.
+///
+/// With the `export-all` feature enabled, no test is generated for each of them. Instead, this
+/// function can be called once, e.g. from a binary or a single test, to export all of them.
+#[cfg(feature = "export-all")]
+pub fn export_all() -> Result<(), ExportError> {
+    for export in inventory::iter::<__private::Export> {
+        (export.0)()?;
+    }
+    Ok(())
+}
+
+/// Removes all `.ts` files within `dir` which are not listed in the `ts-rs-manifest.json` located
+/// in `dir`, and returns their paths. Relative paths are resolved against `CARGO_MANIFEST_DIR`.
+///
+/// Since entries are merged into an existing manifest, it has to be deleted before exporting, so
+/// that it only lists the declarations which still exist. `dir` should only contain generated
+/// bindings, since any other `.ts` file is removed as well.
+pub fn clean_unreferenced(dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, ExportError> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|_| ManifestDirNotSet)?;
+    let dir = Path::new(&manifest_dir).join(dir);
+    let manifest = std::fs::read_to_string(dir.join(MANIFEST_FILE))?;
+
+    let mut referenced = std::collections::HashSet::new();
+    for file in manifest.lines().filter_map(ts_rs_config::manifest_file) {
+        // files which don't exist cannot be removed anyways
+        if let Ok(path) = output_path(&file)?.canonicalize() {
+            referenced.insert(path);
+        }
+    }
+
+    let mut removed = Vec::new();
+    let mut dirs = vec![dir];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "ts")
+                && !referenced.contains(&path.canonicalize()?)
+            {
+                std::fs::remove_file(&path)?;
+                removed.push(path);
+            }
+        }
+    }
+    removed.sort();
+    Ok(removed)
+}
+
+/// Lays out an npm package in `dir` containing the bindings exported to `TS_RS_EXPORT_DIR` (or
+/// `bindings/`), so it can be published after exporting. Relative paths are resolved against
+/// `CARGO_MANIFEST_DIR`.
+///
+/// The bindings are copied to `src/`, next to an `index.ts` which re-exports all of them, and a
+/// `package.json` declaring the package `name` and `version` is written to `dir`. Files which
+/// were copied to `src/` before are not removed, so stale bindings should be removed using
+/// [`clean_unreferenced`] before exporting the package.
+pub fn export_npm_package(
+    dir: impl AsRef<Path>,
+    name: &str,
+    version: &str,
+) -> Result<(), ExportError> {
+    let bindings = output_path(export_dir())?;
+    let dir = output_path(&dir.as_ref().to_string_lossy())?;
+    let src = dir.join("src");
+
+    let mut files = Vec::new();
+    let mut dirs = vec![bindings.clone()];
+    while let Some(current) = dirs.pop() {
+        for entry in std::fs::read_dir(current)? {
+            let path = entry?.path();
+            // the package may be laid out within the bindings
+            if path == dir {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "ts") {
+                files.push(path.strip_prefix(&bindings).unwrap().to_owned());
+            }
+        }
+    }
+    files.retain(|file| file != Path::new("index.ts"));
+    files.sort();
+
+    let mut index = String::new();
+    push_header(&mut index);
+    for file in &files {
+        let content = std::fs::read_to_string(bindings.join(file))?;
+        write_unformatted(src.join(file), &content)?;
+
+        let path = file.to_string_lossy().replace('\\', "/");
+        writeln!(
+            index,
+            "export * from {:?};",
+            with_extension(&format!("./{}", path))
+        )
+        .unwrap();
+    }
+    write_unformatted(src.join("index.ts"), &index)?;
+
+    let package = [
+        "{".to_owned(),
+        format!("  \"name\": {},", crate::json_schema::string(name)),
+        format!("  \"version\": {},", crate::json_schema::string(version)),
+        r#"  "types": "src/index.ts","#.to_owned(),
+        r#"  "files": ["src"]"#.to_owned(),
+        "}\n".to_owned(),
+    ]
+    .join("\n");
+    write_unformatted(dir.join("package.json"), &package)
+}
+
+// 64-bit FNV-1a, used for the content hashes in the manifest since, unlike `DefaultHasher`, it's
+// guaranteed to be stable across Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+const MERGE_CYCLES_ENV_VAR: &str = "TS_RS_MERGE_CYCLES";
+fn merge_cycles() -> bool {
+    static MERGE_CYCLES: OnceLock<bool> = OnceLock::new();
+    *MERGE_CYCLES.get_or_init(|| {
+        matches!(
+            std::env::var(MERGE_CYCLES_ENV_VAR).as_deref(),
+            Ok("1" | "true")
+        )
+    })
+}
+
+// the config of the crate being tested. Errors are ignored, since the derive macro already fails
+// to compile if the config is invalid
+fn config() -> Option<&'static Config> {
+    static CONFIG: OnceLock<Option<Arc<Config>>> = OnceLock::new();
+    CONFIG.get_or_init(|| Config::get().ok()).as_deref()
+}
+
+/// The type of `Result<T, E>`, given the types of `T` and `E`. Like serde, it's externally
+/// tagged, e.g. `{ Ok : T } | { Err : E }`, unless the `result` template in `ts-rs.toml` says
+/// otherwise.
+pub(crate) fn result_type(ok: &str, err: &str) -> String {
+    match result_template() {
+        Some(template) => template
+            .split("{T}")
+            .map(|part| part.replace("{E}", err))
+            .collect::<Vec<_>>()
+            .join(ok),
+        None => format!("{{ Ok : {} }} | {{ Err : {} }}", ok, err),
+    }
+}
+
+pub(crate) fn result_template() -> Option<&'static str> {
+    config()?.result.as_deref()
+}
+
+const PROFILE_ENV_VAR: &str = "TS_RS_PROFILE";
+// the names of the profiles selected by `TS_RS_PROFILE`, e.g. `web,admin`
+fn selected_profiles() -> &'static [String] {
+    static PROFILES: OnceLock<Vec<String>> = OnceLock::new();
+    PROFILES.get_or_init(|| {
+        let profiles = std::env::var(PROFILE_ENV_VAR).unwrap_or_default();
+        profiles
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_owned)
+            .collect()
+    })
+}
+
+thread_local! {
+    static PROFILE: Cell<Option<&'static Profile>> = const { Cell::new(None) };
+}
+
+// the profile which is currently being exported
+fn profile() -> Option<&'static Profile> {
+    PROFILE.with(Cell::get)
+}
+
+/// Calls `export` once for every profile selected by `TS_RS_PROFILE`, during which the options
+/// of the profile take precedence over those of `ts-rs.toml`. If no profile is selected, `export`
+/// is called once.
+fn for_each_profile(
+    export: &mut dyn FnMut() -> Result<(), ExportError>,
+) -> Result<(), ExportError> {
+    if selected_profiles().is_empty() {
+        return export();
+    }
+    for name in selected_profiles() {
+        let profile = config()
+            .and_then(|config| config.profiles.get(name))
+            .ok_or_else(|| UnknownProfile(name.clone()))?;
+        PROFILE.with(|current| current.set(Some(profile)));
+        let result = export();
+        PROFILE.with(|current| current.set(None));
+        result?;
+    }
     Ok(())
 }
 
+/// The directory types are exported to by default, which is the `export_dir` of the current
+/// profile, `TS_RS_EXPORT_DIR`, the `export_dir` from `ts-rs.toml`, or `bindings/`.
+fn export_dir() -> &'static str {
+    profile()
+        .and_then(|profile| profile.export_dir.as_deref())
+        .or_else(__private::provided_default_dir)
+        .unwrap_or("bindings")
+}
+
+/// Push the `header` from `ts-rs.toml`, followed by the banner, which is a note that the file was
+/// generated unless it's overridden, and the `pragmas` from `ts-rs.toml`.
+fn push_header(out: &mut String) {
+    let header = profile()
+        .and_then(|profile| profile.header.as_deref())
+        .or_else(|| config()?.header.as_deref());
+    if let Some(header) = header {
+        out.push_str(header.trim_end());
+        out.push('\n');
+    }
+    match banner() {
+        Some(banner) if banner.trim().is_empty() => (),
+        Some(banner) => {
+            out.push_str(banner.trim_end());
+            out.push('\n');
+        }
+        None => out.push_str(NOTE),
+    }
+    let pragmas = profile()
+        .and_then(|profile| profile.pragmas.as_deref())
+        .or_else(|| Some(&config()?.pragmas))
+        .unwrap_or_default();
+    for pragma in pragmas {
+        out.push_str(pragma.trim_end());
+        out.push('\n');
+    }
+}
+
+/// The style exported files are written in, given by the profile or `ts-rs.toml`.
+fn style() -> Style {
+    let profile = profile();
+    let indent_tabs = profile
+        .and_then(|profile| profile.indent_tabs)
+        .or_else(|| Some(config()?.indent_tabs));
+    let indent_width = profile
+        .and_then(|profile| profile.indent_width)
+        .or_else(|| config()?.indent_width);
+    let indent = match (indent_tabs.unwrap_or(false), indent_width) {
+        (true, _) => Some("\t".to_owned()),
+        (false, Some(width)) if width != 2 => Some(" ".repeat(width)),
+        (false, _) => None,
+    };
+    let single_quotes = profile
+        .and_then(|profile| profile.single_quotes)
+        .or_else(|| Some(config()?.single_quotes));
+    let semicolons = profile
+        .and_then(|profile| profile.semicolons)
+        .or_else(|| config()?.semicolons);
+    Style {
+        indent,
+        single_quotes: single_quotes.unwrap_or(false),
+        semicolons,
+    }
+}
+
+const BANNER_ENV_VAR: &str = "TS_RS_BANNER";
+fn banner() -> Option<&'static str> {
+    static BANNER: OnceLock<Option<String>> = OnceLock::new();
+    BANNER
+        .get_or_init(|| std::env::var(BANNER_ENV_VAR).ok())
+        .as_deref()
+        .or_else(|| profile()?.banner.as_deref())
+        .or_else(|| config()?.banner.as_deref())
+}
+
+const MANIFEST_ENV_VAR: &str = "TS_RS_MANIFEST";
+fn manifest() -> bool {
+    static MANIFEST: OnceLock<bool> = OnceLock::new();
+    *MANIFEST.get_or_init(|| matches!(std::env::var(MANIFEST_ENV_VAR).as_deref(), Ok("1" | "true")))
+}
+
+thread_local! {
+    // set while exporting a type using `TS::export_check`
+    static CHECKING: Cell<bool> = const { Cell::new(false) };
+}
+
+const CHECK_ENV_VAR: &str = "TS_RS_CHECK";
+// whether the bindings are compared to the existing files instead of being written, which is the
+// case within `TS::export_check`, or if `TS_RS_CHECK` is set
+fn checking() -> bool {
+    static CHECK: OnceLock<bool> = OnceLock::new();
+    CHECKING.with(Cell::get)
+        || *CHECK
+            .get_or_init(|| matches!(std::env::var(CHECK_ENV_VAR).as_deref(), Ok("1" | "true")))
+}
+
+/// Like [`export_type_with_dependencies`], but compares every file to the existing one instead of
+/// writing it, failing with [`ExportError::OutOfDate`] if they differ.
+pub(crate) fn check_type_with_dependencies<T: TS + ?Sized + 'static>() -> Result<(), ExportError> {
+    let previous = CHECKING.with(|checking| checking.replace(true));
+    let result = export_type_with_dependencies::<T>(&mut |_| {});
+    CHECKING.with(|checking| checking.set(previous));
+    result
+}
+
+const SOURCE_LOCATIONS_ENV_VAR: &str = "TS_RS_SOURCE_LOCATIONS";
+fn source_locations() -> bool {
+    static SOURCE_LOCATIONS: OnceLock<bool> = OnceLock::new();
+    *SOURCE_LOCATIONS.get_or_init(|| {
+        matches!(
+            std::env::var(SOURCE_LOCATIONS_ENV_VAR).as_deref(),
+            Ok("1" | "true")
+        )
+    })
+}
+
 #[doc(hidden)]
 pub mod __private {
     use super::*;
+    pub use crate::json_schema::reference as json_schema_ref;
+    use crate::ts_type::{property_name, TsField, TsType};
 
     const EXPORT_DIR_ENV_VAR: &str = "TS_RS_EXPORT_DIR";
-    fn provided_default_dir() -> Option<&'static str> {
+    pub(crate) fn provided_default_dir() -> Option<&'static str> {
         static EXPORT_TO: OnceLock<Option<String>> = OnceLock::new();
-        EXPORT_TO.get_or_init(|| std::env::var(EXPORT_DIR_ENV_VAR).ok()).as_deref()
+        EXPORT_TO
+            .get_or_init(|| {
+                std::env::var(EXPORT_DIR_ENV_VAR)
+                    .ok()
+                    .or_else(|| config()?.export_dir.clone())
+            })
+            .as_deref()
+    }
+
+    #[cfg(feature = "export-all")]
+    pub use inventory;
+
+    /// A function exporting a type or constants, which is registered by `#[ts(export)]` and
+    /// `export_consts!` to be called by [`export_all`] if the `export-all` feature is enabled.
+    #[cfg(feature = "export-all")]
+    pub struct Export(pub(crate) fn() -> Result<(), ExportError>);
+
+    #[cfg(feature = "export-all")]
+    impl Export {
+        pub const fn new(export: fn() -> Result<(), ExportError>) -> Self {
+            Self(export)
+        }
+    }
+
+    #[cfg(feature = "export-all")]
+    inventory::collect!(Export);
+
+    /// A type deriving `TS` without generic parameters, which is registered together with the
+    /// path of the module it's declared in, so that `#[ts_rs::export_module]` can export the
+    /// types imported by a glob import if the `export-all` feature is enabled.
+    #[cfg(feature = "export-all")]
+    pub struct Declared {
+        module_path: &'static str,
+        export: fn() -> Result<(), ExportError>,
+    }
+
+    #[cfg(feature = "export-all")]
+    impl Declared {
+        pub const fn new(
+            module_path: &'static str,
+            export: fn() -> Result<(), ExportError>,
+        ) -> Self {
+            Self {
+                module_path,
+                export,
+            }
+        }
+    }
+
+    #[cfg(feature = "export-all")]
+    inventory::collect!(Declared);
+
+    /// Exports every registered type declared in the module a glob import within the module
+    /// `module_path` imports from, e.g. `crate::api` for `pub use crate::api::*;`.
+    #[cfg(feature = "export-all")]
+    pub fn export_glob(module_path: &str, glob: &[&str]) -> Result<(), ExportError> {
+        let mut segments = module_path.split("::").collect::<Vec<_>>();
+        match glob.first() {
+            Some(&"crate") => segments.truncate(1),
+            Some(&"self" | &"super") => (),
+            // a path starting with the name of a crate
+            _ => segments.clear(),
+        }
+        for segment in glob {
+            match *segment {
+                "crate" | "self" => (),
+                "super" => {
+                    segments.pop();
+                }
+                segment => segments.push(segment),
+            }
+        }
+        let glob = segments.join("::");
+        for declared in inventory::iter::<Declared> {
+            if declared.module_path == glob {
+                (declared.export)()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The value of a constant exported by `export_consts!`, which is declared as a TypeScript
+    /// literal.
+    pub trait ConstValue {
+        fn literal(&self) -> String;
+    }
+
+    macro_rules! impl_const_value {
+        ($($ty:ty),* => |$value:ident| $literal:expr) => {$(
+            impl ConstValue for $ty {
+                fn literal(&self) -> String {
+                    let $value = self;
+                    $literal
+                }
+            }
+        )*};
+    }
+
+    impl_const_value!(u8, i8, u16, i16, u32, i32, bool => |value| value.to_string());
+    impl_const_value!(usize, isize => |value| {
+        match config().and_then(|config| config.pointer_sized_integers.as_deref()) {
+            Some("bigint") => format!("{}n", value),
+            Some("string") => crate::json_schema::string(&value.to_string()),
+            _ => value.to_string(),
+        }
+    });
+    // like their types, large integers are declared as `bigint` unless configured otherwise
+    impl_const_value!(u64, i64, u128, i128 => |value| {
+        match config().and_then(|config| config.large_integers_type()) {
+            Some("number") => value.to_string(),
+            Some("string") => crate::json_schema::string(&value.to_string()),
+            _ => format!("{}n", value),
+        }
+    });
+    impl_const_value!(f32, f64 => |value| match value {
+        _ if value.is_nan() => "NaN".to_owned(),
+        _ if value.is_infinite() && value.is_sign_positive() => "Infinity".to_owned(),
+        _ if value.is_infinite() => "-Infinity".to_owned(),
+        _ => value.to_string(),
+    });
+    impl_const_value!(str, String => |value| crate::json_schema::string(value));
+    impl_const_value!(char => |value| crate::json_schema::string(&value.to_string()));
+
+    impl<T: ConstValue + ?Sized> ConstValue for &T {
+        fn literal(&self) -> String {
+            T::literal(self)
+        }
+    }
+
+    impl<T: ConstValue> ConstValue for Option<T> {
+        fn literal(&self) -> String {
+            self.as_ref().map_or_else(|| "null".to_owned(), T::literal)
+        }
+    }
+
+    impl<T: ConstValue> ConstValue for [T] {
+        fn literal(&self) -> String {
+            let items = self.iter().map(T::literal).collect::<Vec<_>>();
+            format!("[{}]", items.join(", "))
+        }
+    }
+
+    impl<T: ConstValue, const N: usize> ConstValue for [T; N] {
+        fn literal(&self) -> String {
+            self[..].literal()
+        }
+    }
+
+    /// Exports `consts`, given as `(name, docs, value)`, as TypeScript constants to the file
+    /// returned by `export_to`, once for every profile.
+    /// This should only be used by the `export_consts!` macro.
+    pub fn export_consts(
+        export_to: &dyn Fn() -> Option<String>,
+        consts: &[(&str, &str, &dyn ConstValue)],
+    ) -> Result<(), ExportError> {
+        for_each_profile(&mut || export_consts_to(export_to(), consts))
+    }
+
+    fn export_consts_to(
+        export_to: Option<String>,
+        consts: &[(&str, &str, &dyn ConstValue)],
+    ) -> Result<(), ExportError> {
+        let export_to = export_to.ok_or(CannotBeExported("constants"))?;
+        if ambient() {
+            return Err(AmbientValue("constants".to_owned()));
+        }
+
+        let mut buffer = String::with_capacity(1024);
+        push_header(&mut buffer);
+        buffer.push('\n');
+        let decls = consts
+            .iter()
+            .map(|(name, docs, value)| {
+                format!("{}export const {} = {};", docs, name, value.literal())
+            })
+            .collect::<Vec<_>>();
+        let names = consts.iter().map(|(name, ..)| *name).collect::<Vec<_>>();
+        buffer.push_str(&crate::docs::resolve_links(&decls.join("\n\n"), &|name| {
+            names.contains(&name)
+        }));
+
+        let contents = write_file(output_path(&export_to)?, buffer, &mut |_| {})?;
+        if manifest() {
+            let entries = consts.iter().map(|(name, ..)| {
+                let key = recursive_export::Key {
+                    export_to: export_to.clone(),
+                    ts_name: (*name).to_owned(),
+                };
+                recursive_export::manifest_entry(&key, &export_to, "const", &[], &contents)
+            });
+            write_manifest(entries.collect())?;
+        }
+        Ok(())
     }
 
-    /// Returns the path to where `T` should be exported using the `TS_RS_EXPORT_DIR` environment variable.
+    /// Returns the path to where a type should be exported using the `export_dir` of the profile
+    /// being exported, the `TS_RS_EXPORT_DIR` environment variable, or the `export_dir` from
+    /// `ts-rs.toml`. `file_name` is the path of the file within that directory.
     ///
     /// This should only be used by the TS derive macro; the `get_export_to` trait method should not
     /// be overridden if the `#[ts(export_to = ..)]` attribute exists.
-    pub fn get_export_to_path<T: TS + ?Sized>() -> Option<String> {
-        provided_default_dir().map_or_else(
-            || T::EXPORT_TO.map(ToString::to_string),
-            |path| Some(format!("{path}/{}.ts", T::name())),
-        )
+    pub fn get_export_to_path(file_name: &str) -> Option<String> {
+        let dir = export_dir();
+        Some(format!("{dir}/{file_name}"))
+    }
+
+    /// Declares the union of `members` as `head`, e.g. `type Foo`. If there are more than
+    /// `max_members` members, or the declaration would be longer than `max_width`, every member
+    /// is put on its own line, preceded by `|`.
+    pub fn union_decl(
+        head: &str,
+        members: &[String],
+        max_members: usize,
+        max_width: usize,
+    ) -> String {
+        let decl = format!("{} = {};", head, members.join(" | "));
+        if members.len() <= max_members && decl.len() <= max_width {
+            return decl;
+        }
+        let mut decl = format!("{} =", head);
+        for member in members {
+            decl.push_str("\n  | ");
+            decl.push_str(member);
+        }
+        decl.push(';');
+        decl
+    }
+
+    /// Declares the generic type `name` with its generic parameters substituted by the type
+    /// arguments it's used with. `generic` is its definition referring to the parameters by name,
+    /// and `args` are the names of the parameters with the names of their arguments. The alias is
+    /// named after the type and its arguments, e.g. `PaginatedUser` for `Paginated<User>`.
+    pub fn concrete_decl(name: &str, generic: &str, args: &[(&str, String)]) -> String {
+        let mut alias = name.to_owned();
+        for (_, arg) in args {
+            for word in arg.split(|c: char| !c.is_alphanumeric() && c != '_') {
+                let mut chars = word.chars();
+                alias.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+                alias.extend(chars);
+            }
+        }
+        let args = args
+            .iter()
+            .map(|(param, arg)| (*param, TsType::parse(arg)))
+            .collect::<Vec<_>>();
+        let mut ty = TsType::parse(generic);
+        ty.substitute(&args);
+        format!("type {} = {};", alias, ty)
+    }
+
+    /// Marks `ty` as an optional element of a tuple, e.g. `number?` or `(number | null)?`.
+    pub fn optional_element(ty: &str) -> String {
+        match TsType::parse(ty) {
+            TsType::Union(_) | TsType::Intersection(_) | TsType::Raw(_) => format!("({})?", ty),
+            _ => format!("{}?", ty),
+        }
+    }
+
+    /// Whether `T`, the type of the field of an internally tagged newtype variant, has no
+    /// properties, like a unit struct or a struct without fields, so that serde serializes the
+    /// variant as its tag alone.
+    pub fn is_payload_less<T: TS + ?Sized>() -> bool {
+        thread_local! {
+            // the types being inlined, since a recursive type, like `Tree(Box<Tree>)`, has
+            // properties unless it's empty
+            static INLINING: RefCell<HashSet<String>> = RefCell::default();
+        }
+        let name = T::name();
+        if !INLINING.with(|inlining| inlining.borrow_mut().insert(name.clone())) {
+            return false;
+        }
+        let payload_less = has_no_properties(&T::inline());
+        INLINING.with(|inlining| inlining.borrow_mut().remove(&name));
+        payload_less
+    }
+
+    fn has_no_properties(ty: &str) -> bool {
+        match TsType::parse(ty) {
+            TsType::Primitive(primitive) => primitive == "null",
+            TsType::Object(fields) => fields.is_empty(),
+            // the `empty_struct` in `ts-rs.toml`
+            TsType::Reference { name, args } => {
+                name == "Record" && args.get(1) == Some(&TsType::Primitive("never".to_owned()))
+            }
+            _ => false,
+        }
+    }
+
+    /// Merges `tag`, like `{ "type": "Variant" }`, into the leading object of `content`, the
+    /// inline type of the fields of an internally tagged variant.
+    pub fn internally_tagged(tag: String, content: String) -> String {
+        match has_no_properties(&content) {
+            true => tag,
+            false => format!("{} & {}", tag, content).replacen(" } & { ", ", ", 1),
+        }
+    }
+
+    /// Converts the keys of the inlined object `ty`, and of the objects it's an intersection of,
+    /// to `case`, given by `#[ts(inline, rename_all = "..")]`.
+    pub fn rename_fields(ty: &str, case: &str) -> String {
+        fn rename(ty: TsType, case: &str) -> TsType {
+            match ty {
+                TsType::Object(fields) => TsType::Object(
+                    fields
+                        .into_iter()
+                        .map(|field| {
+                            let name = field.name.trim_matches('"');
+                            let name = ts_rs_config::convert_case(case, name)
+                                .unwrap_or_else(|| name.to_owned());
+                            TsField {
+                                name: property_name(&name),
+                                ..field
+                            }
+                        })
+                        .collect(),
+                ),
+                TsType::Intersection(types) => {
+                    TsType::Intersection(types.into_iter().map(|ty| rename(ty, case)).collect())
+                }
+                other => other,
+            }
+        }
+
+        match TsType::parse(ty) {
+            TsType::Raw(_) => ty.to_owned(),
+            parsed => rename(parsed, case).to_string(),
+        }
+    }
+
+    /// Returns the keys `T` contributes to a type it's flattened into.
+    pub fn flattened_keys<T: TS + ?Sized>() -> Vec<String> {
+        fn collect(ty: TsType, keys: &mut Vec<String>) {
+            match ty {
+                TsType::Object(fields) => keys.extend(fields.into_iter().map(|field| field.name)),
+                TsType::Intersection(types) => types.into_iter().for_each(|ty| collect(ty, keys)),
+                // a flattened `Option`
+                TsType::Reference { name, mut args } if name == "Partial" && args.len() == 1 => {
+                    collect(args.remove(0), keys)
+                }
+                // the keys of a flattened enum depend on the variant
+                _ => (),
+            }
+        }
+
+        let mut keys = Vec::new();
+        collect(TsType::parse(&T::inline_flattened()), &mut keys);
+        keys
+    }
+
+    /// Fails if a key is defined by more than one of `types`, given as `(name, keys)`, which are
+    /// the type `ts_name` and the types flattened into it.
+    pub fn check_flatten(
+        ts_name: &str,
+        types: Vec<(String, Vec<String>)>,
+    ) -> Result<(), ExportError> {
+        let mut defined_by = BTreeMap::<&str, &str>::new();
+        for (name, keys) in &types {
+            for key in keys {
+                if let Some(first) = defined_by.insert(key, name) {
+                    return Err(FlattenConflict {
+                        ts_name: ts_name.to_owned(),
+                        key: key.clone(),
+                        first: first.to_owned(),
+                        second: name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// A field whose type is overridden by `#[ts(type = "..")]`, see `TS::type_overrides`.
+    pub struct TypeOverride {
+        pub field: &'static str,
+        /// The type given by `#[ts(type = "..")]`
+        pub ts_type: String,
+        /// The name and the definition of the type of the field, if it implements `TS`
+        pub actual: Option<(String, fn() -> String)>,
+    }
+
+    // whether `a` and `b` are the same type, ignoring whitespace
+    pub(crate) fn same_type(a: &str, b: &str) -> bool {
+        let significant = |c: &char| !c.is_whitespace();
+        a.chars()
+            .filter(significant)
+            .eq(b.chars().filter(significant))
+    }
+
+    /// The type of a field, which tells whether it implements `TS` using autoref specialization:
+    /// `(&&FieldType::<T>(PhantomData)).ts_type()` calls [`ImplementsTs`] if it does, and
+    /// [`NotTs`] otherwise.
+    pub struct FieldType<T: ?Sized>(pub std::marker::PhantomData<*const T>);
+
+    pub trait ImplementsTs {
+        fn ts_type(&self) -> Option<(String, fn() -> String)>;
+    }
+
+    impl<T: TS + ?Sized> ImplementsTs for &FieldType<T> {
+        fn ts_type(&self) -> Option<(String, fn() -> String)> {
+            Some((T::name(), T::inline))
+        }
+    }
+
+    pub trait NotTs {
+        fn ts_type(&self) -> Option<(String, fn() -> String)> {
+            None
+        }
+    }
+
+    impl<T: ?Sized> NotTs for FieldType<T> {}
+
+    /// Returns the path of `file_name` within the directory mirroring `module_path`, which is the
+    /// output of `module_path!()`, e.g. `api/v1/User.ts` for `my_crate::api::v1`.
+    pub fn module_file(module_path: &str, file_name: &str) -> String {
+        let mut path = module_path
+            .split("::")
+            .skip(1)
+            .fold(String::new(), |path, module| path + module + "/");
+        path.push_str(file_name);
+        path
     }
 }
 
 /// Returns the generated defintion for `T`.
 pub(crate) fn export_type_to_string<T: TS + ?Sized + 'static>() -> Result<String, ExportError> {
+    let export_to = T::get_export_to().ok_or(CannotBeExported(std::any::type_name::<T>()))?;
+    check_ambient::<T>()?;
+    let deps = T::dependencies();
+    let imports = deps
+        .iter()
+        .map(|dep| (dep.ts_name.as_str(), dep.exported_to.as_str()))
+        .collect::<Vec<_>>();
+
     let mut buffer = String::with_capacity(1024);
-    buffer.push_str(NOTE);
-    generate_imports::<T>(&mut buffer)?;
-    generate_decl::<T>(&mut buffer);
+    push_header(&mut buffer);
+    generate_imports(&mut buffer, &export_to, imports.iter().copied());
+    let mut decl = String::new();
+    generate_decl::<T>(&mut decl, &T::name());
+    buffer.push_str(&crate::docs::resolve_links(&decl, &|name| {
+        name == T::name() || imports.iter().any(|(ts_name, _)| *ts_name == name)
+    }));
     Ok(buffer)
 }
 
-/// Compute the output path to where `T` should be exported.
-fn output_path<T: TS + ?Sized>() -> Result<PathBuf, ExportError> {
+const OUTPUT_ROOT_ENV_VAR: &str = "TS_RS_OUTPUT_ROOT";
+
+/// Compute the output path for a file exported to `export_to`, relative to `CARGO_MANIFEST_DIR`.
+/// If `TS_RS_OUTPUT_ROOT` is set, the path is mirrored within it, e.g. by
+/// `cargo ts-rs export --check`, which compares the files written there to the existing ones.
+fn output_path(export_to: &str) -> Result<PathBuf, ExportError> {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|_| ManifestDirNotSet)?;
-    let manifest_dir = Path::new(&manifest_dir);
-    let path =
-        PathBuf::from(T::get_export_to().ok_or(CannotBeExported(std::any::type_name::<T>()))?);
-    Ok(manifest_dir.join(path))
+    let path = Path::new(&manifest_dir).join(export_to);
+    Ok(match std::env::var_os(OUTPUT_ROOT_ENV_VAR) {
+        Some(root) => ts_rs_config::mirror_path(Path::new(&root), &path),
+        None => path,
+    })
 }
 
-/// Push the declaration of `T`
-fn generate_decl<T: TS + ?Sized>(out: &mut String) {
+/// Push the declaration of `T`, declaring it as `ts_name`
+fn generate_decl<T: TS + ?Sized>(out: &mut String, ts_name: &str) {
+    if let (true, Some(location)) = (source_locations(), T::SOURCE_LOCATION) {
+        writeln!(out, "// {}", location).unwrap();
+    }
+
     // Type Docs
     let docs = &T::DOCS;
     if let Some(docs) = docs {
@@ -178,37 +1547,100 @@ fn generate_decl<T: TS + ?Sized>(out: &mut String) {
     }
 
     // Type Definition
-    out.push_str("export ");
-    out.push_str(&T::decl());
+    let decl = T::decl();
+    let decl = match ts_name == T::name() {
+        true => decl,
+        false => rename_decl(&decl, &T::name(), ts_name),
+    };
+    out.push_str(keyword(&decl));
+    out.push_str(&decl);
+
+    for extra in T::extra_decls() {
+        out.push_str("\n\n");
+        out.push_str(keyword(&extra));
+        out.push_str(&extra);
+    }
 }
 
-/// Push an import statement for all dependencies of `T`
-fn generate_imports<T: TS + ?Sized + 'static>(out: &mut String) -> Result<(), ExportError> {
-    let export_to = T::get_export_to().ok_or(CannotBeExported(std::any::type_name::<T>()))?;
-    let path = Path::new(&export_to);
+/// The keyword preceding `decl`, which is `export`, or `declare` for enums in ambient declaration
+/// files, since their members are values.
+fn keyword(decl: &str) -> &'static str {
+    match ambient() {
+        false => "export ",
+        true if decl.starts_with("enum ") || decl.starts_with("const enum ") => "declare ",
+        true => "",
+    }
+}
 
-    let deps = T::dependencies();
-    let deduplicated_deps = deps
-        .iter()
-        .filter(|dep| dep.type_id != TypeId::of::<T>())
-        .map(|dep| (&dep.ts_name, dep))
-        .collect::<BTreeMap<_, _>>();
+// whether types are exported as ambient declarations, see `ambient` in `ts-rs.toml`
+fn ambient() -> bool {
+    config().is_some_and(|config| config.ambient)
+}
 
-    for (_, dep) in deduplicated_deps {
-        let rel_path = import_path(path, Path::new(&dep.exported_to));
-        writeln!(
-            out,
-            "import type {{ {} }} from {:?};",
-            &dep.ts_name, rel_path
-        )
-        .unwrap();
+/// Fails if `T` declares values, like constants or functions, while exporting ambient
+/// declarations, since they can only describe values which exist elsewhere.
+fn check_ambient<T: TS + ?Sized>() -> Result<(), ExportError> {
+    let is_value = |decl: &String| {
+        decl.starts_with("function ")
+            || (decl.starts_with("const ") && !decl.starts_with("const enum "))
+    };
+    match ambient() && T::extra_decls().iter().any(is_value) {
+        true => Err(AmbientValue(T::name())),
+        false => Ok(()),
+    }
+}
+
+/// Replaces the name of the type declared by `decl`, e.g. `type User = ..` or `enum User { .. }`.
+fn rename_decl(decl: &str, from: &str, to: &str) -> String {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let position = decl.match_indices(from).find(|(i, _)| {
+        let before = decl[..*i].chars().next_back();
+        let after = decl[i + from.len()..].chars().next();
+        before == Some(' ') && !after.is_some_and(is_ident)
+    });
+
+    match position {
+        Some((i, _)) => format!("{}{}{}", &decl[..i], to, &decl[i + from.len()..]),
+        None => decl.to_owned(),
+    }
+}
+
+/// Push an import statement for every `(name, exported_to)` pair in `deps`.
+/// `export_to` is the path of the file the imports are generated for.
+fn generate_imports<'a>(
+    out: &mut String,
+    export_to: &str,
+    deps: impl IntoIterator<Item = (&'a str, &'a str)>,
+) {
+    // ambient declarations are global, so they're not imported
+    if ambient() {
+        writeln!(out).unwrap();
+        return;
+    }
+    let path = Path::new(export_to);
+    // imports are sorted by name and path, and deduplicated after resolving their paths, so that
+    // a type referred to by different paths, e.g. `bindings/A.ts` and `./bindings/A.ts`, is only
+    // imported once, and the file doesn't depend on the order in which dependencies were found
+    let imports = deps
+        .into_iter()
+        .map(|(ts_name, exported_to)| (ts_name, import_path(path, Path::new(exported_to))))
+        .collect::<BTreeSet<_>>();
+
+    for (ts_name, rel_path) in imports {
+        writeln!(out, "import type {{ {} }} from {:?};", ts_name, rel_path).unwrap();
     }
     writeln!(out).unwrap();
-    Ok(())
 }
 
 /// Returns the required import path for importing `import` from the file `from`
 fn import_path(from: &Path, import: &Path) -> String {
+    // relative paths are relative to the crate, so they're resolved if the other path is absolute,
+    // e.g. because it's in the `export_dir` of a workspace
+    let resolve = |path: &Path| match output_path(&path.to_string_lossy()) {
+        Ok(resolved) if from.is_absolute() != import.is_absolute() => resolved,
+        _ => path.to_owned(),
+    };
+    let (from, import) = (normalize(&resolve(from)), normalize(&resolve(import)));
     let rel_path =
         diff_paths(import, from.parent().unwrap()).expect("failed to calculate import path");
     let path = match rel_path.components().next() {
@@ -216,9 +1648,39 @@ fn import_path(from: &Path, import: &Path) -> String {
         _ => rel_path.to_string_lossy().into(),
     };
 
+    with_extension(&path)
+}
+
+/// Removes `.` from `path`, and resolves `..` following a directory, e.g. `a/b.ts` for
+/// `./a/c/../b.ts`, without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Replaces the `.ts` extension of an import path by `.js` if ESM imports are enabled, and
+/// removes it otherwise.
+fn with_extension(path: &str) -> String {
     let path_without_extension = path.trim_end_matches(".ts");
 
-    if cfg!(feature = "import-esm") {
+    let esm_imports = profile()
+        .and_then(|profile| profile.esm_imports)
+        .or_else(|| Some(config()?.esm_imports));
+    if cfg!(feature = "import-esm") || esm_imports.unwrap_or(false) {
         format!("{}.js", path_without_extension)
     } else {
         path_without_extension.to_owned()
@@ -281,4 +1743,3 @@ where
         Some(comps.iter().map(|c| c.as_os_str()).collect())
     }
 }
-
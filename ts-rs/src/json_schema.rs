@@ -0,0 +1,103 @@
+//! Helpers for generating [JSON Schema](https://json-schema.org/draft/2020-12/json-schema-core)
+//! documents, which are exported next to the TypeScript bindings if the `json-schema` feature is
+//! enabled.
+//!
+//! Every schema is a JSON object serialized to a `String`. Exported types are referenced using a
+//! `$ref` to the absolute path of their schema, relative to the export directory, e.g.
+//! `{"$ref":"/bindings/User.schema.json"}`.
+
+use crate::TS;
+
+#[cfg(feature = "json-schema")]
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the schema corresponding to a primitive TypeScript type, like `number` or `string`.
+pub(crate) fn primitive(ts_type: &str) -> String {
+    match ts_type {
+        "number" => r#"{"type":"number"}"#.to_owned(),
+        "bigint" => r#"{"type":"integer"}"#.to_owned(),
+        "boolean" => r#"{"type":"boolean"}"#.to_owned(),
+        "string" => r#"{"type":"string"}"#.to_owned(),
+        "null" => r#"{"type":"null"}"#.to_owned(),
+        _ => "{}".to_owned(),
+    }
+}
+
+pub(crate) fn nullable(schema: &str) -> String {
+    format!(r#"{{"anyOf":[{},{{"type":"null"}}]}}"#, schema)
+}
+
+pub(crate) fn array(items: &str) -> String {
+    format!(r#"{{"type":"array","items":{}}}"#, items)
+}
+
+pub(crate) fn tuple(items: &[String]) -> String {
+    format!(
+        r#"{{"type":"array","prefixItems":[{}],"minItems":{1},"maxItems":{1}}}"#,
+        items.join(","),
+        items.len()
+    )
+}
+
+pub(crate) fn record(values: &str) -> String {
+    format!(r#"{{"type":"object","additionalProperties":{}}}"#, values)
+}
+
+/// An object with the given properties, all of which are required.
+pub(crate) fn object(properties: &[(&str, &str)]) -> String {
+    let (properties, required): (Vec<_>, Vec<_>) = properties
+        .iter()
+        .map(|(name, schema)| (format!("{}:{}", string(name), schema), string(name)))
+        .unzip();
+    format!(
+        r#"{{"type":"object","properties":{{{}}},"required":[{}]}}"#,
+        properties.join(","),
+        required.join(",")
+    )
+}
+
+/// Serializes `value` as a JSON string literal.
+pub(crate) fn string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Path of the schema exported alongside bindings exported to `export_to`.
+pub(crate) fn path(export_to: &str) -> String {
+    format!("{}.schema.json", export_to.trim_end_matches(".ts"))
+}
+
+// the `$id` of the schema, which is its absolute path within the export directory
+fn id(export_to: &str) -> String {
+    string(&format!("/{}", path(export_to).trim_start_matches('/')))
+}
+
+/// Returns a `$ref` to the schema exported for `T`.
+pub fn reference<T: TS + ?Sized>() -> String {
+    match T::get_export_to() {
+        Some(export_to) => format!(r#"{{"$ref":{}}}"#, id(&export_to)),
+        None => T::json_schema(),
+    }
+}
+
+/// Returns the schema of `T`, as it is written to the file at `export_to`.
+#[cfg(feature = "json-schema")]
+pub(crate) fn document<T: TS + ?Sized>(export_to: &str) -> String {
+    let schema = T::json_schema();
+    let header = format!(r#""$schema":"{}","$id":{}"#, DIALECT, id(export_to));
+    match schema.strip_prefix('{') {
+        Some("}") => format!("{{{}}}\n", header),
+        Some(rest) => format!("{{{},{}\n", header, rest),
+        None => format!(r#"{{{},"allOf":[{}]}}"#, header, schema) + "\n",
+    }
+}
@@ -57,14 +57,19 @@
 //! When running `cargo test`, the TypeScript bindings will be exported to the file `bindings/User.ts`.
 //!
 //! ## features
-//! - generate interface declarations from rust structs
+//! - generate type declarations from rust structs, e.g. `type User = { user_id: number, }`.
+//!   Structs are always declared as type aliases instead of interfaces, so they can be intersected
+//!   with other types
 //! - generate union declarations from rust enums
 //! - inline types
-//! - flatten structs/interfaces
+//! - flatten structs
 //! - generate necessary imports when exporting to multiple files
 //! - serde compatibility
 //! - generic types
 //! - support for ESM imports
+//! - parsing generated types into a `TsType` for post-processing (`TS::ts_type()`)
+//! - export constants with `export_consts!`
+//! - export all types re-exported by a module with `#[ts_rs::export_module]`
 //!
 //! ## limitations
 //! - generic fields cannot be inlined or flattened (#56)
@@ -78,9 +83,16 @@
 //!
 //!   When enabled, the generated typescript will be formatted.
 //!   Currently, this sadly adds quite a bit of dependencies.
+//!   Other output, e.g. from `TS::export_to_string`, can be formatted using `ts_rs::format_decl`.
+//!   To only change the indentation, quotes or semicolons, e.g. to match a Prettier config, set
+//!   `indent_width`, `indent_tabs`, `single_quotes` or `semicolons` in `ts-rs.toml` instead.
 //! - `chrono-impl`  
 //!
 //!   Implement `TS` for types from chrono  
+//! - `time-impl`
+//!
+//!   Implement `TS` for `OffsetDateTime`, `PrimitiveDateTime`, `Date`, `Time` and `Duration` from
+//!   time, which are exported as `string`
 //! - `bigdecimal-impl`  
 //!
 //!   Implement `TS` for types from bigdecimal  
@@ -113,6 +125,25 @@
 //! - `semver-impl`  
 //!   Implement `TS` for `Version` from semver
 //!
+//! - `serde-json-impl`  
+//!
+//!   Implement `TS` for `Value`, `Map` and `Number` from serde_json. `Value` is exported once as
+//!   the recursive type `JsonValue`
+//!
+//! - `strum-interop`
+//!
+//!   Use the names given by `#[strum(serialize = "..")]`, `#[strum(to_string = "..")]` and
+//!   `#[strum(serialize_all = "..")]` for enum variants, so the bindings match the output of
+//!   strum's `Display` and `EnumString`. Additional `serialize` values are used as aliases, and
+//!   `#[ts(..)]` and `#[serde(..)]` attributes take precedence
+//!
+//! - `validator-interop`
+//!
+//!   Document the rules given by `#[validate(..)]` of the validator crate and `#[garde(..)]` of
+//!   the garde crate on fields using JSDoc tags, e.g. `@minLength 1` and `@maxLength 64` for
+//!   `length(min = 1, max = 64)`, or `@format email` for `email`. Supported are `length`, `range`,
+//!   garde's `pattern` and formats like `email` and `url`, if their arguments are literals
+//!
 //! - `no-serde-warnings`
 //!
 //!   When `serde-compat` is enabled, warnings are printed during build if unsupported serde
@@ -123,8 +154,115 @@
 //!   `import` statements in the generated file will have the `.js` extension in the end of
 //!   the path to conform to the ES Modules spec. (e.g.: `import { MyStruct } from "./my_struct.js"`)
 //!
+//! - `json-schema`
+//!
+//!   Additionally export a JSON Schema (draft 2020-12) of every type to `<name>.schema.json`,
+//!   next to its bindings. The schema of a type is also available using `TS::json_schema()`.
+//!
+//! - `export-all`
+//!
+//!   In addition to the test generated for every type annotated with `#[ts(export)]` and every
+//!   `export_consts!`, register them to be exported by a single call to `ts_rs::export_all()`,
+//!   e.g. from a binary or one test. Only the types linked into that binary are exported.
+//!
 //! If there's a type you're dealing with which doesn't implement `TS`, use `#[ts(type = "..")]` or open a PR.
 //!
+//! ## configuration
+//! Crate-wide defaults can be set in a `ts-rs.toml` next to the `Cargo.toml` of a crate.
+//! All options are optional, and attributes on a type take precedence over them.
+//! Instead, they can be set in the `[package.metadata.ts-rs]` table of the `Cargo.toml` of a crate.
+//! Crates without their own config use the `ts-rs.toml` in the root of their workspace, or the
+//! `[workspace.metadata.ts-rs]` table of its `Cargo.toml`. Relative paths in the config of a
+//! workspace, like `export_dir`, are relative to the root of the workspace.
+//! Since stable Rust cannot track files read by a derive macro, `ts-rs.toml` is passed to
+//! `include_bytes!` so changing it recompiles the crate. On nightly, set
+//! `RUSTFLAGS="--cfg ts_rs_tracked_path"` to track it using `proc_macro::tracked` instead.
+//! ```toml
+//! # directory types without `#[ts(export_to = "..")]` are exported to, instead of `bindings/`.
+//! # `TS_RS_EXPORT_DIR` takes precedence over this.
+//! export_dir = "../frontend/src/bindings"
+//! # default `rename_all` for the fields of structs and struct variants
+//! rename_all = "camelCase"
+//! # export `Option<T>` fields as `t?: T` ("optional") or as `t?: T | null` ("nullable") instead of `t: T | null`.
+//! # Fields annotated with `#[ts(optional = false)]` are still exported as `t: T | null`
+//! optional = "nullable"
+//! # export `u64`, `i64`, `u128` and `i128` as "number" or "string" instead of "bigint"
+//! large_integers = "number"
+//! # export `usize` and `isize` as "bigint" or "string" instead of "number"
+//! pointer_sized_integers = "bigint"
+//! # language level the bindings are used with. Before "ES2020", which introduced `bigint`, `u64`, `i64`,
+//! # `u128` and `i128` are exported as "string" unless `large_integers` is set
+//! target = "ES2015"
+//! # document the range of integers narrower than 64 bits, e.g. `@minimum 0` and `@maximum 255` for `u8`
+//! numeric_bounds = true
+//! # inserted at the top of every exported file
+//! header = "/* eslint-disable */"
+//! # replaces the "This file was generated by ts-rs" comment following the header.
+//! # `TS_RS_BANNER` takes precedence over this, and an empty banner removes the comment.
+//! banner = "// @generated from the types in `src/models`"
+//! # comments following the banner, each on its own line, e.g. to silence linters and formatters
+//! pragmas = ["// @ts-nocheck", "// prettier-ignore"]
+//! # name of types without `#[ts(rename = "..")]`, in which `{name}` is replaced by the name of the type
+//! # in Rust, e.g. to disambiguate common names like `Error` across the crates of a workspace
+//! type_names = "Api{name}"
+//! # case of the names of files types are exported to, which are named after the type by default.
+//! # Any value accepted by `rename_all`, e.g. "kebab-case" for `user-profile.ts` instead of `UserProfile.ts`
+//! file_names = "kebab-case"
+//! # path of the file types without `#[ts(export_to = "..")]` are exported to within the export directory,
+//! # using the placeholders of `#[ts(export_to = "..")]`, e.g. `models/user_profile.ts` for `UserProfile`
+//! export_to = "models/{snake_name}.ts"
+//! # export types to subdirectories mirroring the modules they are defined in, e.g. `api/v1/User.ts`
+//! # for `crate::api::v1::User`, unless they are annotated with `#[ts(export_to = "..")]`
+//! module_dirs = true
+//! # declare the union of an enum on multiple lines, with a leading `|` for every variant, if it has more
+//! # variants or its declaration would be longer than the given limit
+//! union_max_members = 8
+//! union_max_width = 100
+//! # type of structs without fields, either "Record<string, never>" (the default) or "Record<PropertyKey, never>".
+//! # This also applies to structs whose fields are all skipped, which are exported as `{  }` by default
+//! empty_struct = "Record<PropertyKey, never>"
+//! # export ambient declarations of global types to `.d.ts` files, e.g. `User.d.ts` declaring `type User = ..`,
+//! # without `export` keywords or imports. Values, like `export_consts!` or `#[ts(tag_values)]`, cannot be declared
+//! ambient = true
+//! # import other files with a `.js` extension, like the `import-esm` feature
+//! esm_imports = true
+//! # type of `Result<T, E>`, in which `{T}` and `{E}` are replaced by the types of `T` and `E`, for APIs
+//! # which don't serialize results like serde, e.g. `{ Ok : T } | { Err : E }`
+//! result = "{ ok: {T} } | { err: {E} }"
+//! # style of exported files, which doesn't need the `format` feature, e.g. to match a Prettier config:
+//! # the number of spaces per level of indentation (2 by default) or tabs, single instead of double quotes,
+//! # and whether statements end with a semicolon, which are left as generated if `semicolons` is not set
+//! indent_width = 4
+//! indent_tabs = false
+//! single_quotes = true
+//! semicolons = true
+//! # options used when exporting with the profiles selected by `TS_RS_PROFILE`, e.g. `TS_RS_PROFILE=web,admin`,
+//! # which take precedence over the options above. Every selected profile is exported in one pass, e.g. to
+//! # export bindings to multiple frontends. Only `export_dir`, `header`, `banner`, `pragmas`, `esm_imports` and
+//! # the style of exported files can be set for a profile
+//! [profiles.web]
+//! export_dir = "../web/src/bindings"
+//! esm_imports = true
+//! [profiles.admin]
+//! export_dir = "../admin/src/types"
+//! header = "// @ts-nocheck"
+//! ```
+//!
+//! ## cargo ts-rs
+//! The `cargo-ts-rs` crate provides a cargo subcommand exporting the bindings of the crate in the
+//! current directory, by running the tests generated by `#[ts(export)]` and `export_consts!`.
+//! With the `export-all` feature, name the test calling `ts_rs::export_all()` `export_bindings`.
+//! ```sh
+//! cargo install cargo-ts-rs
+//! # export to the `export_dir` from `ts-rs.toml`, or `bindings/`, after removing the files
+//! # previously generated there
+//! cargo ts-rs export --clean
+//! # fail if the bindings are out of date, without writing anything, e.g. in CI
+//! cargo ts-rs export --check
+//! # merge the bindings into `frontend/src/api/index.ts`, passing `--features api` to `cargo test`
+//! cargo ts-rs export --out-dir frontend/src/api --single-file -- --features api
+//! ```
+//!
 //! ## serde compatability
 //! With the `serde-compat` feature (enabled by default), serde attributes can be parsed for enums and structs.
 //! Supported serde attributes:
@@ -137,9 +275,21 @@
 //! - `skip`
 //! - `flatten`
 //! - `default`
+//! - `alias`
+//! - `borrow`, which doesn't affect the type
+//! - `from`, `try_from` and `into`
+//! - `skip_serializing`, `skip_serializing_if` and `skip_deserializing` (with `#[ts(dual)]`)
+//! - `with`, for chrono's timestamp modules like `chrono::serde::ts_seconds`, the modules of
+//!   `time::serde` like `time::serde::timestamp`, and if it's generated by serde_with's
+//!   `#[serde_as(as = "DisplayFromStr")]`
 //!
-//! Note: `skip_serializing` and `skip_deserializing` are ignored. If you wish to exclude a field
-//! from the generated type, but cannot use `#[serde(skip)]`, use `#[ts(skip)]` instead.
+//! Types with `#[serde(from = "..")]`, `#[serde(try_from = "..")]` or `#[serde(into = "..")]` are
+//! exported as an alias of the type they are (de)serialized as, which must implement `TS`. If both
+//! are present, the type given by `into` is used.
+
+//! Note: `skip_serializing`, `skip_serializing_if` and `skip_deserializing` are ignored, unless the
+//! struct is annotated with `#[ts(dual)]`. If you wish to exclude a field from the generated type,
+//! but cannot use `#[serde(skip)]`, use `#[ts(skip)]` instead.
 //!
 //! When ts-rs encounters an unsupported serde attribute, a warning is emitted, unless the feature `no-serde-warnings` is enabled.
 //!
@@ -168,18 +318,32 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub use ts_rs_macros::TS;
-
-pub use crate::export::ExportError;
-use crate::typelist::TypeList;
+pub use ts_rs_config::sanitize_file_name;
+pub use ts_rs_macros::{export_consts, export_module, TS};
 
 // Used in generated code. Not public API
 #[doc(hidden)]
 pub use crate::export::__private;
+#[cfg(feature = "export-all")]
+pub use crate::export::export_all;
+#[cfg(feature = "format")]
+pub use crate::export::format_decl;
+use crate::typelist::TypeList;
+pub use crate::{
+    export::{clean_unreferenced, export_npm_package, ExportError},
+    ts_type::{TsField, TsType},
+};
 
 #[cfg(feature = "chrono-impl")]
 mod chrono;
+mod diff;
+mod docs;
 mod export;
+mod json_schema;
+#[cfg(feature = "serde-json-impl")]
+mod serde_json;
+mod style;
+pub mod ts_type;
 pub mod typelist;
 
 /// A type which can be represented in TypeScript.  
@@ -200,6 +364,11 @@ pub mod typelist;
 /// Not all serde attributes are supported yet - if you use an unsupported attribute, you'll see a
 /// warning.
 ///
+/// When exporting, these warnings are also written to `ts-rs-warnings.json` in `TS_RS_EXPORT_DIR`
+/// (or `bindings/`), together with types whose representation differs from how serde_json
+/// serializes them, e.g. `u64` exported as `bigint`. Every warning is a JSON object on its own
+/// line, so CI can check for them. See [`TS::warnings`].
+///
 /// ### container attributes
 /// attributes applicable for both structs and enums
 ///
@@ -208,31 +377,172 @@ pub mod typelist;
 ///   `cargo test`. The default base directory can be overridden with the `TS_RS_EXPORT_DIR` environment variable.
 ///   Adding the variable to a project's [config.toml](https://doc.rust-lang.org/cargo/reference/config.html#env) can
 ///   make it easier to manage.
+///   The test is named `export_bindings_<type>_<hash>`, e.g. `export_bindings_user_id_409cc53f`
+///   for `UserId`, so `cargo test export_bindings_` only exports the bindings.
 /// ```toml
 /// # <project-root>/.cargo/config.toml
 /// [env]
 /// TS_RS_EXPORT_DIR = { value = "<OVERRIDE_DIR>", relative = true }
 /// ```
+///   Types which depend on each other across multiple files form an import cycle. Since imports
+///   are generated as `import type`, this is not a problem at runtime. If you'd rather have these
+///   types in a single file, set the `TS_RS_MERGE_CYCLES` environment variable to `true`.
+///   If the `TS_RS_SOURCE_LOCATIONS` environment variable is set to `true`, every declaration is
+///   preceded by a comment pointing to its Rust definition, e.g. `// src/models/user.rs:42`.
+///   The comment at the top of every exported file can be replaced using the `TS_RS_BANNER`
+///   environment variable, or the `banner` option in `ts-rs.toml`.
+///   If the `TS_RS_MANIFEST` environment variable is set to `true`, every exported declaration
+///   is listed in `ts-rs-manifest.json` in `TS_RS_EXPORT_DIR` (or `bindings/`), together with
+///   the file it was written to, its dependencies, its [`TypeKind`] and a hash of the file's
+///   contents. Entries are merged into an existing manifest, so delete it before exporting to
+///   find stale files, which can then be removed using [`clean_unreferenced`].
+///   To publish the bindings, [`export_npm_package`] lays them out as an npm package after
+///   exporting.
+///   If the `TS_RS_CHECK` environment variable is set to `true`, the bindings are compared to the
+///   existing files instead of being written, failing the test with a diff if they're out of
+///   date, see [`TS::export_check`].
 ///
 /// - `#[ts(export_to = "..")]`:  
 ///   Specifies where the type should be exported to. Defaults to `bindings/<name>.ts`.  
+///   Characters of the name which may be invalid in file names are replaced by `_`, see
+///   [`sanitize_file_name`]. Exporting fails with [`ExportError::FileNameCollision`] if the
+///   files of different types are named the same after sanitizing, or only differ in case.  
 ///   The `export_to` attribute will also override the `TS_RS_EXPORT_DIR` environment variable.  
 ///   If the provided path ends in a trailing `/`, it is interpreted as a directory.   
+///   Otherwise, it's the path of the file, e.g. `api/user-model.ts`, which doesn't change the name of the type.  
 ///   Note that you need to add the `export` attribute as well, in order to generate a test which exports the type.
+///   `export_to` can be repeated, e.g. `#[ts(export_to = "api/", export_to = "admin/")]`, to export
+///   a copy of the type to every target. Other types always import it from the first target.
+///   Using `export_to(path = "..", rename = "..")`, the copy can be given a different name.  
+///   The path may contain placeholders which are replaced by the name of the type in TypeScript,
+///   e.g. `#[ts(export_to = "models/{snake_name}.ts")]` for `models/user_profile.ts`. Besides
+///   `{name}`, these are `{lower_name}`, `{upper_name}`, `{camel_name}`, `{snake_name}`,
+///   `{pascal_name}`, `{screaming_snake_name}`, `{kebab_name}` and `{screaming_kebab_name}`.
+///
+/// - `#[ts(file_rename_all = "..")]`:  
+///   Changes the case of the name of the file the type is exported to, which is named after the
+///   type by default, e.g. `#[ts(file_rename_all = "kebab-case")]` for `user-profile.ts`.
+///   This is ignored if `export_to` is the path of a file, and overrides `file_names` in `ts-rs.toml`.
+///
+/// - `#[ts(target = "..")]`:  
+///   Sets the language level this type is used with, like `"ES2015"` or `"ES2020"`, overriding
+///   `target` in `ts-rs.toml`. Before ES2020, `u64`, `i64`, `u128` and `i128` are exported as
+///   `string` instead of `bigint`, unless `large_integers` is set in `ts-rs.toml`.
+///
+/// - `#[ts(cfg(..))]`:  
+///   Only generates the export test if the given predicate holds, e.g. `#[ts(export, cfg(feature = "bindings"))]`.
+///   The predicate is evaluated in the crate deriving `TS`, just like a regular `#[cfg(..)]`.
+///
+/// - `#[ts(skip_generic = "..")]`:  
+///   Omits the given generic parameter from the generated type, e.g. a hasher or a marker type.
+///   The parameter is not required to implement `TS`.
+///   Alternatively, the parameter itself can be annotated, e.g. `struct Id<T, #[ts(skip)] M>`.
+///   Parameters which are only used within `PhantomData`, which is exported as `null`, are
+///   skipped automatically.
+///   Note that when referencing such a type, the argument used for the skipped parameter still
+///   has to implement `TS`.
+///
+/// - `#[ts(concrete(T = ..))]`:  
+///   Pins a generic parameter to a concrete Rust type, e.g. `#[ts(concrete(T = User))]`.
+///   The parameter is omitted from the generated type, and replaced by the given type wherever
+///   it's used. Like skipped parameters, it's not required to implement `TS`.
+///
+/// - `#[ts(generic_bound(T = ".."))]`:  
+///   Constrains a generic parameter in TypeScript, e.g. `type Foo<T extends string | number> = ..`.
+///   Alternatively, the parameter itself can be annotated, e.g. `struct Foo<#[ts(extends = "string")] T>`.
+///
+/// - `#[ts(bound = "..")]`:  
+///   Replaces the `T: TS` bounds added to the generated impl for every generic parameter with
+///   the given where clause, like serde's `bound`, e.g. `#[ts(bound = "")]` for a marker
+///   parameter which only appears in a skipped field, or `#[ts(bound = "T: TS + Clone")]`.
+///
+/// - `#[ts(doc_generic(T = ".."))]`:  
+///   Documents a generic parameter, emitting a `@template T ..` JSDoc tag.
 ///
 /// - `#[ts(rename = "..")]`:  
-///   Sets the typescript name of the generated type
+///   Sets the typescript name of the generated type.
+///   `{name}` is replaced by the name of the type in Rust, e.g. `#[ts(rename = "Api{name}")]`
+///   exports `Config` as `ApiConfig`. This overrides `type_names` in `ts-rs.toml`.
 ///
 /// - `#[ts(rename_all = "..")]`:  
 ///   Rename all fields/variants of the type.
-///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, "kebab-case"
+///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`
+///
+/// - `#[ts(as_display)]`:  
+///   Exports the type as `string`, for types which are serialized using their `Display`
+///   implementation. Its fields or variants are ignored.
+///
+///
+/// ### struct attributes
+///
+/// - `#[ts(readonly)]`:  
+///   Marks all fields of the struct as `readonly`. Flattened fields are not affected.
+///
+/// - `#[ts(skip_private)]`:  
+///   Skips all fields which are not `pub`, including `pub(crate)` fields, as if they were
+///   annotated with `#[ts(skip)]`, e.g. for crates which only serialize public data.
+///   This is only applicable to structs with named fields.
+///
+/// - `#[ts(reviver)]`:  
+///   Declares fields annotated with `#[ts(revive = "..")]` using their revived type, e.g. `Date`.
+///   The JSON representation is declared as `<name>Raw`, together with the functions
+///   `revive<name>(raw: <name>Raw): <name>` and `serialize<name>(value: <name>): <name>Raw`
+///   converting between them. Fields of other types are not revived.
+///
+/// - `#[ts(dual)]`, `#[ts(dual = "..")]`:  
+///   Generates separate types for both directions of (de)serialization. The type itself is the
+///   serialize view, without fields annotated with `skip_serializing`, and with fields annotated
+///   with `#[serde(skip_serializing_if = "..")]` being optional.
+///   The deserialize view is declared as `<name>Input`, or the given name. It omits fields
+///   annotated with `skip_deserializing`, and fields with a `#[serde(default)]` are optional.
+///
+/// - `#[ts(as = "..")]`:  
+///   Exports the struct as an alias of the given type, e.g. a marker struct annotated with
+///   `#[ts(as = "(f64, f64)")]` is declared as `type LatLng = [number, number];`.
+///   Fields can then refer to the alias with `#[ts(as = "LatLng")]` instead of repeating the tuple.
 ///
+/// - `#[ts(index_signature = "..")]`:  
+///   Adds an index signature to the struct, e.g. `#[ts(index_signature = "string => JsonValue")]`
+///   declares `[key: string]: JsonValue`, for objects which may contain additional keys.
+///   Both types are used verbatim, and the types they reference can be imported using
+///   `#[ts(uses(..))]`, e.g. `uses(serde_json::Value)`.
 ///
 /// ### struct field attributes
 ///
 /// - `#[ts(type = "..")]`:  
 ///   Overrides the type used in TypeScript.  
 ///   This is useful when there's a type for which you cannot derive `TS`.  
+///   Generic parameters of the type can be referred to as `{T}`, e.g.
+///   `#[ts(type = "Record<string, {T}>")]`, to keep the override generic.  
+///   When exporting, a warning is written to `ts-rs-warnings.json` if the override is likely stale:
+///   if the type of the field already implements `TS` and is exported as the given type, or if the
+///   given type is exported alongside, e.g. through `uses`, but declared differently than the type
+///   of the field.
+///
+/// - `#[ts(uses(..))]`:  
+///   Declares the types referenced by `#[ts(type = "..")]`, so that they're imported and exported
+///   as well, e.g. `#[ts(type = "Paginated<Item>", uses(Paginated<Item>, Item))]`.
+///   A warning is emitted for every type whose name doesn't appear in the override.
+///
+/// - `#[ts(literal = "..")]`:  
+///   Declares this field as a literal type, for fields which are always serialized as the same
+///   value, e.g. `#[ts(literal = "user")]` declares `kind: "user"`. Numbers and booleans are
+///   supported as well, e.g. `#[ts(literal = 2)]`.
+///
+/// - `#[ts(large_integers = "..")]`:  
+///   Exports `u64`, `i64`, `u128` and `i128` within the type of this field as `"bigint"`,
+///   `"number"` or `"string"`, e.g. if they're serialized as JSON numbers. This overrides
+///   `large_integers` in `ts-rs.toml`.
+///
+/// - `#[ts(pointer_sized_integers = "..")]`:  
+///   Exports `usize` and `isize` within the type of this field as `"number"`, `"bigint"` or
+///   `"string"`, e.g. for IDs which don't fit into a `number`. This overrides
+///   `pointer_sized_integers` in `ts-rs.toml`.
+///
+/// - `#[ts(as_display)]`:  
+///   Exports this field as `string`, e.g. if it's serialized using `serde_with::DisplayFromStr`.
+///   With `serde-compat`, `#[serde_as(as = "DisplayFromStr")]` is recognized as well, including
+///   adapters like `Option<DisplayFromStr>`.
 ///
 /// - `#[ts(rename = "..")]`:  
 ///   Renames this field  
@@ -240,6 +550,11 @@ pub mod typelist;
 /// - `#[ts(inline)]`:  
 ///   Inlines the type of this field  
 ///
+/// - `#[ts(inline, rename_all = "..")]`:  
+///   Renames the keys of the inlined type, e.g. to match the `rename_all` of the struct if the
+///   inlined type uses a different case. Keys of the types flattened into it are renamed as
+///   well, while nested types are not.
+///
 /// - `#[ts(skip)]`:  
 ///   Skip this field  
 ///
@@ -248,15 +563,44 @@ pub mod typelist;
 ///   By default, such a field would turn into `t: T | null`.
 ///   If `#[ts(optional)]` is present, `t?: T` is generated instead.
 ///   If `#[ts(optional = nullable)]` is present, `t?: T | null` is generated.
+///   The default can be changed for a whole crate using `optional` in `ts-rs.toml`, and
+///   `#[ts(optional = false)]` keeps a field as `t: T | null` regardless of it.
+///   On the fields of a tuple struct or variant, it turns the element into `T?` or
+///   `(T | null)?`, which is only allowed if the following fields are optional as well.
+///   On the field of a newtype variant of an adjacently tagged enum, it makes the content
+///   optional, e.g. `{ "type": "V", "content"?: T }`.
 ///
 /// - `#[ts(flatten)]`:  
-///   Flatten this field
+///   Flatten this field.  
+///   Wrappers like `Box<T>` and `Arc<T>` are flattened transparently, while a flattened
+///   `Option<T>` becomes `Partial<T>`, since either all or none of its fields are present.
+///   A flattened map becomes an intersection with a `Record`. Flattening any other type, e.g. a
+///   `Vec<T>`, is a compile error, unless the type depends on a generic parameter.
+///   Exporting fails with [`ExportError::FlattenConflict`] if a key is defined by more than one
+///   flattened type, or by a flattened type and the struct itself.
+///   In the struct variants of enums, flattened types are referenced instead of inlined,
+///   e.g. `{ "Variant": { id: number, } & Inner }` or `{ "type": "Variant" } & Inner` if the enum
+///   is internally tagged.
+///
+/// - `#[ts(readonly)]`:  
+///   Marks this field as `readonly`, e.g. `readonly user_id: number`
+///
+/// - `#[ts(revive = "..")]`:  
+///   Marks this field as one the client should transform after `JSON.parse` by adding a JSDoc tag,
+///   e.g. `@revive Date`. Valid values are `"Date"` (an ISO 8601 string), `"bigint"` (a string)
+///   and `"Uint8Array"` (a base64 encoded string). See `#[ts(reviver)]`.
 ///   
+/// - `#[ts(skip_serializing)]`, `#[ts(skip_deserializing)]`:  
+///   Omits this field from the serialize or deserialize view. See `#[ts(dual)]`.
+///   With `serde-compat`, the serde attributes of the same name are used as well.
+///
 /// ### enum attributes
 ///
 /// - `#[ts(tag = "..")]`:  
 ///   Changes the representation of the enum to store its tag in a separate field.
 ///   See [the serde docs](https://serde.rs/enum-representations.html).
+///   Like with serde, variants without properties, e.g. unit variants or newtype variants of a
+///   unit struct, are represented by the tag alone.
 ///
 /// - `#[ts(content = "..")]`:  
 ///   Changes the representation of the enum to store its content in a separate field.
@@ -266,13 +610,47 @@ pub mod typelist;
 ///   Changes the representation of the enum to not include its tag.
 ///   See [the serde docs](https://serde.rs/enum-representations.html).
 ///
+/// - `#[ts(untagged, unit_variant = "string")]`:  
+///   Represents the unit variants of an untagged enum by their names, e.g. `"Unknown"`, instead
+///   of `null`, for enums which are serialized differently than by serde. Defaults to `"null"`.
+///
 /// - `#[ts(rename_all = "..")]`:  
 ///   Rename all variants of this enum.  
-///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, "kebab-case"
+///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`
 ///
 /// - `#[ts(rename_all_fieds = "..")]`
 ///   Renames the fields of all the struct variants of this enum.
-///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, "kebab-case"
+///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`
+///
+/// - `#[ts(enum)]`, `#[ts(const_enum)]`:  
+///   Emits a fieldless enum as a TypeScript `enum` (or `const enum`) instead of a union of string
+///   literals, e.g. `enum Status { Active = "Active", Inactive = "Inactive" }`.
+///   Cannot be used with `tag`, `content` or `untagged`.
+///
+/// - `#[ts(tag_values)]`, `#[ts(tag_values = "..")]`:  
+///   Additionally exports a constant containing the tag of every variant, e.g.
+///   `export const EventKind = { Created: "created", Deleted: "deleted" } as const;`.
+///   The name of the constant defaults to the name of the enum, suffixed with `Kind`.
+///   Cannot be used with `untagged`.
+///
+/// - `#[ts(values)]`, `#[ts(values = "..")]`:  
+///   Additionally exports a constant listing the value of every variant of a fieldless enum, e.g.
+///   `export const USER_ROLE_VALUES = ["admin", "user"] as const;`, e.g. to build a dropdown.
+///   Skipped variants are omitted. The name of the constant defaults to the name of the enum in
+///   `SCREAMING_SNAKE_CASE`, suffixed with `_VALUES`.
+///   Cannot be used with `tag`, `content` or `untagged`.
+///
+/// - `#[ts(variant_types)]`:  
+///   Additionally exports an alias narrowing the enum to each of its variants, named after the
+///   enum and the variant, e.g. `export type EventClick = Extract<Event, { type: "Click" }>;`.
+///   Can only be used with `tag`.
+///
+/// - `#[ts(from_string)]`, `#[ts(from_string = "..")]`:  
+///   Additionally exports a constant mapping the value and every alias of each variant of a
+///   fieldless enum to the variant, e.g.
+///   `export const LevelFromString: Record<string, Level> = { "low": "low", "lo": "low" };`.
+///   The name of the constant defaults to the name of the enum, suffixed with `FromString`.
+///   Cannot be used with `tag`, `content` or `untagged`.
 ///  
 /// ### enum variant attributes
 ///
@@ -281,38 +659,175 @@ pub mod typelist;
 ///
 /// - `#[ts(skip)]`:  
 ///   Skip this variant  
+///
+/// - `#[ts(alias = "..")]`:  
+///   Adds an additional key for this variant to the constant generated by `#[ts(from_string)]`.  
+///   Can be used multiple times. With `serde-compat`, `#[serde(alias = "..")]` is used as well.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `TS`",
+    note = "derive `TS` for `{Self}`, or override the type it's used in with `#[ts(type = \"..\")]` or `#[ts(as = \"..\")]`"
+)]
 pub trait TS {
     const EXPORT_TO: Option<&'static str> = None;
     const DOCS: Option<&'static str> = None;
+    /// Location of the Rust definition of this type, e.g. `src/models/user.rs:42`.
+    const SOURCE_LOCATION: Option<&'static str> = None;
+    /// Additional files this type is exported to, together with the name it is declared with in
+    /// each of them. Types depending on this type always import it from [`TS::EXPORT_TO`].
+    const ALSO_EXPORT_TO: &'static [(&'static str, &'static str)] = &[];
+    /// `true` if this type can be flattened into another one using `#[ts(flatten)]`, i.e. if it
+    /// implements [`TS::inline_flattened`], or if its [`TS::ts_type`] is an object type. For fields
+    /// whose type doesn't depend on a generic parameter, this is checked at compile time.
+    const FLATTENABLE: bool = false;
+    /// Whether this type is a struct, an enum, a newtype, etc., e.g. for tools generating code
+    /// from the bindings, so they don't have to parse the declaration.
+    const KIND: TypeKind = TypeKind::Primitive;
 
     fn get_export_to() -> Option<String> {
         Self::EXPORT_TO.map(ToString::to_string)
     }
 
-    /// Declaration of this type, e.g. `interface User { user_id: number, ... }`.
+    /// Declaration of this type, e.g. `type User = { user_id: number, ... };`.
     /// This function will panic if the type has no declaration.
     fn decl() -> String {
         panic!("{} cannot be declared", Self::name());
     }
 
+    /// Declaration of this type with its generic parameters substituted by the type arguments
+    /// it's used with, as an alias named after the type and its arguments. For `Paginated<User>`,
+    /// this gives `type PaginatedUser = { items: Array<User>, };`.
+    /// For types without generic parameters, this is equivalent to [`TS::decl`].
+    fn decl_concrete() -> String {
+        Self::decl()
+    }
+
+    /// Additional declarations which are exported alongside [`TS::decl`], e.g. constants
+    /// generated by `#[ts(tag_values)]`.
+    fn extra_decls() -> Vec<String> {
+        vec![]
+    }
+
     /// Name of this type in TypeScript.
     fn name() -> String;
 
+    /// [JSON Schema](https://json-schema.org/) (draft 2020-12) of this type, serialized as a
+    /// `String`. Other exported types are referenced using `$ref`.
+    /// By default, this is `{}`, which accepts any value. `#[derive(TS)]` only implements it with
+    /// the `json-schema` feature.
+    fn json_schema() -> String {
+        "{}".to_owned()
+    }
+
+    /// Warnings about this type, e.g. serde attributes which could not be parsed, or a
+    /// representation which does not match how the type is serialized.
+    /// When exporting, these are written to `ts-rs-warnings.json`.
+    fn warnings() -> Vec<String> {
+        vec![]
+    }
+
+    /// Checks that the types flattened into this type don't define the same keys as each other
+    /// or as this type, which is done before exporting it.
+    #[doc(hidden)]
+    fn check_flatten() -> Result<(), ExportError> {
+        Ok(())
+    }
+
+    /// The fields of this type annotated with `#[ts(type = "..")]`, which are checked for stale
+    /// overrides when exporting it.
+    #[doc(hidden)]
+    fn type_overrides() -> Vec<__private::TypeOverride> {
+        vec![]
+    }
+
+    /// JSON Schema used when this type is referenced by another schema.
+    /// For types which are exported, this is a `$ref` to their exported schema.
+    fn json_schema_ref() -> String {
+        Self::json_schema()
+    }
+
+    /// JSON Schema used when this type is referenced by another schema, given the schemas of
+    /// its type arguments.
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        let _ = args;
+        Self::json_schema_ref()
+    }
+
     /// Name of this type in TypeScript, with type arguments.
     fn name_with_type_args(args: Vec<String>) -> String {
         format!("{}<{}>", Self::name(), args.join(", "))
     }
 
+    /// Name of this type in TypeScript with the type arguments it's used with, e.g.
+    /// `Paginated<User>`, which [`TS::decl_concrete`] refers to its type arguments by.
+    #[doc(hidden)]
+    fn reference() -> String {
+        Self::name()
+    }
+
     /// Formats this types definition in TypeScript, e.g `{ user_id: number }`.
     /// This function will panic if the type cannot be inlined.
     fn inline() -> String {
         panic!("{} cannot be inlined", Self::name());
     }
 
-    /// Flatten an type declaration.  
+    /// [`TS::inline`] parsed into a [`TsType`], which can be transformed and rendered back to
+    /// TypeScript. Parts which cannot be parsed are kept as [`TsType::Raw`].
+    fn ts_type() -> TsType {
+        TsType::parse(&Self::inline())
+    }
+
+    /// Definition of this type when it's flattened into another type using `#[ts(flatten)]`.
+    /// This is an object type like `{ id: number, }`, which may be intersected with the types
+    /// flattened into this type, e.g. `{ id: number, } & ({ "type": "A" } | { "type": "B" })`, or
+    /// a union of such types for enums, or `Record<K, V>` for maps.
+    ///
+    /// If [`TS::FLATTENABLE`] is `true`, this is [`TS::ts_type`] by default, so that types
+    /// implementing `TS` manually, e.g. for a type of another crate, can be flattened by
+    /// implementing [`TS::inline`] or `ts_type`, e.g. using [`TsField::new`]:
+    /// ```
+    /// # use ts_rs::{TS, TsField, TsType};
+    /// struct Metadata; // a type of another crate, which is serialized as an object
+    ///
+    /// impl TS for Metadata {
+    ///     const FLATTENABLE: bool = true;
+    ///     fn name() -> String {
+    ///         "Metadata".to_owned()
+    ///     }
+    ///     fn ts_type() -> TsType {
+    ///         let string = || TsType::Primitive("string".to_owned());
+    ///         TsType::Object(vec![
+    ///             TsField::new("created_at", string()),
+    ///             TsField::new("labels", TsType::Array(Box::new(string()))).optional(),
+    ///         ])
+    ///     }
+    ///     fn transparent() -> bool {
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// #[derive(TS)]
+    /// struct Post {
+    ///     id: i32,
+    ///     #[ts(flatten)]
+    ///     metadata: Metadata,
+    /// }
+    ///
+    /// assert_eq!(Post::inline(), "{ id: number, created_at: string, labels?: string[], }");
+    /// ```
     /// This function will panic if the type cannot be flattened.
     fn inline_flattened() -> String {
-        panic!("{} cannot be flattened", Self::name())
+        if !Self::FLATTENABLE {
+            panic!("{} cannot be flattened", Self::name())
+        }
+        let ty = Self::ts_type();
+        if !ty.is_flattenable() {
+            panic!(
+                "{} cannot be flattened, since `{}` is not an object type",
+                Self::name(),
+                ty
+            )
+        }
+        ty.to_string()
     }
 
     fn dependency_types() -> impl TypeList
@@ -331,13 +846,33 @@ pub trait TS {
         struct Visit<'a>(&'a mut Vec<Dependency>);
         impl<'a> TypeVisitor for Visit<'a> {
             fn visit<T: TS + 'static + ?Sized>(&mut self) {
-                if let Some(dep) = Dependency::from_ty::<T>() {
+                // a type may be reachable through multiple fields, e.g. `T` and `Vec<T>`
+                if self.0.iter().any(|dep| dep.type_id == TypeId::of::<T>()) {
+                    return;
+                }
+                let Some(dep) = Dependency::from_ty::<T>() else {
+                    return;
+                };
+                // different instances of a generic type, like `Page<User>` and `Page<Group>`,
+                // are imported once
+                let is_imported = |other: &Dependency| {
+                    other.ts_name == dep.ts_name && other.exported_to == dep.exported_to
+                };
+                if !self.0.iter().any(is_imported) {
                     self.0.push(dep);
                 }
             }
         }
         Self::dependency_types().for_each(&mut Visit(&mut deps));
 
+        // a recursive type depends on itself, possibly with different type arguments, e.g.
+        // `Tree<T>` containing `Vec<Tree<String>>`, but it's never imported into its own file
+        deps.retain(|dep| {
+            let is_self = dep.type_id == TypeId::of::<Self>()
+                || (dep.ts_name == Self::name()
+                    && Some(&dep.exported_to) == Self::get_export_to().as_ref());
+            !is_self
+        });
         deps
     }
 
@@ -349,14 +884,54 @@ pub trait TS {
     /// The output file can be specified by annotating the type with `#[ts(export_to = ".."]`.
     /// By default, the filename will be derived from the types name.
     ///
-    /// When a type is annotated with `#[ts(export)]`, it is exported automatically within a test.
+    /// When a type is annotated with `#[ts(export)]`, it is exported automatically within a test,
+    /// and by `export_all` if the `export-all` feature is enabled.
     /// This function is only usefull if you need to export the type outside of the context of a
     /// test.
     fn export() -> Result<(), ExportError>
     where
         Self: 'static,
     {
-        export::export_type_with_dependencies::<Self>()
+        export::export_type_with_dependencies::<Self>(&mut |_| {})
+    }
+
+    /// Like [`TS::export`], but instead of writing the bindings, checks that the files they would
+    /// be written to are up to date, without writing anything. If a file differs, this fails with
+    /// [`ExportError::OutOfDate`], which describes how, e.g. to fail CI if the bindings weren't
+    /// exported again after changing a type:
+    /// ```no_run
+    /// # use ts_rs::TS;
+    /// # #[derive(TS)]
+    /// # struct User { id: u32 }
+    /// #[test]
+    /// fn bindings_are_up_to_date() {
+    ///     if let Err(err) = User::export_check() {
+    ///         panic!("{err}");
+    ///     }
+    /// }
+    /// ```
+    /// The tests generated by `#[ts(export)]` check the bindings the same way if the `TS_RS_CHECK`
+    /// environment variable is set to `true`.
+    fn export_check() -> Result<(), ExportError>
+    where
+        Self: 'static,
+    {
+        export::check_type_with_dependencies::<Self>()
+    }
+
+    /// Like [`TS::export`], but calls `transform` with the contents of every exported file before
+    /// it's written, e.g. to add a license banner:
+    /// ```no_run
+    /// # use ts_rs::TS;
+    /// # #[derive(TS)]
+    /// # struct User { id: u32 }
+    /// User::export_with(|file| file.insert_str(0, "// SPDX-License-Identifier: MIT\n")).unwrap();
+    /// ```
+    fn export_with(mut transform: impl FnMut(&mut String)) -> Result<(), ExportError>
+    where
+        Self: 'static,
+    {
+        export::export_type_with_dependencies::<Self>(&mut transform)
     }
 
     /// Manually export this type to a file with a file with the specified path. This
@@ -369,7 +944,8 @@ pub trait TS {
     }
 
     /// Manually generate bindings for this type, returning a [`String`].  
-    /// This function does not format the output, even if the `format` feature is enabled.
+    /// This function does not format the output, even if the `format` feature is enabled, but it
+    /// can be formatted using `format_decl`.
     fn export_to_string() -> Result<String, ExportError>
     where
         Self: 'static,
@@ -405,6 +981,64 @@ impl Dependency {
     }
 }
 
+/// The kind of Rust type a [`TS`] implementation describes, see [`TS::KIND`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    /// A struct with named fields
+    Struct,
+    /// An enum, together with how its variants are represented
+    Enum { representation: EnumRepresentation },
+    /// A struct with a single unnamed field
+    Newtype,
+    /// A struct with multiple unnamed fields, or a tuple
+    Tuple,
+    /// A struct without fields, e.g. `struct Marker;` or `struct Empty {}`
+    Unit,
+    /// A type exported as a built-in TypeScript type, e.g. numbers and strings.
+    /// This is the default for types implementing [`TS`] manually.
+    Primitive,
+    /// A type containing values of other types, e.g. `Vec<T>`, `Option<T>` or `HashMap<K, V>`
+    Container,
+}
+
+impl TypeKind {
+    /// The name of the kind, e.g. `"newtype"`, as written to `ts-rs-manifest.json`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TypeKind::Struct => "struct",
+            TypeKind::Enum { .. } => "enum",
+            TypeKind::Newtype => "newtype",
+            TypeKind::Tuple => "tuple",
+            TypeKind::Unit => "unit",
+            TypeKind::Primitive => "primitive",
+            TypeKind::Container => "container",
+        }
+    }
+}
+
+/// How the variants of an enum are represented, following serde's enum representations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// `{ "Variant": content }`, which is the default
+    External,
+    /// `{ "tag": "Variant", ...fields }`, given by `#[ts(tag = "..")]`
+    Internal { tag: &'static str },
+    /// `{ "tag": "Variant", "content": content }`, given by `#[ts(tag = "..", content = "..")]`
+    Adjacent {
+        tag: &'static str,
+        content: &'static str,
+    },
+    /// Only the content of the variant, given by `#[ts(untagged)]`
+    Untagged,
+}
+
+/// A type exported as `unknown`, for types which can't implement [`TS`], e.g. trait objects.
+/// Use it with `#[ts(as = "..")]`, e.g. `#[ts(as = "Box<ts_rs::Unknown>")]` on a field of type
+/// `Box<dyn MyTrait>`.
+///
+/// Trait objects of `std::error::Error` and `std::any::Any` are exported as `unknown` without it.
+pub struct Unknown;
+
 // generate impls for primitive types
 macro_rules! impl_primitives {
     ($($($ty:ty),* => $l:literal),*) => { $($(
@@ -415,6 +1049,7 @@ macro_rules! impl_primitives {
                 $l.to_owned()
             }
             fn inline() -> String { $l.to_owned() }
+            fn json_schema() -> String { $crate::json_schema::primitive($l) }
             fn transparent() -> bool { false }
         }
     )*)* };
@@ -423,12 +1058,16 @@ macro_rules! impl_primitives {
 macro_rules! impl_tuples {
     ( impl $($i:ident),* ) => {
         impl<$($i: TS),*> TS for ($($i,)*) {
+            const KIND: TypeKind = TypeKind::Tuple;
             fn name() -> String {
                 format!("[{}]", [$($i::name()),*].join(", "))
             }
             fn inline() -> String {
                 format!("[{}]", [$($i::inline()),*].join(", "))
             }
+            fn json_schema() -> String {
+                json_schema::tuple(&[$($i::json_schema_ref()),*])
+            }
             fn dependency_types() -> impl TypeList
             where
                 Self: 'static
@@ -449,13 +1088,22 @@ macro_rules! impl_tuples {
 macro_rules! impl_wrapper {
     ($($t:tt)*) => {
         $($t)* {
+            const FLATTENABLE: bool = T::FLATTENABLE;
+            const KIND: TypeKind = T::KIND;
             fn name() -> String { T::name() }
+            fn reference() -> String { T::reference() }
             fn name_with_type_args(mut args: Vec<String>) -> String {
                 assert_eq!(args.len(), 1);
                 args.remove(0)
             }
             fn inline() -> String { T::inline() }
             fn inline_flattened() -> String { T::inline_flattened() }
+            fn json_schema() -> String { T::json_schema() }
+            fn json_schema_ref() -> String { T::json_schema_ref() }
+            fn json_schema_ref_with_type_args(mut args: Vec<String>) -> String {
+                assert_eq!(args.len(), 1);
+                args.remove(0)
+            }
             fn dependency_types() -> impl TypeList
             where
                 Self: 'static
@@ -471,10 +1119,18 @@ macro_rules! impl_wrapper {
 macro_rules! impl_shadow {
     (as $s:ty: $($impl:tt)*) => {
         $($impl)* {
+            const FLATTENABLE: bool = <$s>::FLATTENABLE;
+            const KIND: $crate::TypeKind = <$s>::KIND;
             fn name() -> String { <$s>::name() }
+            fn reference() -> String { <$s>::reference() }
             fn name_with_type_args(args: Vec<String>) -> String { <$s>::name_with_type_args(args) }
             fn inline() -> String { <$s>::inline() }
             fn inline_flattened() -> String { <$s>::inline_flattened() }
+            fn json_schema() -> String { <$s>::json_schema() }
+            fn json_schema_ref() -> String { <$s>::json_schema_ref() }
+            fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+                <$s>::json_schema_ref_with_type_args(args)
+            }
             fn dependency_types() -> impl $crate::typelist::TypeList
             where
                 Self: 'static
@@ -485,10 +1141,19 @@ macro_rules! impl_shadow {
         }
     };
 }
+#[allow(unused_imports)]
+pub(crate) use impl_shadow;
 
 impl<T: TS> TS for Option<T> {
+    const FLATTENABLE: bool = T::FLATTENABLE;
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        unreachable!();
+        format!("{} | null", T::name())
+    }
+
+    fn reference() -> String {
+        format!("{} | null", T::reference())
     }
 
     fn name_with_type_args(args: Vec<String>) -> String {
@@ -505,6 +1170,20 @@ impl<T: TS> TS for Option<T> {
         format!("{} | null", T::inline())
     }
 
+    // when flattened, either all or none of the fields of `T` are present
+    fn inline_flattened() -> String {
+        format!("Partial<{}>", T::inline_flattened())
+    }
+
+    fn json_schema() -> String {
+        json_schema::nullable(&T::json_schema_ref())
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 1);
+        json_schema::nullable(&args[0])
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -518,11 +1197,37 @@ impl<T: TS> TS for Option<T> {
 }
 
 impl<T: TS, E: TS> TS for Result<T, E> {
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        unreachable!();
+        Self::name_with_type_args(vec![T::name(), E::name()])
+    }
+    fn name_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(
+            args.len(),
+            2,
+            "called Result::name_with_type_args with {} args",
+            args.len()
+        );
+        export::result_type(&args[0], &args[1])
     }
     fn inline() -> String {
-        format!("{{ Ok : {} }} | {{ Err : {} }}", T::inline(), E::inline())
+        export::result_type(&T::inline(), &E::inline())
+    }
+    fn json_schema() -> String {
+        Self::json_schema_ref_with_type_args(vec![T::json_schema_ref(), E::json_schema_ref()])
+    }
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 2);
+        // the shape of a custom `result` isn't known, so any value is accepted
+        if export::result_template().is_some() {
+            return "{}".to_owned();
+        }
+        format!(
+            r#"{{"anyOf":[{},{}]}}"#,
+            json_schema::object(&[("Ok", &args[0])]),
+            json_schema::object(&[("Err", &args[1])])
+        )
     }
     fn dependency_types() -> impl TypeList
     where
@@ -536,14 +1241,39 @@ impl<T: TS, E: TS> TS for Result<T, E> {
 }
 
 impl<T: TS> TS for Vec<T> {
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        "Array".to_owned()
+        format!("Array<{}>", T::name())
+    }
+
+    fn reference() -> String {
+        format!("Array<{}>", T::reference())
+    }
+
+    fn name_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(
+            args.len(),
+            1,
+            "called Vec::name_with_type_args with {} args",
+            args.len()
+        );
+        format!("Array<{}>", args[0])
     }
 
     fn inline() -> String {
         format!("Array<{}>", T::inline())
     }
 
+    fn json_schema() -> String {
+        json_schema::array(&T::json_schema_ref())
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 1);
+        json_schema::array(&args[0])
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -558,12 +1288,10 @@ impl<T: TS> TS for Vec<T> {
 // Arrays longer than this limit will be emitted as Array<T>
 const ARRAY_TUPLE_LIMIT: usize = 64;
 impl<T: TS, const N: usize> TS for [T; N] {
-    fn name() -> String {
-        if N > ARRAY_TUPLE_LIMIT {
-            return Vec::<T>::name();
-        }
+    const KIND: TypeKind = TypeKind::Container;
 
-        "[]".to_owned()
+    fn name() -> String {
+        Self::name_with_type_args(vec![T::name()])
     }
 
     fn name_with_type_args(args: Vec<String>) -> String {
@@ -598,6 +1326,18 @@ impl<T: TS, const N: usize> TS for [T; N] {
         )
     }
 
+    fn json_schema() -> String {
+        Self::json_schema_ref_with_type_args(vec![T::json_schema_ref()])
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 1);
+        format!(
+            r#"{{"type":"array","items":{},"minItems":{1},"maxItems":{1}}}"#,
+            args[0], N
+        )
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -611,8 +1351,11 @@ impl<T: TS, const N: usize> TS for [T; N] {
 }
 
 impl<K: TS, V: TS, H> TS for HashMap<K, V, H> {
+    const FLATTENABLE: bool = true;
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        "Record".to_owned()
+        Self::name_with_type_args(vec![K::name(), V::name()])
     }
 
     fn name_with_type_args(args: Vec<String>) -> String {
@@ -629,6 +1372,20 @@ impl<K: TS, V: TS, H> TS for HashMap<K, V, H> {
         format!("Record<{}, {}>", K::inline(), V::inline())
     }
 
+    // serde collects the fields which don't belong to the other type into a flattened map
+    fn inline_flattened() -> String {
+        Self::inline()
+    }
+
+    fn json_schema() -> String {
+        json_schema::record(&V::json_schema_ref())
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 2);
+        json_schema::record(&args[1])
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -642,8 +1399,10 @@ impl<K: TS, V: TS, H> TS for HashMap<K, V, H> {
 }
 
 impl<I: TS> TS for Range<I> {
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        panic!("called Range::name - Did you use a type alias?")
+        Self::name_with_type_args(vec![I::name()])
     }
 
     fn name_with_type_args(args: Vec<String>) -> String {
@@ -656,6 +1415,19 @@ impl<I: TS> TS for Range<I> {
         format!("{{ start: {}, end: {}, }}", &args[0], &args[0])
     }
 
+    fn inline() -> String {
+        Self::name_with_type_args(vec![I::inline()])
+    }
+
+    fn json_schema() -> String {
+        Self::json_schema_ref_with_type_args(vec![I::json_schema_ref()])
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 1);
+        json_schema::object(&[("start", &args[0]), ("end", &args[0])])
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -669,8 +1441,10 @@ impl<I: TS> TS for Range<I> {
 }
 
 impl<I: TS> TS for RangeInclusive<I> {
+    const KIND: TypeKind = TypeKind::Container;
+
     fn name() -> String {
-        panic!("called RangeInclusive::name - Did you use a type alias?")
+        Self::name_with_type_args(vec![I::name()])
     }
 
     fn name_with_type_args(args: Vec<String>) -> String {
@@ -683,6 +1457,19 @@ impl<I: TS> TS for RangeInclusive<I> {
         format!("{{ start: {}, end: {}, }}", &args[0], &args[0])
     }
 
+    fn inline() -> String {
+        Self::name_with_type_args(vec![I::inline()])
+    }
+
+    fn json_schema() -> String {
+        Self::json_schema_ref_with_type_args(vec![I::json_schema_ref()])
+    }
+
+    fn json_schema_ref_with_type_args(args: Vec<String>) -> String {
+        assert_eq!(args.len(), 1);
+        json_schema::object(&[("start", &args[0]), ("end", &args[0])])
+    }
+
     fn dependency_types() -> impl TypeList
     where
         Self: 'static,
@@ -695,6 +1482,29 @@ impl<I: TS> TS for RangeInclusive<I> {
     }
 }
 
+// serde serializes `PhantomData` like `()`, so its type argument doesn't need to implement `TS`
+impl<T: ?Sized> TS for std::marker::PhantomData<T> {
+    fn name() -> String {
+        "null".to_owned()
+    }
+
+    fn name_with_type_args(_: Vec<String>) -> String {
+        Self::name()
+    }
+
+    fn inline() -> String {
+        Self::name()
+    }
+
+    fn json_schema() -> String {
+        json_schema::primitive("null")
+    }
+
+    fn transparent() -> bool {
+        false
+    }
+}
+
 impl_shadow!(as T: impl<T: TS + ?Sized> TS for &T);
 impl_shadow!(as Vec<T>: impl<T: TS, H> TS for HashSet<T, H>);
 impl_shadow!(as Vec<T>: impl<T: TS> TS for BTreeSet<T>);
@@ -709,7 +1519,6 @@ impl_wrapper!(impl<T: TS> TS for std::cell::Cell<T>);
 impl_wrapper!(impl<T: TS> TS for std::cell::RefCell<T>);
 impl_wrapper!(impl<T: TS> TS for std::sync::Mutex<T>);
 impl_wrapper!(impl<T: TS + ?Sized> TS for std::sync::Weak<T>);
-impl_wrapper!(impl<T: TS> TS for std::marker::PhantomData<T>);
 
 impl_tuples!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
 
@@ -746,6 +1555,11 @@ impl_shadow!(as Vec<T>: impl<K: index_vec::Idx, T: TS> TS for index_vec::IndexVe
 #[cfg(feature = "semver-impl")]
 impl_primitives! { semver::Version => "string" }
 
+#[cfg(feature = "time-impl")]
+impl_primitives! {
+    time::OffsetDateTime, time::PrimitiveDateTime, time::Date, time::Time, time::Duration => "string"
+}
+
 #[cfg(feature = "bytes-impl")]
 mod bytes {
     use super::TS;
@@ -766,5 +1580,11 @@ impl_primitives! {
     Ipv4Addr, Ipv6Addr, IpAddr, SocketAddrV4, SocketAddrV6, SocketAddr => "string",
     () => "null"
 }
+impl_primitives! {
+    Unknown,
+    dyn std::error::Error, dyn std::error::Error + Send, dyn std::error::Error + Send + Sync,
+    dyn std::any::Any, dyn std::any::Any + Send, dyn std::any::Any + Send + Sync => "unknown"
+}
 #[rustfmt::skip]
+#[allow(unused_imports)]
 pub(crate) use impl_primitives;
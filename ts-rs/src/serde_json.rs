@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+
+use super::{impl_primitives, impl_shadow, TS};
+use crate::typelist::TypeList;
+
+// objects may be missing any key, so they're not exported as a `Record`
+const JSON_VALUE: &str =
+    "number | string | boolean | Array<JsonValue> | { [key in string]?: JsonValue } | null";
+
+impl_primitives!(Number => "number");
+impl_shadow!(as HashMap<String, Value>: impl TS for Map<String, Value>);
+
+// `Value` is exported once as the recursive type `JsonValue`, which is then referenced by name
+impl TS for Value {
+    const EXPORT_TO: Option<&'static str> = Some("bindings/JsonValue.ts");
+
+    fn get_export_to() -> Option<String> {
+        crate::__private::get_export_to_path("JsonValue.ts")
+    }
+    fn decl() -> String {
+        format!("type JsonValue = {};", JSON_VALUE)
+    }
+    fn name() -> String {
+        "JsonValue".to_owned()
+    }
+    fn inline() -> String {
+        JSON_VALUE.to_owned()
+    }
+    fn json_schema() -> String {
+        "{}".to_owned()
+    }
+    fn json_schema_ref() -> String {
+        crate::__private::json_schema_ref::<Self>()
+    }
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        ().push::<Self>()
+    }
+    fn transparent() -> bool {
+        false
+    }
+}
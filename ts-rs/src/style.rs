@@ -0,0 +1,175 @@
+//! Lightweight formatting of exported files, changing their indentation, the quotes of string
+//! literals and the semicolons terminating statements as configured in `ts-rs.toml`, without
+//! depending on the `format` feature.
+
+/// How exported files are written, see `indent_width`, `indent_tabs`, `single_quotes` and
+/// `semicolons` in `ts-rs.toml`.
+#[derive(Default)]
+pub(crate) struct Style {
+    /// What every level of indentation is replaced by, if it's not two spaces
+    pub indent: Option<String>,
+    /// Whether string literals in double quotes are enclosed in single quotes instead
+    pub single_quotes: bool,
+    /// Whether statements are terminated by a semicolon, or `None` to leave them as they are
+    pub semicolons: Option<bool>,
+}
+
+/// A line of the file, after applying the indentation and quotes of the style.
+struct Line {
+    text: String,
+    /// Byte offset in `text` after the last character which isn't part of a comment or whitespace
+    code_end: Option<usize>,
+    /// Nesting depth of brackets at the end of the line
+    depth: usize,
+}
+
+/// Applies `style` to `text`, the contents of an exported file, which is indented by two spaces
+/// per level.
+pub(crate) fn apply(text: &str, style: &Style) -> String {
+    if style.indent.is_none() && !style.single_quotes && style.semicolons.is_none() {
+        return text.to_owned();
+    }
+
+    let mut lines = Vec::new();
+    let mut in_comment = false;
+    let mut depth = 0usize;
+    for line in text.split('\n') {
+        let mut out = String::with_capacity(line.len());
+        let code = line.trim_start_matches(' ');
+        let spaces = line.len() - code.len();
+        match &style.indent {
+            Some(indent) => {
+                out.push_str(&indent.repeat(spaces / 2));
+                out.push_str(&" ".repeat(spaces % 2));
+            }
+            None => out.push_str(&line[..spaces]),
+        }
+
+        let mut code_end = None;
+        let mut chars = code.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_comment {
+                out.push(c);
+                if c == '*' && chars.peek() == Some(&'/') {
+                    out.push(chars.next().unwrap());
+                    in_comment = false;
+                }
+                continue;
+            }
+            match c {
+                '/' if chars.peek() == Some(&'/') => {
+                    out.push(c);
+                    out.extend(chars.by_ref());
+                    break;
+                }
+                '/' if chars.peek() == Some(&'*') => {
+                    out.push(c);
+                    out.push(chars.next().unwrap());
+                    in_comment = true;
+                    continue;
+                }
+                '"' | '\'' | '`' => push_string(&mut out, &mut chars, c, style),
+                '{' | '[' | '(' => {
+                    depth += 1;
+                    out.push(c);
+                }
+                '}' | ']' | ')' => {
+                    depth = depth.saturating_sub(1);
+                    out.push(c);
+                }
+                _ => out.push(c),
+            }
+            if !c.is_whitespace() {
+                code_end = Some(out.len());
+            }
+        }
+
+        lines.push(Line {
+            text: out,
+            code_end,
+            depth,
+        });
+    }
+
+    if let Some(semicolons) = style.semicolons {
+        terminate_statements(&mut lines, semicolons);
+    }
+    let lines = lines.into_iter().map(|line| line.text).collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+// pushes the string literal starting with `quote`, which was already consumed from `chars`
+fn push_string(
+    out: &mut String,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    quote: char,
+    style: &Style,
+) {
+    let convert = quote == '"' && style.single_quotes;
+    out.push(if convert { '\'' } else { quote });
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"') if convert => out.push('"'),
+                Some(escaped) => {
+                    out.push(c);
+                    out.push(escaped);
+                }
+                None => out.push(c),
+            },
+            '\'' if convert => out.push_str("\\'"),
+            c if c == quote => {
+                out.push(if convert { '\'' } else { quote });
+                return;
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+/// Adds or removes the semicolon at the end of every statement, except for declarations of enums,
+/// interfaces, functions and namespaces, which are not terminated by one.
+/// A statement ends on a line outside of any brackets which is followed by a blank or unindented
+/// line, e.g. a multiline union continues on the indented lines of its variants.
+fn terminate_statements(lines: &mut [Line], semicolons: bool) {
+    let mut start = None;
+    for i in 0..lines.len() {
+        let Some(code_end) = lines[i].code_end else {
+            continue;
+        };
+        let first = *start.get_or_insert(i);
+        let ends = lines[i].depth == 0
+            && lines
+                .get(i + 1)
+                .is_none_or(|next| !next.text.starts_with(char::is_whitespace));
+        if !ends {
+            continue;
+        }
+        start = None;
+
+        let statement = lines[first].text.trim_start();
+        let statement = statement.strip_prefix("export ").unwrap_or(statement);
+        let statement = statement.strip_prefix("declare ").unwrap_or(statement);
+        let terminated = [
+            "enum ",
+            "const enum ",
+            "interface ",
+            "function ",
+            "namespace ",
+        ]
+        .iter()
+        .all(|keyword| !statement.starts_with(keyword));
+        if !terminated {
+            continue;
+        }
+
+        let text = &mut lines[i].text;
+        match (text[..code_end].ends_with(';'), semicolons) {
+            (true, false) => {
+                text.remove(code_end - 1);
+            }
+            (false, true) => text.insert(code_end, ';'),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,485 @@
+//! TypeScript types parsed from the output of ts-rs, see [`TS::ts_type`].
+//!
+//! `#[derive(TS)]` generates TypeScript as strings, which [`TsType::parse`] turns into a
+//! [`TsType`].
+//!
+//! A [`TsType`] can be inspected and transformed before it's rendered back to TypeScript using its
+//! `Display` implementation. With the alternate flag (`{:#}`), objects are rendered on multiple
+//! lines.
+//!
+//! [`TS::ts_type`]: crate::TS::ts_type
+
+use std::fmt::{self, Display, Formatter};
+
+const PRIMITIVES: [&str; 12] = [
+    "number",
+    "bigint",
+    "string",
+    "boolean",
+    "null",
+    "undefined",
+    "never",
+    "unknown",
+    "any",
+    "void",
+    "object",
+    "symbol",
+];
+
+/// A TypeScript type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TsType {
+    /// A primitive type, like `number`, `string` or `null`
+    Primitive(String),
+    /// A literal type as written in TypeScript, like `"active"`, `42` or `true`
+    Literal(String),
+    /// A reference to a named type, like `User` or `Array<number>`
+    Reference { name: String, args: Vec<TsType> },
+    /// An object type, like `{ id: number, }`
+    Object(Vec<TsField>),
+    /// A tuple, like `[number, string]`
+    Tuple(Vec<TsType>),
+    /// An array written as `T[]`
+    Array(Box<TsType>),
+    /// A union, like `A | B`
+    Union(Vec<TsType>),
+    /// An intersection, like `A & B`
+    Intersection(Vec<TsType>),
+    /// A type which could not be parsed, e.g. one given by `#[ts(type = "..")]`
+    Raw(String),
+}
+
+/// A property of an object type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TsField {
+    /// The name of the property, which is quoted if it's not a valid identifier
+    pub name: String,
+    pub ty: TsType,
+    pub optional: bool,
+    pub readonly: bool,
+    /// The JSDoc comment of the property, starting with `/**` and ending with `*/`
+    pub docs: Option<String>,
+}
+
+impl TsField {
+    /// A required property named `name`, which is quoted if it's not a valid identifier, e.g.
+    /// `TsField::new("created_at", TsType::Primitive("string".to_owned()))`.
+    pub fn new(name: &str, ty: TsType) -> Self {
+        Self {
+            name: property_name(name),
+            ty,
+            optional: false,
+            readonly: false,
+            docs: None,
+        }
+    }
+
+    /// Marks the property as optional, e.g. `tags?: Array<string>`.
+    pub fn optional(self) -> Self {
+        Self {
+            optional: true,
+            ..self
+        }
+    }
+
+    /// Marks the property as `readonly`.
+    pub fn readonly(self) -> Self {
+        Self {
+            readonly: true,
+            ..self
+        }
+    }
+}
+
+/// Quotes `name` if it's not a valid identifier, so it can be used as the name of a property.
+pub(crate) fn property_name(name: &str) -> String {
+    let is_ident = name.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    match is_ident {
+        true => name.to_owned(),
+        false => format!("\"{}\"", name),
+    }
+}
+
+impl TsType {
+    /// Whether the fields of a value of this type can be flattened into an object, which is the
+    /// case for object types, references to other types, and unions and intersections of those.
+    pub(crate) fn is_flattenable(&self) -> bool {
+        match self {
+            TsType::Primitive(_) | TsType::Literal(_) | TsType::Tuple(_) | TsType::Array(_) => {
+                false
+            }
+            TsType::Union(types) | TsType::Intersection(types) => {
+                types.iter().all(TsType::is_flattenable)
+            }
+            TsType::Reference { .. } | TsType::Object(_) | TsType::Raw(_) => true,
+        }
+    }
+
+    /// Replaces every reference to one of the generic parameters in `args`, e.g. `T`, by the type
+    /// given for it.
+    pub(crate) fn substitute(&mut self, args: &[(&str, TsType)]) {
+        match self {
+            TsType::Reference {
+                name,
+                args: type_args,
+            } if type_args.is_empty() => {
+                if let Some((_, ty)) = args.iter().find(|(param, _)| param == name) {
+                    *self = ty.clone();
+                }
+            }
+            TsType::Reference { args: types, .. }
+            | TsType::Tuple(types)
+            | TsType::Union(types)
+            | TsType::Intersection(types) => {
+                types.iter_mut().for_each(|ty| ty.substitute(args));
+            }
+            TsType::Array(elem) => elem.substitute(args),
+            TsType::Object(fields) => fields
+                .iter_mut()
+                .for_each(|field| field.ty.substitute(args)),
+            TsType::Primitive(_) | TsType::Literal(_) | TsType::Raw(_) => (),
+        }
+    }
+
+    /// Parses a type as generated by ts-rs, e.g. by [`TS::inline`](crate::TS::inline).
+    /// If the type contains syntax which isn't supported, [`TsType::Raw`] is returned.
+    pub fn parse(ts: &str) -> Self {
+        let mut parser = Parser { src: ts, pos: 0 };
+        match parser.ty() {
+            Some(ty) if parser.at_end() => ty,
+            _ => TsType::Raw(ts.trim().to_owned()),
+        }
+    }
+
+    // `indent` is `None` if the type is rendered on a single line
+    fn render(&self, out: &mut String, indent: Option<usize>) {
+        match self {
+            TsType::Primitive(s) | TsType::Literal(s) | TsType::Raw(s) => out.push_str(s),
+            TsType::Reference { name, args } => {
+                out.push_str(name);
+                if !args.is_empty() {
+                    out.push('<');
+                    render_list(out, args, ", ", indent, |_| false);
+                    out.push('>');
+                }
+            }
+            TsType::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            TsType::Object(fields) => render_object(out, fields, indent),
+            TsType::Tuple(elems) => {
+                out.push('[');
+                render_list(out, elems, ", ", indent, |_| false);
+                out.push(']');
+            }
+            TsType::Array(elem) => {
+                let parens = matches!(**elem, TsType::Union(_) | TsType::Intersection(_));
+                render_parenthesized(out, elem, parens, indent);
+                out.push_str("[]");
+            }
+            TsType::Union(variants) => render_list(out, variants, " | ", indent, |_| false),
+            TsType::Intersection(parts) => render_list(out, parts, " & ", indent, |ty| {
+                matches!(ty, TsType::Union(_))
+            }),
+        }
+    }
+}
+
+impl Display for TsType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.render(&mut out, f.alternate().then_some(0));
+        f.write_str(&out)
+    }
+}
+
+fn render_object(out: &mut String, fields: &[TsField], indent: Option<usize>) {
+    let field_indent = indent.map(|indent| indent + 2);
+    out.push('{');
+    for field in fields {
+        match field_indent {
+            None => {
+                out.push(' ');
+                if let Some(docs) = &field.docs {
+                    out.push('\n');
+                    out.push_str(docs);
+                    out.push('\n');
+                }
+            }
+            Some(indent) => {
+                let pad = " ".repeat(indent);
+                out.push('\n');
+                for line in field.docs.iter().flat_map(|docs| docs.lines()) {
+                    out.push_str(&pad);
+                    // lines of the comment following `/**` are aligned to its `*`
+                    match line.trim_start().starts_with('*') {
+                        true => out.push_str(&format!(" {}", line.trim())),
+                        false => out.push_str(line.trim()),
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&pad);
+            }
+        }
+        if field.readonly {
+            out.push_str("readonly ");
+        }
+        out.push_str(&field.name);
+        if field.optional {
+            out.push('?');
+        }
+        out.push_str(": ");
+        field.ty.render(out, field_indent);
+        out.push(',');
+    }
+    match indent {
+        None => out.push_str(" }"),
+        Some(indent) => {
+            out.push('\n');
+            out.push_str(&" ".repeat(indent));
+            out.push('}');
+        }
+    }
+}
+
+fn render_list(
+    out: &mut String,
+    types: &[TsType],
+    separator: &str,
+    indent: Option<usize>,
+    parens: impl Fn(&TsType) -> bool,
+) {
+    for (i, ty) in types.iter().enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        render_parenthesized(out, ty, parens(ty), indent);
+    }
+}
+
+fn render_parenthesized(out: &mut String, ty: &TsType, parens: bool, indent: Option<usize>) {
+    if parens {
+        out.push('(');
+    }
+    ty.render(out, indent);
+    if parens {
+        out.push(')');
+    }
+}
+
+// A recursive descent parser for the subset of TypeScript generated by ts-rs.
+// Every method returns `None` if the input doesn't match.
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let eaten = self.rest().starts_with(token);
+        if eaten {
+            self.pos += token.len();
+        }
+        eaten
+    }
+
+    fn expect(&mut self, token: &str) -> Option<()> {
+        self.eat(token).then_some(())
+    }
+
+    // consumes the longest prefix of the remaining input whose characters match `f`
+    fn take_while(&mut self, f: impl Fn(usize, char) -> bool) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(i, c)| !f(i, c))
+            .map_or(rest.len(), |(i, _)| i);
+        self.pos += len;
+        (len > 0).then(|| &rest[..len])
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        self.take_while(|i, c| {
+            c == '_' || c == '$' || c.is_alphabetic() || (i > 0 && (c.is_numeric() || c == '.'))
+        })
+    }
+
+    fn number(&mut self) -> Option<&'a str> {
+        self.take_while(|i, c| c.is_ascii_digit() || c == '.' || (i == 0 && c == '-'))
+    }
+
+    // a string literal, including its quotes
+    fn string(&mut self) -> Option<&'a str> {
+        let start = self.pos;
+        self.expect("\"")?;
+        let mut escaped = false;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                '"' if !escaped => {
+                    self.pos += i + 1;
+                    return Some(&self.src[start..self.pos]);
+                }
+                '\\' => escaped = !escaped,
+                _ => escaped = false,
+            }
+        }
+        None
+    }
+
+    // a JSDoc comment, including `/**` and `*/`
+    fn docs(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        if !rest.starts_with("/**") {
+            return None;
+        }
+        let len = rest.find("*/")? + 2;
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    fn ty(&mut self) -> Option<TsType> {
+        // a union may start with a leading `|`
+        self.eat("|");
+        let mut variants = vec![self.intersection()?];
+        while self.eat("|") {
+            variants.push(self.intersection()?);
+        }
+        Some(flatten(variants, TsType::Union))
+    }
+
+    fn intersection(&mut self) -> Option<TsType> {
+        let mut parts = vec![self.postfix()?];
+        while self.eat("&") {
+            parts.push(self.postfix()?);
+        }
+        Some(flatten(parts, TsType::Intersection))
+    }
+
+    fn postfix(&mut self) -> Option<TsType> {
+        let mut ty = self.primary()?;
+        while self.rest().starts_with("[]") {
+            self.pos += 2;
+            ty = TsType::Array(Box::new(ty));
+        }
+        Some(ty)
+    }
+
+    fn primary(&mut self) -> Option<TsType> {
+        match self.peek()? {
+            '(' => {
+                self.expect("(")?;
+                let ty = self.ty()?;
+                self.expect(")")?;
+                Some(ty)
+            }
+            '{' => self.object(),
+            '[' => {
+                self.expect("[")?;
+                let mut elems = Vec::new();
+                while !self.eat("]") {
+                    elems.push(self.ty()?);
+                    if !self.eat(",") {
+                        self.expect("]")?;
+                        break;
+                    }
+                }
+                Some(TsType::Tuple(elems))
+            }
+            '"' => self.string().map(|s| TsType::Literal(s.to_owned())),
+            c if c == '-' || c.is_ascii_digit() => {
+                self.number().map(|n| TsType::Literal(n.to_owned()))
+            }
+            _ => {
+                let name = self.ident()?;
+                if name == "true" || name == "false" {
+                    return Some(TsType::Literal(name.to_owned()));
+                }
+                if PRIMITIVES.contains(&name) {
+                    return Some(TsType::Primitive(name.to_owned()));
+                }
+
+                let mut args = Vec::new();
+                if self.eat("<") {
+                    loop {
+                        args.push(self.ty()?);
+                        if !self.eat(",") {
+                            break;
+                        }
+                    }
+                    self.expect(">")?;
+                }
+                Some(TsType::Reference {
+                    name: name.to_owned(),
+                    args,
+                })
+            }
+        }
+    }
+
+    fn object(&mut self) -> Option<TsType> {
+        self.expect("{")?;
+        let mut fields = Vec::new();
+        while !self.eat("}") {
+            let docs = self.docs().map(ToOwned::to_owned);
+            let mut name = self.key()?;
+            // `readonly` is a modifier, unless it's the name of the property
+            let readonly = name == "readonly" && !matches!(self.peek()?, ':' | '?');
+            if readonly {
+                name = self.key()?;
+            }
+            let optional = self.eat("?");
+            self.expect(":")?;
+            fields.push(TsField {
+                name: name.to_owned(),
+                ty: self.ty()?,
+                optional,
+                readonly,
+                docs,
+            });
+            if !self.eat(",") && !self.eat(";") {
+                self.expect("}")?;
+                break;
+            }
+        }
+        Some(TsType::Object(fields))
+    }
+
+    fn key(&mut self) -> Option<&'a str> {
+        match self.peek()? {
+            '"' => self.string(),
+            c if c.is_ascii_digit() => self.number(),
+            _ => self.ident(),
+        }
+    }
+}
+
+// a union or intersection of a single type is the type itself
+fn flatten(mut types: Vec<TsType>, f: impl Fn(Vec<TsType>) -> TsType) -> TsType {
+    match types.len() {
+        1 => types.remove(0),
+        _ => f(types),
+    }
+}
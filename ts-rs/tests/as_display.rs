@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use std::{fmt, net::IpAddr};
+
+use ts_rs::TS;
+
+/// A color, serialized as `#rrggbb`
+#[derive(TS)]
+#[ts(as_display, export_to = "tests-out/as_display/")]
+struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+#[derive(TS)]
+#[ts(as_display)]
+enum Version {
+    Stable { major: u32, minor: u32 },
+    Nightly(String),
+}
+
+#[derive(TS)]
+struct Host {
+    #[ts(as_display)]
+    addr: IpAddr,
+    color: Color,
+}
+
+#[test]
+fn container() {
+    assert_eq!(Color::inline(), "string");
+    assert_eq!(Color::decl(), "type Color = string;");
+    assert_eq!(Version::decl(), "type Version = string;");
+    assert!(Color::dependencies().is_empty());
+}
+
+#[test]
+fn field() {
+    assert_eq!(Host::inline(), "{ addr: string, color: Color, }");
+}
+
+#[cfg(feature = "serde-compat")]
+mod serde_as {
+    use std::net::IpAddr;
+
+    use serde::Serialize;
+    use serde_with::DisplayFromStr;
+    use ts_rs::TS;
+
+    // what `#[serde_as(as = "..")]` expands to, using a stand-in for serde_with
+    #[derive(Serialize, TS)]
+    struct Server {
+        #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+        addr: IpAddr,
+        #[serde(with = "serde_with :: As :: < Option < DisplayFromStr > >")]
+        fallback: Option<IpAddr>,
+        #[serde(with = "serde_with::As::<Vec<DisplayFromStr>>")]
+        peers: Vec<IpAddr>,
+        #[ts(type = "`${number}.${number}.${number}.${number}`")]
+        #[serde(with = "serde_with::As::<DisplayFromStr>")]
+        gateway: IpAddr,
+    }
+
+    mod serde_with {
+        use std::marker::PhantomData;
+
+        use serde::Serializer;
+
+        pub struct As<T>(PhantomData<T>);
+        pub struct DisplayFromStr;
+
+        impl<T> As<T> {
+            pub fn serialize<V, S: Serializer>(_: &V, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_unit()
+            }
+        }
+    }
+
+    #[test]
+    fn display_from_str() {
+        assert_eq!(
+            Server::inline(),
+            "{ addr: string, fallback: string | null, peers: Array<string>, gateway: `${number}.${number}.${number}.${number}`, }"
+        );
+        assert!(Server::warnings().is_empty());
+    }
+}
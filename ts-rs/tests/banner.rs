@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/banner/")]
+struct Banner {
+    id: u32,
+}
+
+// the banner is only read once, so this is the only test exporting a type in this file
+#[test]
+fn banner() {
+    std::env::set_var(
+        "TS_RS_BANNER",
+        "// @ts-nocheck\n// generated, do not edit\n",
+    );
+
+    Banner::export().unwrap();
+
+    let content = fs::read_to_string("tests-out/banner/Banner.ts").unwrap();
+    assert!(content.starts_with("// @ts-nocheck\n// generated, do not edit\n"));
+    assert!(!content.contains("This file was generated"));
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+use ts_rs::TS;
+
+// doesn't implement `TS`
+#[derive(Debug)]
+struct Customer;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/bound/", bound = "")]
+struct Id<M> {
+    id: i32,
+    #[ts(skip)]
+    owners: Vec<M>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/bound/", bound = "T: TS + Debug")]
+enum Event<T> {
+    Created(T),
+    Deleted { id: Id<T> },
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/bound/", bound = "T: TS")]
+struct Unsized<T: ?Sized> {
+    value: Box<T>,
+}
+
+#[test]
+fn empty_bound() {
+    assert_eq!(Id::<Customer>::decl(), "type Id<M> = { id: number, }");
+    assert_eq!(Id::<Customer>::inline(), "{ id: number, }");
+}
+
+#[test]
+fn custom_bound() {
+    assert_eq!(
+        Event::<()>::decl(),
+        r#"type Event<T> = { "Created": T } | { "Deleted": { id: Id<T>, } };"#
+    );
+    assert_eq!(Unsized::<str>::decl(), "type Unsized<T> = { value: T, }");
+}
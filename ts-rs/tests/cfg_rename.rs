@@ -0,0 +1,65 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "tests-out/cfg_rename/")]
+#[ts(rename = "UserV1")]
+#[cfg_attr(feature = "serde-compat", ts(rename = "UserV2"))]
+struct User {
+    id: i32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/cfg_rename/")]
+struct Session {
+    user: User,
+}
+
+const USER: &str = if cfg!(feature = "serde-compat") {
+    "UserV2"
+} else {
+    "UserV1"
+};
+
+#[test]
+fn cfg_rename() {
+    assert_eq!(User::name(), USER);
+    assert_eq!(
+        User::EXPORT_TO,
+        Some(format!("tests-out/cfg_rename/{USER}.ts").as_str())
+    );
+
+    Session::export().unwrap();
+
+    let session = fs::read_to_string("tests-out/cfg_rename/Session.ts").unwrap();
+    assert!(session.contains(&format!("import type {{ {USER} }} from \"./{USER}\";")));
+    assert!(fs::metadata(format!("tests-out/cfg_rename/{USER}.ts")).is_ok());
+}
+
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", derive(serde::Serialize))]
+#[ts(rename = "TsAccount")]
+#[cfg_attr(feature = "serde-compat", serde(rename = "SerdeAccount"))]
+struct Account {
+    #[ts(rename = "tsName")]
+    #[cfg_attr(feature = "serde-compat", serde(rename = "serdeName"))]
+    name: String,
+}
+
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", derive(serde::Serialize))]
+enum Role {
+    #[ts(rename = "TsAdmin")]
+    #[cfg_attr(feature = "serde-compat", serde(rename = "SerdeAdmin"))]
+    Admin,
+}
+
+#[test]
+fn ts_rename_over_serde_rename() {
+    assert_eq!(Account::name(), "TsAccount");
+    assert_eq!(Account::inline(), "{ tsName: string, }");
+    assert_eq!(Role::inline(), r#""TsAdmin""#);
+}
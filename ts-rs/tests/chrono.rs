@@ -30,3 +30,31 @@ fn chrono() {
         "type Chrono = { date: [string, string, string, string], time: string, date_time: [string, string, string, string], duration: string, month: string, weekday: string, }"
     )
 }
+
+#[cfg(feature = "serde-compat")]
+#[test]
+fn chrono_timestamps() {
+    use chrono::serde::{ts_milliseconds, ts_seconds_option};
+    use serde::Serialize;
+
+    #[derive(Serialize, TS)]
+    #[allow(dead_code)]
+    struct Event {
+        #[serde(with = "chrono::serde::ts_seconds")]
+        created: DateTime<Utc>,
+        #[serde(with = "ts_milliseconds")]
+        updated: DateTime<Utc>,
+        #[serde(with = "ts_seconds_option")]
+        deleted: Option<DateTime<Utc>>,
+        #[ts(type = "Date")]
+        #[serde(with = "::chrono::serde::ts_microseconds")]
+        received: DateTime<Utc>,
+        scheduled: DateTime<Utc>,
+    }
+
+    assert_eq!(
+        Event::inline(),
+        "{ created: number, updated: number, deleted: number | null, received: Date, scheduled: string, }"
+    );
+    assert!(Event::warnings().is_empty());
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use std::{fs, path::Path};
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct Kept {
+    id: u32,
+}
+
+// the export directory and whether the manifest is written are only read once, so this is the
+// only test exporting a type in this file
+#[test]
+fn clean_unreferenced() {
+    std::env::set_var("TS_RS_EXPORT_DIR", "tests-out/clean_unreferenced");
+    std::env::set_var("TS_RS_MANIFEST", "1");
+
+    let dir = Path::new("tests-out/clean_unreferenced");
+    let _ = fs::remove_dir_all(dir);
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    fs::write(dir.join("Renamed.ts"), "export type Renamed = number;").unwrap();
+    fs::write(
+        dir.join("nested/Removed.ts"),
+        "export type Removed = number;",
+    )
+    .unwrap();
+    fs::write(dir.join("notes.md"), "not generated").unwrap();
+
+    Kept::export().unwrap();
+
+    let removed = ts_rs::clean_unreferenced(dir).unwrap();
+    let removed = removed
+        .iter()
+        .map(|path| path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        removed,
+        [dir.join("Renamed.ts"), dir.join("nested/Removed.ts")]
+    );
+    assert!(dir.join("Kept.ts").is_file());
+    assert!(dir.join("notes.md").is_file());
+    assert!(dir.join("ts-rs-manifest.json").is_file());
+}
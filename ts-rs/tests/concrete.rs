@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct User {
+    id: u32,
+}
+
+// doesn't implement `TS`
+struct Internal;
+
+#[derive(TS)]
+#[ts(concrete(T = User))]
+struct Response<T, E> {
+    data: Option<Vec<T>>,
+    error: E,
+}
+
+#[derive(TS)]
+#[ts(concrete(T = String, E = i32))]
+enum Outcome<T, E> {
+    Done(T),
+    Failed { error: E },
+}
+
+#[derive(TS)]
+struct Page {
+    response: Response<User, String>,
+    outcome: Outcome<u8, u8>,
+}
+
+#[test]
+fn struct_with_concrete_generic() {
+    assert_eq!(
+        Response::<User, ()>::decl(),
+        "type Response<E> = { data: Array<User> | null, error: E, }"
+    );
+    // the pinned parameter doesn't have to implement `TS`
+    assert_eq!(
+        Response::<Internal, ()>::decl(),
+        Response::<User, ()>::decl()
+    );
+}
+
+#[test]
+fn enum_with_concrete_generics() {
+    assert_eq!(
+        Outcome::<String, i32>::decl(),
+        r#"type Outcome = { "Done": string } | { "Failed": { error: number, } };"#
+    );
+}
+
+#[test]
+fn reference() {
+    assert_eq!(
+        Page::inline(),
+        "{ response: Response<string>, outcome: Outcome, }"
+    );
+}
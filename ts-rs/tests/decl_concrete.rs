@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct User {
+    id: u32,
+}
+
+#[derive(TS)]
+struct Paginated<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+#[derive(TS)]
+enum Response<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+#[derive(TS)]
+struct Pair<A, B>(A, B);
+
+#[test]
+fn generic_struct() {
+    assert_eq!(
+        Paginated::<User>::decl_concrete(),
+        "type PaginatedUser = { items: Array<User>, next: string | null, };"
+    );
+    assert_eq!(
+        Paginated::<Vec<Paginated<User>>>::decl_concrete(),
+        "type PaginatedArrayPaginatedUser = { items: Array<Array<Paginated<User>>>, next: string | null, };"
+    );
+    assert_eq!(
+        Paginated::<()>::decl(),
+        "type Paginated<T> = { items: Array<T>, next: string | null, }"
+    );
+}
+
+#[test]
+fn generic_enum() {
+    assert_eq!(
+        Response::<Paginated<User>, String>::decl_concrete(),
+        r#"type ResponsePaginatedUserString = { "Ok": Paginated<User>, } | { "Err": string, };"#
+    );
+}
+
+#[test]
+fn generic_tuple() {
+    assert_eq!(
+        Pair::<u8, bool>::decl_concrete(),
+        "type PairNumberBoolean = [number, boolean];"
+    );
+}
+
+#[test]
+fn not_generic() {
+    assert_eq!(User::decl_concrete(), User::decl());
+}
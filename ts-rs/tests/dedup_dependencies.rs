@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct Inner {
+    value: i32,
+}
+
+#[derive(TS)]
+struct Repeated<'a, 'b> {
+    a: Inner,
+    b: Inner,
+    c: Vec<Inner>,
+    d: Option<Inner>,
+    e: &'a Inner,
+    f: &'b Inner,
+    g: HashMap<String, Inner>,
+    h: (Inner, Inner),
+}
+
+#[derive(TS)]
+enum Variants<'a> {
+    A(Inner),
+    B { inner: Inner },
+    C(&'a Inner, Vec<Inner>),
+}
+
+#[test]
+fn deduplicated() {
+    assert_eq!(names(Repeated::dependencies()), ["Inner"]);
+    assert_eq!(names(Variants::dependencies()), ["Inner"]);
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/dedup_dependencies/")]
+struct Tree {
+    children: Vec<Tree>,
+    parent: Option<Box<Tree>>,
+    inner: Inner,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/dedup_dependencies/")]
+struct Page<T: 'static> {
+    items: Vec<T>,
+    next: Option<Box<Page<i32>>>,
+    groups: Vec<Group<T>>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/dedup_dependencies/")]
+struct Group<T: 'static> {
+    members: Vec<T>,
+    pages: Vec<Page<String>>,
+    subgroups: Vec<Group<bool>>,
+    page: Page<T>,
+}
+
+fn names(deps: Vec<ts_rs::Dependency>) -> Vec<String> {
+    deps.into_iter().map(|dep| dep.ts_name).collect()
+}
+
+#[test]
+fn recursive() {
+    assert_eq!(names(Tree::dependencies()), ["Inner"]);
+    let exported = Tree::export_to_string().unwrap();
+    assert!(!exported.contains("import type { Tree }"));
+}
+
+#[test]
+fn mutually_generic() {
+    assert_eq!(names(Page::<()>::dependencies()), ["Group"]);
+    assert_eq!(names(Group::<()>::dependencies()), ["Page"]);
+
+    let exported = Page::<()>::export_to_string().unwrap();
+    assert!(exported.contains("import type { Group } from \"./Group\";\n\n"));
+    assert!(!exported.contains("import type { Page }"));
+}
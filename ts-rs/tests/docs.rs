@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, deprecated)]
 
 use std::{concat, fs};
 
@@ -298,16 +298,34 @@ fn export_f() {
             " *\n",
             " * Testing\n",
             " */\n",
-            "export type F = \"VarA\" | { \"VarB\": never[] } | {\n",
-            "  \"VarC\": {\n",
-            "    /**\n",
-            "     * Doc of field of variant\n",
-            "     *\n",
-            "     * Testing\n",
-            "     */\n",
-            "    variant_field: number;\n",
-            "  };\n",
-            "};\n"
+            "export type F =\n",
+            "  /**\n",
+            "   * Doc of variant\n",
+            "   *\n",
+            "   * Testing\n",
+            "   */\n",
+            "  | \"VarA\"\n",
+            "  /**\n",
+            "   * Doc of variant\n",
+            "   *\n",
+            "   * Testing\n",
+            "   */\n",
+            "  | { \"VarB\": never[] }\n",
+            "  /**\n",
+            "   * Doc of variant\n",
+            "   *\n",
+            "   * Testing\n",
+            "   */\n",
+            "  | {\n",
+            "    \"VarC\": {\n",
+            "      /**\n",
+            "       * Doc of field of variant\n",
+            "       *\n",
+            "       * Testing\n",
+            "       */\n",
+            "      variant_field: number;\n",
+            "    };\n",
+            "  };\n"
         )
     } else {
         concat!(
@@ -318,7 +336,25 @@ fn export_f() {
             " *\n",
             " * Testing\n",
             " */\n",
-            "export type F = \"VarA\" | { \"VarB\": never[] } | { \"VarC\": { \n",
+            "export type F = \n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "\"VarA\" | \n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "{ \"VarB\": never[] } | \n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "{ \"VarC\": { \n",
             "/**\n",
             " * Doc of field of variant\n",
             " *\n",
@@ -347,16 +383,35 @@ fn export_g() {
             "     */\n",
             "    some_other_field: number;\n",
             "  }\n",
-            "  & (\"VarA\" | { \"VarB\": never[] } | {\n",
-            "    \"VarC\": {\n",
-            "      /**\n",
-            "       * Doc of field of variant\n",
-            "       *\n",
-            "       * Testing\n",
-            "       */\n",
-            "      variant_field: number;\n",
-            "    };\n",
-            "  });\n"
+            "  & (\n",
+            "    /**\n",
+            "     * Doc of variant\n",
+            "     *\n",
+            "     * Testing\n",
+            "     */\n",
+            "    | \"VarA\"\n",
+            "    /**\n",
+            "     * Doc of variant\n",
+            "     *\n",
+            "     * Testing\n",
+            "     */\n",
+            "    | { \"VarB\": never[] }\n",
+            "    /**\n",
+            "     * Doc of variant\n",
+            "     *\n",
+            "     * Testing\n",
+            "     */\n",
+            "    | {\n",
+            "      \"VarC\": {\n",
+            "        /**\n",
+            "         * Doc of field of variant\n",
+            "         *\n",
+            "         * Testing\n",
+            "         */\n",
+            "        variant_field: number;\n",
+            "      };\n",
+            "    }\n",
+            "  );\n"
         )
     } else {
         concat!(
@@ -366,7 +421,25 @@ fn export_g() {
             "/**\n",
             " * Docs\n",
             " */\n",
-            "some_other_field: number, } & (\"VarA\" | { \"VarB\": never[] } | { \"VarC\": { \n",
+            "some_other_field: number, } & (\n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "\"VarA\" | \n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "{ \"VarB\": never[] } | \n",
+            "/**\n",
+            " * Doc of variant\n",
+            " *\n",
+            " * Testing\n",
+            " */\n",
+            "{ \"VarC\": { \n",
             "/**\n",
             " * Doc of field of variant\n",
             " *\n",
@@ -374,12 +447,184 @@ fn export_g() {
             " */\n",
             "variant_field: number, } })",
         )
-
-
-
     };
 
     let actual_content = fs::read_to_string("tests-out/docs/G.ts").unwrap();
 
     assert_eq!(actual_content, expected_content);
 }
+
+/// Doc comment.
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/docs/",
+    doc_generic(T = "type of the item", E = "type of the error")
+)]
+struct Paginated<T, E> {
+    items: Vec<T>,
+    error: Option<E>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/docs/", doc_generic(T = "type of the value"))]
+enum Maybe<T> {
+    Some(T),
+    None,
+}
+
+#[test]
+fn generic_docs() {
+    assert_eq!(
+        Paginated::<(), ()>::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * Doc comment.\n",
+            " *\n",
+            " * @template T type of the item\n",
+            " * @template E type of the error\n",
+            " */\n",
+        ))
+    );
+    assert_eq!(
+        Maybe::<()>::DOCS,
+        Some("/**\n * @template T type of the value\n */\n")
+    );
+}
+
+#[derive(TS)]
+struct Point(
+    /// Horizontal position
+    i32,
+    /// Vertical position
+    i32,
+);
+
+/// Identifier of a user
+#[derive(TS)]
+struct UserId(
+    /// Assigned by the database
+    u64,
+);
+
+#[derive(TS)]
+#[ts(enum)]
+enum Level {
+    /// Only errors
+    Error,
+    Info,
+}
+
+#[test]
+fn member_docs() {
+    assert_eq!(
+        Point::inline(),
+        "[\n/**\n * Horizontal position\n */\nnumber, \n/**\n * Vertical position\n */\nnumber]"
+    );
+    assert_eq!(
+        UserId::DOCS,
+        Some("/**\n * Identifier of a user\n *\n * Assigned by the database\n */\n")
+    );
+    assert_eq!(
+        Level::decl(),
+        "enum Level { \n/**\n * Only errors\n */\nError = \"Error\", Info = \"Info\" }"
+    );
+}
+
+/// Owner of an [`Account`], see [the account](crate::Account) and [Profile].
+/// Unlike [`Vec::new`], links to [the docs](https://docs.rs) and `[Account]` are kept.
+#[derive(TS)]
+#[ts(export_to = "tests-out/docs/")]
+struct Owner {
+    /// The [`Account`] of the owner
+    account: Account,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/docs/")]
+struct Account {
+    id: u32,
+}
+
+#[test]
+fn doc_links() {
+    let exported = Owner::export_to_string().unwrap();
+    assert!(exported.contains(concat!(
+        " * Owner of an {@link Account}, see {@link Account | the account} and `Profile`.\n",
+        " * Unlike `Vec::new`, links to [the docs](https://docs.rs) and `[Account]` are kept.\n",
+    )));
+    assert!(exported.contains(" * The {@link Account} of the owner\n"));
+}
+
+/// Creates a session
+///
+/// ```
+/// # let token = "";
+/// let session = Session::new(token); /* [Session] */
+/// ## not hidden
+/// ```
+///
+/// ```json
+/// # { "token": "" }
+/// ```
+#[derive(TS)]
+struct Session;
+
+#[test]
+fn doc_code_blocks() {
+    assert_eq!(
+        Session::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * Creates a session\n",
+            " *\n",
+            " * ```rust\n",
+            " * let session = Session::new(token); /* [Session] *\\/\n",
+            " * # not hidden\n",
+            " * ```\n",
+            " *\n",
+            " * ```json\n",
+            " * # { \"token\": \"\" }\n",
+            " * ```\n",
+            " */\n",
+        ))
+    );
+}
+
+/// A user of the legacy API
+#[derive(TS)]
+#[deprecated(since = "2.0.0", note = "use `User` instead")]
+struct LegacyUser {
+    #[deprecated = "always empty"]
+    nickname: String,
+    #[deprecated]
+    /// Unix timestamp
+    created: u32,
+}
+
+#[derive(TS)]
+enum LegacyRole {
+    Admin,
+    /// Read only access
+    #[deprecated(note = "use `Admin`")]
+    Guest,
+}
+
+#[test]
+fn deprecated() {
+    assert_eq!(
+        LegacyUser::DOCS,
+        Some("/**\n * A user of the legacy API\n *\n * @deprecated use `User` instead\n */\n")
+    );
+    assert_eq!(
+        LegacyUser::decl(),
+        concat!(
+            "type LegacyUser = { ",
+            "\n/**\n * @deprecated always empty\n */\nnickname: string, ",
+            "\n/**\n * Unix timestamp\n *\n * @deprecated\n */\ncreated: number, }"
+        )
+    );
+    assert_eq!(
+        LegacyRole::decl(),
+        "type LegacyRole = \"Admin\" | \n/**\n * Read only access\n *\n * @deprecated use `Admin`\n */\n\"Guest\";"
+    );
+}
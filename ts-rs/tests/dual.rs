@@ -0,0 +1,81 @@
+#![cfg(feature = "serde-compat")]
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(dual, export_to = "tests-out/dual/")]
+struct User {
+    #[serde(skip_deserializing)]
+    id: u32,
+    name: String,
+    #[serde(skip_serializing)]
+    password: String,
+    #[serde(default)]
+    admin: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[serde(default)]
+#[ts(dual = "NewSettings")]
+struct Settings {
+    theme: String,
+    #[ts(skip_serializing)]
+    token: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "light".to_owned(),
+            token: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, TS)]
+struct Ignored {
+    #[serde(skip_serializing)]
+    secret: String,
+}
+
+#[test]
+fn views() {
+    assert_eq!(
+        User::inline(),
+        "{ id: number, name: string, admin: boolean, nickname?: string | null, }"
+    );
+    assert_eq!(
+        User::extra_decls(),
+        vec!["type UserInput = { name: string, password: string, admin?: boolean, nickname: string | null, };"]
+    );
+}
+
+#[test]
+fn custom_name() {
+    assert_eq!(Settings::inline(), "{ theme: string, }");
+    assert_eq!(
+        Settings::extra_decls(),
+        vec!["type NewSettings = { theme?: string, token?: string | null, };"]
+    );
+}
+
+#[test]
+fn export() {
+    User::export().unwrap();
+    let exported = std::fs::read_to_string("tests-out/dual/User.ts").unwrap();
+    assert!(exported.contains("export type UserInput = "));
+}
+
+#[test]
+fn ignored_without_dual() {
+    assert_eq!(Ignored::inline(), "{ secret: string, }");
+    assert!(Ignored::extra_decls().is_empty());
+    assert_eq!(
+        Ignored::warnings(),
+        vec!["`skip_serializing` is ignored unless the struct is annotated with `#[ts(dual)]`"]
+    );
+}
@@ -53,3 +53,35 @@ pub fn enum_struct_rename_all_fields() {
         r#"{ "Running": { "started-time": string, } } | { "Terminated": { status: number, stdout: string, stderr: string, } }"#
     )
 }
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS, Clone)]
+#[cfg_attr(feature = "serde-compat", serde(rename_all_fields = "camelCase"))]
+#[cfg_attr(not(feature = "serde-compat"), ts(rename_all_fields = "camelCase"))]
+pub enum TaskStatus3 {
+    Running {
+        started_time: String,
+    },
+
+    #[cfg_attr(feature = "serde-compat", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
+    #[cfg_attr(not(feature = "serde-compat"), ts(rename_all = "SCREAMING_SNAKE_CASE"))]
+    Terminated {
+        exit_status: i32,
+        #[cfg_attr(feature = "serde-compat", serde(rename = "output"))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(rename = "output"))]
+        std_out: String,
+    },
+
+    #[ts(rename_all = "kebab-case")]
+    Failed {
+        error_message: String,
+    },
+}
+
+#[test]
+pub fn enum_struct_rename_all_fields_with_override() {
+    assert_eq!(
+        TaskStatus3::inline(),
+        r#"{ "Running": { startedTime: string, } } | { "Terminated": { EXIT_STATUS: number, output: string, } } | { "Failed": { "error-message": string, } }"#
+    )
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/enum_values/", values, rename_all = "lowercase")]
+enum UserRole {
+    Admin,
+    #[ts(rename = "member")]
+    User,
+    #[ts(skip)]
+    System,
+}
+
+#[derive(TS)]
+#[ts(enum, values = "Colors")]
+enum Color {
+    Red,
+    Green,
+}
+
+#[test]
+fn union() {
+    assert_eq!(
+        UserRole::extra_decls(),
+        vec![r#"const USER_ROLE_VALUES = ["admin", "member"] as const;"#]
+    );
+}
+
+#[test]
+fn ts_enum() {
+    assert_eq!(
+        Color::extra_decls(),
+        vec!["const Colors = [Color.Red, Color.Green] as const;"]
+    );
+}
+
+#[test]
+fn exported() {
+    let exported = UserRole::export_to_string().unwrap();
+    assert!(exported.contains(r#"export const USER_ROLE_VALUES = ["admin", "member"] as const;"#));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "export-all")]
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use ts_rs::{export_consts, TS};
+
+#[derive(TS)]
+#[ts(export, export_to = "tests-out/export_all/")]
+struct Registered {
+    value: Dependency,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/export_all/")]
+struct Dependency {
+    value: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "tests-out/export_all/")]
+struct Generic<T> {
+    value: T,
+}
+
+export_consts! {
+    #![ts(export_to = "tests-out/export_all/")]
+    const LIMIT: u32 = 10;
+}
+
+#[test]
+fn export_all() {
+    ts_rs::export_all().unwrap();
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests-out/export_all");
+    for file in [
+        "Registered.ts",
+        "Dependency.ts",
+        "Generic.ts",
+        "constants.ts",
+    ] {
+        assert!(dir.join(file).is_file(), "{} was not exported", file);
+    }
+}
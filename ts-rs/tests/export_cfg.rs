@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "tests-out/export_cfg/", cfg(all()))]
+struct Enabled {
+    a: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "tests-out/export_cfg/", cfg(any()))]
+enum Disabled {
+    A,
+}
+
+// If the export test for `Disabled` was generated, this would be a duplicate definition.
+fn export_bindings_disabled_bfce9925() {}
+
+#[test]
+fn export_test_is_gated() {
+    export_bindings_enabled_9c86e43e();
+    assert!(std::path::Path::new("tests-out/export_cfg/Enabled.ts").exists());
+}
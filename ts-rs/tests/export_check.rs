@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+use std::{fs, path::Path};
+
+use ts_rs::{ExportError, TS};
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/export_check/")]
+struct Checked {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn export_check() {
+    let path = Path::new("tests-out/export_check/Checked.ts");
+    let _ = fs::remove_file(path);
+
+    // a missing file is out of date, and isn't created
+    let err = Checked::export_check().unwrap_err();
+    assert!(matches!(err, ExportError::OutOfDate { .. }));
+    assert!(!path.exists());
+
+    Checked::export().unwrap();
+    Checked::export_check().unwrap();
+
+    let outdated = fs::read_to_string(path)
+        .unwrap()
+        .replace("name: string", "name: number");
+    fs::write(path, &outdated).unwrap();
+
+    let ExportError::OutOfDate { diff, .. } = Checked::export_check().unwrap_err() else {
+        panic!("expected the bindings to be out of date");
+    };
+    assert!(diff.starts_with("@@ line "));
+    let removed = diff.lines().filter(|line| line.starts_with('-'));
+    let added = diff.lines().filter(|line| line.starts_with('+'));
+    assert!(removed.collect::<Vec<_>>().concat().contains("name: number"));
+    assert!(added.collect::<Vec<_>>().concat().contains("name: string"));
+
+    // nothing was written
+    assert_eq!(fs::read_to_string(path).unwrap(), outdated);
+}
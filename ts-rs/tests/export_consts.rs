@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+ts_rs::export_consts! {
+    #![ts(export_to = "tests-out/export_consts/limits.ts")]
+
+    /// The largest page which can be requested
+    pub const MAX_PAGE_SIZE: u32 = 100;
+    const API_VERSION: &str = "v2";
+    static TIMEOUT_SECONDS: f64 = 2.5;
+    const MAX_FILE_SIZE: u64 = 1 << 40;
+    const BETA: bool = false;
+    const REGIONS: [&str; 2] = ["eu", "us"];
+    const FALLBACK: Option<char> = None;
+}
+
+#[test]
+fn export_consts() {
+    // the items are emitted unchanged
+    assert_eq!(MAX_PAGE_SIZE, 100);
+    assert_eq!(API_VERSION, "v2");
+
+    export_consts_limits_50e0280b();
+
+    let content = fs::read_to_string("tests-out/export_consts/limits.ts").unwrap();
+    let decls = content.split_once("\n\n").unwrap().1;
+    assert_eq!(
+        decls,
+        concat!(
+            "/**\n * The largest page which can be requested\n */\n",
+            "export const MAX_PAGE_SIZE = 100;\n\n",
+            "export const API_VERSION = \"v2\";\n\n",
+            "export const TIMEOUT_SECONDS = 2.5;\n\n",
+            "export const MAX_FILE_SIZE = 1099511627776n;\n\n",
+            "export const BETA = false;\n\n",
+            "export const REGIONS = [\"eu\", \"us\"];\n\n",
+            "export const FALLBACK = null;"
+        )
+    );
+}
+
+// exported to files of the same name from the same module
+ts_rs::export_consts! {
+    #![ts(export_to = "tests-out/export_consts/a/constants.ts")]
+    const RETRIES: u8 = 3;
+}
+
+ts_rs::export_consts! {
+    #![ts(export_to = "tests-out/export_consts/b/constants.ts")]
+    const DEADLINE: u8 = 30;
+}
+
+#[test]
+fn same_file_name() {
+    export_consts_constants_a58a48f8();
+    export_consts_constants_ac6dab13();
+
+    let a = fs::read_to_string("tests-out/export_consts/a/constants.ts").unwrap();
+    let b = fs::read_to_string("tests-out/export_consts/b/constants.ts").unwrap();
+    assert!(a.ends_with("export const RETRIES = 3;"));
+    assert!(b.ends_with("export const DEADLINE = 30;"));
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/cycles/A.ts")]
+struct A {
+    b: Option<Box<B>>,
+    c: C,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/cycles/B.ts")]
+struct B {
+    a: Vec<A>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/cycles/C.ts")]
+struct C {
+    value: i32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/cycles/D.ts")]
+struct D {
+    b: B,
+}
+
+#[test]
+fn merge_cycles() {
+    // this is the only test in this binary, so no other export can observe the variable
+    std::env::set_var("TS_RS_MERGE_CYCLES", "1");
+    let _ = fs::remove_dir_all("tests-out/cycles");
+
+    D::export().unwrap();
+
+    assert!(!std::path::Path::new("tests-out/cycles/B.ts").exists());
+
+    let a = fs::read_to_string("tests-out/cycles/A.ts").unwrap();
+    assert!(a.contains("import type { C } from \"./C\";"));
+    assert!(!a.contains("import type { B }"));
+    assert!(a.contains("export type A = "));
+    assert!(a.contains("export type B = "));
+
+    let d = fs::read_to_string("tests-out/cycles/D.ts").unwrap();
+    assert!(d.contains("import type { B } from \"./A\";"));
+}
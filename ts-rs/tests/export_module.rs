@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+
+use std::path::Path;
+
+mod models {
+    use ts_rs::TS;
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module/")]
+    pub struct User {
+        pub role: Role,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module/")]
+    pub enum Role {
+        Admin,
+        Member,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module/")]
+    pub struct Invoice {
+        pub total: u32,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module/")]
+    pub struct Unlisted;
+
+    pub mod admin {}
+
+    pub fn create_user() -> User {
+        User { role: Role::Member }
+    }
+
+    pub const MAX_USERS: usize = 100;
+}
+
+#[ts_rs::export_module]
+mod bindings {
+    pub use crate::models::{Invoice as BillingInvoice, User};
+    // items which are not types are skipped
+    #[allow(unused_imports)]
+    pub use crate::models::{admin, create_user, MAX_USERS};
+
+    #[test]
+    fn export_module() {
+        export_bindings_bindings();
+
+        let dir = super::Path::new("tests-out/export_module");
+        // dependencies of the listed types are exported as well
+        for file in ["User.ts", "Role.ts", "Invoice.ts"] {
+            assert!(dir.join(file).is_file(), "{} was not exported", file);
+        }
+        assert!(!dir.join("Unlisted.ts").exists());
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "export-all")]
+#![allow(dead_code)]
+
+use std::path::Path;
+
+mod models {
+    use ts_rs::TS;
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module_glob/")]
+    pub struct Account {
+        pub plan: Plan,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/export_module_glob/")]
+    pub enum Plan {
+        Free,
+        Pro,
+    }
+
+    pub mod internal {
+        use ts_rs::TS;
+
+        #[derive(TS)]
+        #[ts(export_to = "tests-out/export_module_glob/")]
+        pub struct Secret;
+    }
+}
+
+#[ts_rs::export_module]
+mod bindings {
+    // only used by the exported test, which finds the types by the path of this module
+    #[allow(unused_imports)]
+    pub use super::models::*;
+
+    #[test]
+    fn export_module_glob() {
+        export_bindings_bindings();
+
+        let dir = super::Path::new("tests-out/export_module_glob");
+        for file in ["Account.ts", "Plan.ts"] {
+            assert!(dir.join(file).is_file(), "{} was not exported", file);
+        }
+        // types in submodules are not imported by the glob
+        assert!(!dir.join("Secret.ts").exists());
+    }
+}
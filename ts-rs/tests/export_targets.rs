@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/targets/shared/")]
+struct Id {
+    value: i32,
+}
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/targets/api/",
+    export_to = "tests-out/targets/admin/"
+)]
+struct User {
+    id: Id,
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/targets/api/",
+    export_to(path = "tests-out/targets/admin/", rename = "AdminRole")
+)]
+enum Role {
+    Member,
+    Owner,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/targets/api/")]
+#[ts(export_to = "tests-out/targets/admin/")]
+#[ts(export_to(path = "tests-out/targets/audit/", rename = "AuditSession"))]
+struct Session {
+    user: User,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/targets/admin/")]
+struct Membership {
+    user: User,
+    role: Role,
+}
+
+#[test]
+fn export_targets() {
+    assert_eq!(User::EXPORT_TO, Some("tests-out/targets/api/User.ts"));
+    assert_eq!(
+        User::ALSO_EXPORT_TO,
+        &[("tests-out/targets/admin/User.ts", "User")]
+    );
+    assert_eq!(
+        Role::ALSO_EXPORT_TO,
+        &[("tests-out/targets/admin/AdminRole.ts", "AdminRole")]
+    );
+
+    Membership::export().unwrap();
+
+    let api_user = fs::read_to_string("tests-out/targets/api/User.ts").unwrap();
+    let admin_user = fs::read_to_string("tests-out/targets/admin/User.ts").unwrap();
+    assert!(api_user.contains(r#"import type { Id } from "../shared/Id";"#));
+    assert!(admin_user.contains(r#"import type { Id } from "../shared/Id";"#));
+    assert!(admin_user.contains("export type User = { id: Id, name: string, }"));
+
+    let admin_role = fs::read_to_string("tests-out/targets/admin/AdminRole.ts").unwrap();
+    assert!(admin_role.contains(r#"export type AdminRole = "Member" | "Owner";"#));
+
+    // dependents import from the first target
+    let membership = fs::read_to_string("tests-out/targets/admin/Membership.ts").unwrap();
+    assert!(membership.contains(r#"import type { Role } from "../api/Role";"#));
+    assert!(membership.contains(r#"import type { User } from "../api/User";"#));
+}
+
+#[test]
+fn export_targets_across_attributes() {
+    assert_eq!(Session::EXPORT_TO, Some("tests-out/targets/api/Session.ts"));
+    assert_eq!(
+        Session::ALSO_EXPORT_TO,
+        &[
+            ("tests-out/targets/admin/Session.ts", "Session"),
+            ("tests-out/targets/audit/AuditSession.ts", "AuditSession")
+        ]
+    );
+}
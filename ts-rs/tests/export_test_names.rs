@@ -0,0 +1,32 @@
+#![allow(dead_code, clippy::upper_case_acronyms)]
+
+use ts_rs::TS;
+
+// both names are lowercased to `userid`, and would be exported by the same test if it was named
+// after the exported name
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "tests-out/export_test_names/id/",
+    rename = "UserId"
+)]
+struct UserId(i32);
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "tests-out/export_test_names/upper/",
+    rename = "USERID"
+)]
+struct UserID(String);
+
+// a function of the user doesn't collide with the generated tests
+fn export_bindings_user_id() {}
+
+#[test]
+fn export_test_names() {
+    export_bindings_user_id_409cc53f();
+    export_bindings_user_id_209c92df();
+    assert!(std::path::Path::new("tests-out/export_test_names/id/UserId.ts").exists());
+    assert!(std::path::Path::new("tests-out/export_test_names/upper/USERID.ts").exists());
+}
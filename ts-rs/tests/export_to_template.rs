@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/export_to_template/models/{snake_name}.ts")]
+struct UserProfile {
+    user_name: String,
+}
+
+#[derive(TS)]
+#[ts(
+    rename = "Role",
+    export_to = "tests-out/export_to_template/{kebab_name}/",
+    export_to(
+        path = "tests-out/export_to_template/{name}.d.ts",
+        rename = "AdminRole"
+    )
+)]
+enum AccountRole {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/export_to_template/{camel_name}.ts")]
+struct UserSettings {
+    profile: UserProfile,
+    role: AccountRole,
+}
+
+#[test]
+fn export_to_template() {
+    assert_eq!(
+        UserProfile::get_export_to().as_deref(),
+        Some("tests-out/export_to_template/models/user_profile.ts")
+    );
+    // placeholders are replaced by the name of the type in TypeScript
+    assert_eq!(
+        AccountRole::get_export_to().as_deref(),
+        Some("tests-out/export_to_template/role/Role.ts")
+    );
+    assert_eq!(
+        AccountRole::ALSO_EXPORT_TO,
+        &[("tests-out/export_to_template/AdminRole.d.ts", "AdminRole")]
+    );
+
+    UserSettings::export().unwrap();
+    let exported = std::fs::read_to_string("tests-out/export_to_template/userSettings.ts").unwrap();
+    assert!(exported.contains(r#"import type { Role } from "./role/Role";"#));
+    assert!(exported.contains(r#"import type { UserProfile } from "./models/user_profile";"#));
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/export_with/")]
+struct Transformed {
+    id: u32,
+}
+
+#[test]
+fn export_with() {
+    Transformed::export_with(|content| {
+        content.insert_str(0, "// SPDX-License-Identifier: MIT\n");
+    })
+    .unwrap();
+
+    let content = fs::read_to_string("tests-out/export_with/Transformed.ts").unwrap();
+
+    assert!(content.starts_with("// SPDX-License-Identifier: MIT\n// This file was generated"));
+    assert!(content.contains("export type Transformed = { id: number"));
+}
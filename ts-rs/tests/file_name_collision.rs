@@ -0,0 +1,91 @@
+#![allow(dead_code)]
+
+use ts_rs::{sanitize_file_name, ExportError, TS};
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/", rename = "Price$")]
+struct Price {
+    cents: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/", rename = "Price_")]
+struct PriceTag {
+    label: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/")]
+struct Currency {
+    code: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/", rename = "CURRENCY")]
+struct CurrencyCode {
+    code: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/shared.ts")]
+struct Shared {
+    price: Price,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_name_collision/shared.ts")]
+struct AlsoShared {
+    currency: Currency,
+}
+
+#[test]
+fn sanitized() {
+    assert_eq!(sanitize_file_name("Price$"), "Price_");
+    assert_eq!(sanitize_file_name("user-profile"), "user-profile");
+    assert_eq!(
+        Price::get_export_to().as_deref(),
+        Some("tests-out/file_name_collision/Price_.ts")
+    );
+}
+
+fn assert_collision(result: Result<(), ExportError>, export_to: &str, first: &str, second: &str) {
+    match result {
+        Err(ExportError::FileNameCollision {
+            export_to: e,
+            first: f,
+            second: s,
+        }) => {
+            assert_eq!(e, format!("tests-out/file_name_collision/{export_to}"));
+            assert_eq!((f, s), (first, second));
+        }
+        _ => panic!("expected a file name collision"),
+    }
+}
+
+#[test]
+fn after_sanitizing() {
+    Price::export().unwrap();
+    assert_collision(
+        PriceTag::export(),
+        "Price_.ts",
+        "file_name_collision::Price",
+        "file_name_collision::PriceTag",
+    );
+}
+
+#[test]
+fn in_case() {
+    Currency::export().unwrap();
+    assert_collision(
+        CurrencyCode::export(),
+        "CURRENCY.ts",
+        "file_name_collision::Currency",
+        "file_name_collision::CurrencyCode",
+    );
+}
+
+#[test]
+fn shared_file() {
+    Shared::export().unwrap();
+    AlsoShared::export().unwrap();
+}
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/file_rename_all/",
+    file_rename_all = "kebab-case"
+)]
+struct UserProfile {
+    user_name: String,
+}
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/file_rename_all/",
+    export_to = "tests-out/file_rename_all/admin/",
+    file_rename_all = "snake_case"
+)]
+enum AccountKind {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/file_rename_all/user-settings-model.ts")]
+struct UserSettings {
+    profile: UserProfile,
+    kind: AccountKind,
+}
+
+#[test]
+fn file_rename_all() {
+    assert_eq!(
+        UserProfile::get_export_to().as_deref(),
+        Some("tests-out/file_rename_all/user-profile.ts")
+    );
+    assert_eq!(
+        AccountKind::get_export_to().as_deref(),
+        Some("tests-out/file_rename_all/account_kind.ts")
+    );
+    assert_eq!(
+        AccountKind::ALSO_EXPORT_TO,
+        &[(
+            "tests-out/file_rename_all/admin/account_kind.ts",
+            "AccountKind"
+        )]
+    );
+}
+
+#[test]
+fn file_path() {
+    assert_eq!(
+        UserSettings::get_export_to().as_deref(),
+        Some("tests-out/file_rename_all/user-settings-model.ts")
+    );
+    let exported = UserSettings::export_to_string().unwrap();
+    assert!(exported.contains("import type { UserProfile } from \"./user-profile\";\n"));
+    assert!(exported.contains("import type { AccountKind } from \"./account_kind\";\n"));
+    assert!(exported.contains("export type UserSettings = "));
+}
@@ -29,3 +29,115 @@ fn test_def() {
         "{ b: { c: number, a: number, b: number, }, d: number, }"
     );
 }
+
+#[derive(TS)]
+struct D {
+    d: i32,
+}
+
+#[derive(TS)]
+struct Wrapped {
+    #[ts(flatten)]
+    boxed: Box<A>,
+    #[ts(flatten)]
+    arc: std::sync::Arc<D>,
+}
+
+#[derive(TS)]
+struct OptionalFlatten {
+    #[ts(flatten)]
+    a: Option<A>,
+    c: i32,
+}
+
+#[derive(TS)]
+struct OnlyOptionalFlatten {
+    #[ts(flatten)]
+    a: Option<Box<A>>,
+}
+
+#[test]
+fn flatten_wrapped() {
+    assert_eq!(Wrapped::inline(), "{ a: number, b: number, d: number, }");
+}
+
+#[test]
+fn flatten_option() {
+    assert_eq!(
+        OptionalFlatten::inline(),
+        "{ c: number, } & Partial<{ a: number, b: number, }>"
+    );
+    assert_eq!(
+        OnlyOptionalFlatten::inline(),
+        "Partial<{ a: number, b: number, }>"
+    );
+}
+
+#[derive(TS)]
+struct FlattenMap {
+    id: i32,
+    #[ts(flatten)]
+    extra: std::collections::HashMap<String, i32>,
+}
+
+#[test]
+fn flatten_map() {
+    assert_eq!(
+        FlattenMap::inline(),
+        "{ id: number, } & Record<string, number>"
+    );
+}
+
+#[test]
+fn flattenable() {
+    let flattenable = [
+        A::FLATTENABLE,
+        <Option<Box<A>>>::FLATTENABLE,
+        <std::collections::BTreeMap<String, i32>>::FLATTENABLE,
+        <Vec<A>>::FLATTENABLE,
+        i32::FLATTENABLE,
+    ];
+    assert_eq!(flattenable, [true, true, true, false, false]);
+}
+
+#[derive(TS)]
+struct Audit {
+    id: i32,
+    created_at: String,
+}
+
+#[derive(TS)]
+struct Entity {
+    id: i32,
+}
+
+#[derive(TS)]
+struct ConflictingFlatten {
+    #[ts(flatten)]
+    entity: Entity,
+    #[ts(flatten)]
+    audit: Option<Audit>,
+}
+
+#[derive(TS)]
+struct ConflictingField {
+    #[ts(rename = "created_at")]
+    created: i64,
+    #[ts(flatten)]
+    audit: Audit,
+}
+
+#[test]
+fn flatten_conflict() {
+    let conflict = |result: Result<(), ts_rs::ExportError>| result.unwrap_err().to_string();
+    assert_eq!(
+        conflict(ConflictingFlatten::check_flatten()),
+        "`id` of `ConflictingFlatten` is defined by both `Entity` and `Audit`"
+    );
+    assert_eq!(
+        conflict(ConflictingField::check_flatten()),
+        "`created_at` of `ConflictingField` is defined by both `ConflictingField` and `Audit`"
+    );
+    assert!(conflict(ConflictingField::export()).contains("`created_at` of `ConflictingField`"));
+    assert!(B::check_flatten().is_ok());
+}
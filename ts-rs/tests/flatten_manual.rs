@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+// stands in for a type of another crate, which only implements `inline`
+struct Audit;
+
+impl TS for Audit {
+    const FLATTENABLE: bool = true;
+
+    fn name() -> String {
+        "Audit".to_owned()
+    }
+
+    fn inline() -> String {
+        "{ created_by: string, updated_by: string | null, }".to_owned()
+    }
+
+    fn transparent() -> bool {
+        false
+    }
+}
+
+// can be flattened, but isn't an object type
+struct Code;
+
+impl TS for Code {
+    const FLATTENABLE: bool = true;
+
+    fn name() -> String {
+        "Code".to_owned()
+    }
+
+    fn inline() -> String {
+        "string".to_owned()
+    }
+
+    fn transparent() -> bool {
+        false
+    }
+}
+
+#[derive(TS)]
+struct Document {
+    id: i32,
+    #[ts(flatten)]
+    audit: Audit,
+}
+
+#[derive(TS)]
+struct Draft {
+    #[ts(flatten)]
+    audit: Option<Audit>,
+}
+
+#[derive(TS)]
+struct Coded {
+    #[ts(flatten)]
+    code: Code,
+}
+
+#[test]
+fn manual_impl() {
+    assert_eq!(
+        Document::inline(),
+        "{ id: number, created_by: string, updated_by: string | null, }"
+    );
+    assert_eq!(
+        Draft::inline(),
+        "Partial<{ created_by: string, updated_by: string | null, }>"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Code cannot be flattened, since `string` is not an object type")]
+fn not_an_object() {
+    Coded::inline();
+}
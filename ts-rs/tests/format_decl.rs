@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+#![cfg(feature = "format")]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[test]
+fn format_decl() {
+    assert_eq!(
+        ts_rs::format_decl(&User::decl()).unwrap(),
+        "type User = { id: number; name: string };\n"
+    );
+}
+
+#[test]
+fn format_export() {
+    let exported = User::export_to_string().unwrap();
+    let formatted = ts_rs::format_decl(&exported).unwrap();
+    assert!(formatted.ends_with("export type User = { id: number; name: string };\n"));
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/from_string/",
+    from_string,
+    rename_all = "lowercase"
+)]
+enum Level {
+    Low,
+    #[ts(alias = "med", alias = "mid")]
+    Medium,
+    #[ts(rename = "HIGH", alias = "high")]
+    High,
+    #[ts(skip)]
+    Internal,
+}
+
+#[derive(TS)]
+#[ts(enum, from_string = "parseColor")]
+enum Color {
+    Red,
+    #[ts(alias = "Red")]
+    Green,
+}
+
+#[test]
+fn union() {
+    assert_eq!(
+        Level::extra_decls(),
+        vec![
+            r#"const LevelFromString: Record<string, Level> = { "low": "low", "medium": "medium", "med": "medium", "mid": "medium", "HIGH": "HIGH", "high": "HIGH" };"#
+        ]
+    );
+}
+
+#[test]
+fn ts_enum() {
+    assert_eq!(
+        Color::extra_decls(),
+        vec![
+            r#"const parseColor: Record<string, Color> = { "Red": Color.Red, "Green": Color.Green };"#
+        ]
+    );
+}
+
+#[test]
+fn exported() {
+    let exported = Level::export_to_string().unwrap();
+    assert!(exported.contains("export const LevelFromString: Record<string, Level>"));
+}
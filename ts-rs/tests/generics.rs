@@ -256,7 +256,30 @@ fn default() {
         // #[ts(inline)]
         // xi2: X<i32>
     }
-    assert_eq!(Y::decl(), "type Y = { a1: A, a2: A<number>, }")
+    assert_eq!(Y::decl(), "type Y = { a1: A, a2: A<number>, }");
+
+    #[derive(TS)]
+    struct Page<T = ()> {
+        items: Vec<T>,
+    }
+    assert_eq!(
+        Page::<()>::decl(),
+        "type Page<T = null> = { items: Array<T>, }"
+    );
+
+    #[derive(TS)]
+    struct Z<T = (i32, Option<String>), U = (Page,), V = T> {
+        t: T,
+        u: U,
+        v: V,
+    }
+    assert_eq!(
+        Z::<(), (), ()>::decl(),
+        "type Z<T = [number, string | null], U = [Page], V = T> = { t: T, u: U, v: V, }"
+    );
+    assert!(Z::<(), (), ()>::dependencies()
+        .iter()
+        .any(|dep| dep.ts_name == "Page"));
 }
 
 #[test]
@@ -349,3 +372,69 @@ fn deeply_nested() {
          }"
     );
 }
+
+#[test]
+fn skip_generic() {
+    use std::collections::HashMap;
+
+    // skipped generic arguments are omitted, but still need to implement `TS` at the use site
+    #[derive(TS)]
+    struct Marker;
+
+    #[derive(TS)]
+    #[ts(skip_generic = "S")]
+    struct Map<K, V, S = std::collections::hash_map::RandomState> {
+        map: HashMap<K, V, S>,
+    }
+
+    #[derive(TS)]
+    struct Tagged<T, #[ts(skip)] M> {
+        value: T,
+        #[ts(skip)]
+        marker: std::marker::PhantomData<M>,
+    }
+
+    #[derive(TS)]
+    struct Container {
+        map: Map<String, i32>,
+        tagged: Tagged<i32, Marker>,
+    }
+
+    assert_eq!(
+        Map::<(), (), ()>::decl(),
+        "type Map<K, V> = { map: Record<K, V>, }"
+    );
+    assert_eq!(
+        Tagged::<(), Marker>::decl(),
+        "type Tagged<T> = { value: T, }"
+    );
+    assert_eq!(
+        Container::decl(),
+        "type Container = { map: Map<string, number>, tagged: Tagged<number>, }"
+    );
+}
+
+#[test]
+fn generic_bound() {
+    #[derive(TS)]
+    #[ts(generic_bound(T = "string | number", U = "{ id: number }"))]
+    struct Bounded<T, U = ()> {
+        t: T,
+        u: U,
+    }
+
+    #[derive(TS)]
+    #[ts(generic_bound(K = "string"))]
+    enum Keyed<K> {
+        A(K),
+    }
+
+    assert_eq!(
+        Bounded::<(), ()>::decl(),
+        "type Bounded<T extends string | number, U extends { id: number } = null> = { t: T, u: U, }"
+    );
+    assert_eq!(
+        Keyed::<()>::decl(),
+        r#"type Keyed<K extends string> = { "A": K };"#
+    );
+}
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+#![cfg(not(feature = "import-esm"))]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/import_order/")]
+struct Beta;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/import_order/")]
+struct Alpha;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/import_order/../import_order/Gamma.ts")]
+struct Gamma;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/import_order/")]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/import_order/")]
+struct Uses {
+    gamma: Gamma,
+    beta: Wrapper<Beta>,
+    alpha: Wrapper<Alpha>,
+    also_beta: Beta,
+}
+
+#[test]
+fn imports_are_sorted_and_deduplicated() {
+    let exported = Uses::export_to_string().unwrap();
+    let imports = exported
+        .lines()
+        .filter(|line| line.starts_with("import"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        [
+            r#"import type { Alpha } from "./Alpha";"#,
+            r#"import type { Beta } from "./Beta";"#,
+            r#"import type { Gamma } from "./Gamma";"#,
+            r#"import type { Wrapper } from "./Wrapper";"#,
+        ]
+    );
+}
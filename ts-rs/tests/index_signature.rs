@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/index_signature/")]
+struct Extra {
+    note: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/index_signature/")]
+#[ts(index_signature = "string => unknown")]
+struct Options {
+    verbose: bool,
+    #[ts(optional)]
+    level: Option<u8>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/index_signature/")]
+#[ts(index_signature = "string => Extra | null", uses(Extra))]
+struct Extensible {}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/index_signature/")]
+struct Outer {
+    id: u32,
+    #[ts(flatten)]
+    options: Options,
+}
+
+#[test]
+fn index_signature() {
+    assert_eq!(
+        Options::decl(),
+        "type Options = { verbose: boolean, level?: number, [key: string]: unknown, }"
+    );
+    assert!(Options::dependencies().is_empty());
+}
+
+#[test]
+fn without_fields() {
+    assert_eq!(
+        Extensible::decl(),
+        "type Extensible = { [key: string]: Extra | null, }"
+    );
+    let deps = Extensible::dependencies();
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].ts_name, "Extra");
+}
+
+#[test]
+fn flattened() {
+    assert_eq!(
+        Outer::decl(),
+        "type Outer = { id: number, verbose: boolean, level?: number, [key: string]: unknown, }"
+    );
+}
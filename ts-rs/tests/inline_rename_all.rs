@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct Address {
+    street_name: String,
+    #[ts(rename = "Zip-Code")]
+    zip_code: String,
+}
+
+#[derive(TS)]
+struct Audit {
+    created_at: String,
+}
+
+#[derive(TS)]
+struct Profile {
+    display_name: String,
+    #[ts(flatten)]
+    audit: Audit,
+}
+
+#[derive(TS)]
+#[ts(rename_all = "camelCase")]
+struct User {
+    user_id: i32,
+    #[ts(inline, rename_all = "camelCase")]
+    home_address: Address,
+    #[ts(inline, rename_all = "SCREAMING_SNAKE_CASE")]
+    profile: Profile,
+    #[ts(inline)]
+    work_address: Address,
+}
+
+#[test]
+fn inline_rename_all() {
+    assert_eq!(
+        User::inline(),
+        "{ userId: number, \
+         homeAddress: { streetName: string, \"zip-Code\": string, }, \
+         profile: { DISPLAY_NAME: string, CREATED_AT: string, }, \
+         workAddress: { street_name: string, \"Zip-Code\": string, }, }"
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "json-schema")]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/json_schema/")]
+struct User {
+    id: u32,
+    name: String,
+    #[ts(optional)]
+    email: Option<String>,
+    tags: Vec<String>,
+    role: Role,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/json_schema/")]
+enum Role {
+    Admin,
+    Member { since: i64 },
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/json_schema/", tag = "type")]
+enum Event {
+    Login(User),
+    Logout { user: User },
+    Ping,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/json_schema/")]
+struct Page<T> {
+    items: Vec<T>,
+    meta: HashMap<String, (u8, bool)>,
+    #[ts(flatten)]
+    cursor: Cursor,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/json_schema/")]
+struct Cursor {
+    next: Option<String>,
+}
+
+#[test]
+fn struct_schema() {
+    assert_eq!(
+        User::json_schema(),
+        concat!(
+            r#"{"type":"object","properties":{"#,
+            r#""id":{"type":"number"},"#,
+            r#""name":{"type":"string"},"#,
+            r#""email":{"type":"string"},"#,
+            r#""tags":{"type":"array","items":{"type":"string"}},"#,
+            r#""role":{"$ref":"/tests-out/json_schema/Role.schema.json"}"#,
+            r#"},"required":["id","name","tags","role"]}"#
+        )
+    );
+}
+
+#[test]
+fn generic_schema() {
+    assert_eq!(
+        Page::<User>::json_schema(),
+        concat!(
+            r#"{"allOf":[{"type":"object","properties":{"#,
+            r#""items":{"type":"array","items":{}},"#,
+            r#""meta":{"type":"object","additionalProperties":{"type":"array","prefixItems":[{"type":"number"},{"type":"boolean"}],"minItems":2,"maxItems":2}}"#,
+            r#"},"required":["items","meta"]},"#,
+            r#"{"$ref":"/tests-out/json_schema/Cursor.schema.json"}]}"#
+        )
+    );
+}
+
+#[test]
+fn enum_schema() {
+    assert_eq!(
+        Role::json_schema(),
+        concat!(
+            r#"{"anyOf":[{"const":"Admin"},"#,
+            r#"{"type":"object","properties":{"Member":{"type":"object","properties":{"since":{"type":"integer"}},"required":["since"]}},"required":["Member"]}"#,
+            r#"]}"#
+        )
+    );
+    assert_eq!(
+        Event::json_schema(),
+        concat!(
+            r#"{"anyOf":["#,
+            r#"{"allOf":[{"type":"object","properties":{"type":{"const":"Login"}},"required":["type"]},{"$ref":"/tests-out/json_schema/User.schema.json"}]},"#,
+            r#"{"allOf":[{"type":"object","properties":{"type":{"const":"Logout"}},"required":["type"]},{"type":"object","properties":{"user":{"$ref":"/tests-out/json_schema/User.schema.json"}},"required":["user"]}]},"#,
+            r#"{"type":"object","properties":{"type":{"const":"Ping"}},"required":["type"]}"#,
+            r#"]}"#
+        )
+    );
+}
+
+#[test]
+fn export_schema() {
+    Event::export().unwrap();
+
+    let schema = std::fs::read_to_string("tests-out/json_schema/User.schema.json").unwrap();
+    assert!(schema.starts_with(concat!(
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","#,
+        r#""$id":"/tests-out/json_schema/User.schema.json","type":"object","#
+    )));
+    assert!(std::fs::metadata("tests-out/json_schema/Role.schema.json").is_ok());
+    assert!(std::fs::metadata("tests-out/json_schema/Event.schema.json").is_ok());
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/large_integers/")]
+struct Account {
+    #[ts(large_integers = "number")]
+    id: u64,
+    #[ts(large_integers = "string")]
+    balances: HashMap<String, i128>,
+    #[ts(large_integers = "number", pointer_sized_integers = "string")]
+    history: Vec<(u64, usize)>,
+    created_at: i64,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/large_integers/")]
+struct Balance(#[ts(large_integers = "string")] u128);
+
+#[test]
+fn large_integers() {
+    assert_eq!(
+        Account::decl(),
+        "type Account = { id: number, balances: Record<string, string>, history: Array<[number, string]>, created_at: bigint, }"
+    );
+    assert_eq!(Balance::decl(), "type Balance = string;");
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn large_integers_schema() {
+    assert_eq!(Balance::json_schema(), r#"{"type":"string"}"#);
+}
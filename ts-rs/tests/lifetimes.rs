@@ -32,3 +32,41 @@ fn contains_borrow_type_args() {
         "type A = { a: Array<number>, b: Array<B<number>>, c: Record<string, boolean>, }"
     );
 }
+
+#[cfg(feature = "serde-compat")]
+#[test]
+fn serde_borrow() {
+    use std::borrow::Cow;
+
+    use serde::Deserialize;
+
+    #[derive(Deserialize, TS)]
+    #[allow(dead_code)]
+    struct Message<'a, 'b> {
+        #[serde(borrow)]
+        author: Cow<'a, str>,
+        text: &'a str,
+        payload: &'a [u8],
+        #[serde(borrow = "'b")]
+        tags: Vec<Cow<'b, str>>,
+    }
+
+    #[derive(Deserialize, TS)]
+    #[allow(dead_code)]
+    enum Token<'a> {
+        #[serde(borrow)]
+        Word(Cow<'a, str>),
+        Bytes(&'a [u8]),
+    }
+
+    assert_eq!(
+        Message::inline(),
+        "{ author: string, text: string, payload: Array<number>, tags: Array<string>, }"
+    );
+    assert!(Message::warnings().is_empty());
+    assert_eq!(
+        Token::inline(),
+        r#"{ "Word": string } | { "Bytes": Array<number> }"#
+    );
+    assert!(Token::warnings().is_empty());
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/literal/")]
+struct User {
+    #[ts(literal = "user")]
+    kind: String,
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/literal/")]
+struct Bot {
+    #[ts(literal = "bot")]
+    kind: &'static str,
+    #[ts(literal = 2)]
+    version: u8,
+    #[ts(literal = true, readonly)]
+    automated: bool,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/literal/")]
+#[ts(untagged)]
+enum Account {
+    User(User),
+    Bot(Bot),
+}
+
+#[test]
+fn literal() {
+    assert_eq!(
+        User::decl(),
+        "type User = { kind: \"user\", name: string, }"
+    );
+    assert_eq!(
+        Bot::decl(),
+        "type Bot = { kind: \"bot\", version: 2, readonly automated: true, }"
+    );
+    assert_eq!(Account::decl(), "type Account = User | Bot;");
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn literal_schema() {
+    assert_eq!(
+        Bot::json_schema(),
+        r#"{"type":"object","properties":{"kind":{"const":"bot"},"version":{"const":2},"automated":{"const":true}},"required":["kind","version","automated"]}"#
+    );
+}
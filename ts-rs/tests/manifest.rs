@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/manifest/")]
+struct ManifestRole {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/manifest/")]
+struct ManifestUser {
+    id: u32,
+    role: ManifestRole,
+}
+
+// whether the manifest is written is only read once, so this is the only test exporting a type in
+// this file
+#[test]
+fn manifest() {
+    std::env::set_var("TS_RS_MANIFEST", "1");
+
+    ManifestUser::export().unwrap();
+
+    let manifest = fs::read_to_string("bindings/ts-rs-manifest.json").unwrap();
+    let entry = |name: &str| {
+        let prefix =
+            format!(r#"  {{"file":"tests-out/manifest/{name}.ts","type":"{name}","dependencies":"#);
+        manifest
+            .lines()
+            .find(|line| line.starts_with(&prefix))
+            .unwrap_or_else(|| panic!("{name} is missing from the manifest"))
+            .to_owned()
+    };
+
+    let user = entry("ManifestUser");
+    assert!(user.contains(
+        r#""dependencies":[{"type":"ManifestRole","file":"tests-out/manifest/ManifestRole.ts"}]"#
+    ));
+    assert!(entry("ManifestRole").contains(r#""dependencies":[]"#));
+    assert!(user.contains(r#""kind":"struct""#));
+
+    // the hash changes with the contents of the file
+    let hash = |entry: &str| {
+        let (_, hash) = entry.split_once(r#""hash":""#).unwrap();
+        hash[..16].to_owned()
+    };
+    assert!(hash(&user).chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(hash(&user), hash(&entry("ManifestRole")));
+}
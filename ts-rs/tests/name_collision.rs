@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+
+use ts_rs::{ExportError, TS};
+
+mod a {
+    use ts_rs::TS;
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/name_collision/")]
+    pub struct User {
+        pub id: u32,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/name_collision/")]
+    pub struct Item {
+        pub id: u32,
+    }
+}
+
+mod b {
+    use ts_rs::TS;
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/name_collision/")]
+    pub struct User {
+        pub name: String,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/name_collision/")]
+    pub struct Item {
+        pub name: String,
+    }
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/name_collision/")]
+struct Users {
+    a: a::User,
+    b: b::User,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/name_collision/")]
+struct Page<T: TS> {
+    items: Vec<T>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/name_collision/")]
+struct Pages {
+    numbers: Page<u32>,
+    strings: Page<String>,
+}
+
+fn assert_collision(result: Result<(), ExportError>, first: &str, second: &str) {
+    match result {
+        Err(ExportError::NameCollision {
+            ts_name,
+            export_to,
+            first: f,
+            second: s,
+        }) => {
+            assert_eq!(ts_name, first.rsplit("::").next().unwrap());
+            assert_eq!(export_to, format!("tests-out/name_collision/{ts_name}.ts"));
+            assert_eq!((f, s), (first, second));
+        }
+        _ => panic!("expected a name collision"),
+    }
+}
+
+#[test]
+fn within_one_export() {
+    assert_collision(
+        Users::export(),
+        "name_collision::a::User",
+        "name_collision::b::User",
+    );
+}
+
+#[test]
+fn across_exports() {
+    a::Item::export().unwrap();
+    a::Item::export().unwrap();
+    assert_collision(
+        b::Item::export(),
+        "name_collision::a::Item",
+        "name_collision::b::Item",
+    );
+}
+
+#[test]
+fn generic_instantiations() {
+    Pages::export().unwrap();
+}
@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+
+use std::{fs, path::Path};
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/npm_package/models/")]
+struct Role {
+    name: String,
+}
+
+#[derive(TS)]
+struct User {
+    id: u32,
+    role: Role,
+}
+
+// the export directory is only read once, so this is the only test exporting a type in this file
+#[test]
+fn export_npm_package() {
+    std::env::set_var("TS_RS_EXPORT_DIR", "tests-out/npm_package");
+
+    let dir = Path::new("tests-out/npm_package");
+    let _ = fs::remove_dir_all(dir);
+
+    User::export().unwrap();
+    ts_rs::export_npm_package(dir.join("pkg"), "@acme/types", "1.2.3").unwrap();
+
+    let pkg = dir.join("pkg");
+    assert_eq!(
+        fs::read_to_string(pkg.join("package.json")).unwrap(),
+        concat!(
+            "{\n",
+            "  \"name\": \"@acme/types\",\n",
+            "  \"version\": \"1.2.3\",\n",
+            "  \"types\": \"src/index.ts\",\n",
+            "  \"files\": [\"src\"]\n",
+            "}\n"
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(pkg.join("src/User.ts")).unwrap(),
+        fs::read_to_string(dir.join("User.ts")).unwrap()
+    );
+    assert!(pkg.join("src/models/Role.ts").is_file());
+
+    let index = fs::read_to_string(pkg.join("src/index.ts")).unwrap();
+    let exports = index.split_once("\n").unwrap().1;
+    assert_eq!(
+        exports,
+        "export * from \"./User\";\nexport * from \"./models/Role\";\n"
+    );
+
+    // exporting the package again doesn't pick up the copied bindings
+    ts_rs::export_npm_package(dir.join("pkg"), "@acme/types", "1.2.4").unwrap();
+    assert!(!pkg.join("src/pkg").exists());
+}
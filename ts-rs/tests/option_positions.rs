@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::TS;
+
+type MaybePair = (Option<i32>, String);
+type MaybeArray = [Option<i32>; 2];
+type MaybeList = Vec<Option<i32>>;
+type MaybeMap = HashMap<String, Option<i32>>;
+type Fallible = Result<i32, Option<String>>;
+
+#[derive(TS)]
+struct Generic<T> {
+    t: T,
+}
+
+#[derive(TS)]
+struct Positions {
+    tuple: (Option<i32>, u8),
+    array: [Option<u8>; 2],
+    list: Vec<(Option<i32>, u8)>,
+    generic_tuple: Generic<(Option<i32>, u8)>,
+    generic_array: Generic<[Option<u8>; 2]>,
+}
+
+#[derive(TS)]
+struct Aliases {
+    pair: MaybePair,
+    array: MaybeArray,
+    list: MaybeList,
+    map: MaybeMap,
+    result: Fallible,
+    generic: Generic<MaybePair>,
+}
+
+#[test]
+fn tuples_and_arrays() {
+    assert_eq!(
+        Positions::inline(),
+        "{ tuple: [number | null, number], array: [number | null, number | null], \
+         list: Array<[number | null, number]>, generic_tuple: Generic<[number | null, number]>, \
+         generic_array: Generic<[number | null, number | null]>, }"
+    );
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(
+        Aliases::inline(),
+        "{ pair: [number | null, string], array: [number | null, number | null], \
+         list: Array<number | null>, map: Record<string, number | null>, \
+         result: { Ok : number } | { Err : string | null }, \
+         generic: Generic<[number | null, string]>, }"
+    );
+}
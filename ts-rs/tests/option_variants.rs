@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/option_variants/")]
+struct Payload {
+    id: i32,
+}
+
+#[derive(TS)]
+#[ts(
+    export_to = "tests-out/option_variants/",
+    tag = "type",
+    content = "data"
+)]
+enum Adjacent {
+    Required(Option<Payload>),
+    Optional(#[ts(optional)] Option<Payload>),
+    Nullable(#[ts(optional = nullable)] Option<Payload>),
+    Inline(#[ts(optional, inline)] Option<Payload>),
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/option_variants/", tag = "type")]
+enum Internal {
+    Payload(Option<Payload>),
+    Empty,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/option_variants/")]
+enum External {
+    Payload(Option<Payload>),
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/option_variants/", untagged)]
+enum Untagged {
+    Payload(Option<Payload>),
+}
+
+#[test]
+fn adjacently_tagged() {
+    assert_eq!(
+        Adjacent::inline(),
+        "{ \"type\": \"Required\", \"data\": Payload | null } \
+         | { \"type\": \"Optional\", \"data\"?: Payload } \
+         | { \"type\": \"Nullable\", \"data\"?: Payload | null } \
+         | { \"type\": \"Inline\", \"data\"?: { id: number, } }"
+    );
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn adjacently_tagged_schema() {
+    let schema = Adjacent::json_schema();
+    assert!(schema.contains(
+        r#""data":{"$ref":"/tests-out/option_variants/Payload.schema.json"}},"required":["type"]}"#
+    ));
+    assert!(schema.contains(r#""required":["type","data"]"#));
+}
+
+#[test]
+fn internally_tagged() {
+    // serde fails to serialize `Internal::Payload(None)`
+    assert_eq!(
+        Internal::inline(),
+        "{ \"type\": \"Payload\" } & Payload | { \"type\": \"Empty\" }"
+    );
+}
+
+#[test]
+fn other_representations() {
+    assert_eq!(External::inline(), "{ \"Payload\": Payload | null }");
+    assert_eq!(Untagged::inline(), "Payload | null");
+}
@@ -20,6 +20,25 @@ fn in_struct() {
     assert_eq!(Optional::inline(), format!("{{ {a}, {b}, {c}, }}"));
 }
 
+#[test]
+fn explicit() {
+    #[derive(Serialize, TS)]
+    struct Optional {
+        #[ts(optional = true)]
+        a: Option<i32>,
+        #[ts(optional = false)]
+        b: Option<i32>,
+        #[ts(optional)]
+        #[ts(optional = false)]
+        c: Option<i32>,
+    }
+
+    let a = "a?: number";
+    let b = "b: number | null";
+    let c = "c: number | null";
+    assert_eq!(Optional::inline(), format!("{{ {a}, {b}, {c}, }}"));
+}
+
 #[test]
 fn in_enum() {
     #[derive(Serialize, TS)]
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use std::{fs, thread};
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/parallel_export/")]
+struct Shared {
+    id: u64,
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/parallel_export/")]
+struct First {
+    shared: Shared,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/parallel_export/")]
+struct Second {
+    shared: Vec<Shared>,
+}
+
+#[test]
+fn parallel_export() {
+    // every thread writes `Shared.ts`, as a dependency of the type it exports
+    let threads = (0..16)
+        .map(|i| {
+            thread::spawn(move || match i % 2 {
+                0 => First::export(),
+                _ => Second::export(),
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap().unwrap();
+    }
+
+    let shared = fs::read_to_string("tests-out/parallel_export/Shared.ts").unwrap();
+    assert_eq!(shared.matches("export type Shared").count(), 1);
+
+    // no temporary file is left behind
+    for entry in fs::read_dir("tests-out/parallel_export").unwrap() {
+        let name = entry.unwrap().file_name();
+        assert!(!name.to_string_lossy().ends_with(".tmp"), "{:?}", name);
+    }
+}
@@ -0,0 +1,102 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct Marker;
+
+#[derive(TS)]
+struct Empty {}
+
+#[derive(TS)]
+struct Point {
+    x: i32,
+}
+
+#[derive(TS)]
+#[ts(tag = "type")]
+enum Shape {
+    Unit,
+    Braced {},
+    Skipped {
+        #[ts(skip)]
+        x: i32,
+    },
+    Marker(Marker),
+    Empty(Empty),
+    Tuple(()),
+    Point(Point),
+}
+
+#[derive(TS)]
+#[ts(tag = "type", content = "data")]
+enum Adjacent {
+    Unit,
+    Marker(Marker),
+}
+
+#[derive(TS)]
+#[ts(tag = "type")]
+enum Generic<T> {
+    Value(T),
+}
+
+#[derive(TS)]
+#[ts(untagged, unit_variant = "string")]
+enum Lookup {
+    Unknown,
+    Id(i32),
+    Point { x: i32 },
+}
+
+#[derive(TS)]
+#[ts(untagged)]
+enum Nullable {
+    Unknown,
+    Id(i32),
+}
+
+#[test]
+fn internally_tagged() {
+    assert_eq!(
+        Shape::decl(),
+        concat!(
+            r#"type Shape = { "type": "Unit" } | { "type": "Braced" } | { "type": "Skipped" } | "#,
+            r#"{ "type": "Marker" } | { "type": "Empty" } | { "type": "Tuple" } | "#,
+            r#"{ "type": "Point" } & Point;"#
+        )
+    );
+    assert_eq!(
+        Generic::<()>::decl(),
+        r#"type Generic<T> = { "type": "Value" } & T;"#
+    );
+}
+
+#[test]
+fn adjacently_tagged() {
+    assert_eq!(
+        Adjacent::decl(),
+        r#"type Adjacent = { "type": "Unit" } | { "type": "Marker", "data": Marker };"#
+    );
+}
+
+#[test]
+fn untagged_unit_variant() {
+    assert_eq!(
+        Lookup::decl(),
+        r#"type Lookup = "Unknown" | number | { x: number, };"#
+    );
+    assert_eq!(Nullable::decl(), "type Nullable = null | number;");
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn internally_tagged_schema() {
+    let schema = Shape::json_schema();
+    assert!(schema.contains(
+        r#"{"type":"object","properties":{"type":{"const":"Marker"}},"required":["type"]},"#
+    ));
+    assert!(
+        schema.contains(r#"{"allOf":[{"type":"object","properties":{"type":{"const":"Point"}}"#)
+    );
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use ts_rs::TS;
+
+// doesn't implement `TS`
+struct User;
+
+#[derive(TS)]
+struct Admin;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+struct Id<M> {
+    id: i32,
+    marker: PhantomData<M>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+struct Key<K, V>(String, #[ts(skip)] PhantomData<(K, V)>);
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+struct Page<T, M> {
+    items: Vec<T>,
+    owner: Id<M>,
+    marker: PhantomData<M>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+enum Command<T, M> {
+    Run { arg: T },
+    Stop(PhantomData<M>),
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+struct Wrapper<T> {
+    #[ts(type = "Array<{T}>")]
+    values: PhantomData<T>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/phantom_generics/")]
+struct Account {
+    id: Id<Admin>,
+    key: Key<Admin, String>,
+}
+
+#[test]
+fn phantom_only() {
+    assert_eq!(
+        Id::<User>::decl(),
+        "type Id = { id: number, marker: null, }"
+    );
+    assert_eq!(Key::<User, User>::decl(), "type Key = [string];");
+    assert_eq!(Account::decl(), "type Account = { id: Id, key: Key, }");
+}
+
+#[test]
+fn used_elsewhere() {
+    assert_eq!(
+        Page::<(), ()>::decl(),
+        "type Page<T, M> = { items: Array<T>, owner: Id, marker: null, }"
+    );
+    assert_eq!(
+        Wrapper::<()>::decl(),
+        "type Wrapper<T> = { values: Array<T>, }"
+    );
+}
+
+#[test]
+fn enum_variants() {
+    assert_eq!(
+        Command::<(), User>::decl(),
+        r#"type Command<T> = { "Run": { arg: T, } } | { "Stop": null };"#
+    );
+}
+
+#[test]
+fn schema() {
+    assert_eq!(PhantomData::<User>::json_schema(), r#"{"type":"null"}"#);
+}
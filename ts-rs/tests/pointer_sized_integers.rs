@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/pointer_sized_integers/")]
+struct Row {
+    #[ts(pointer_sized_integers = "bigint")]
+    id: usize,
+    #[ts(pointer_sized_integers = "string")]
+    parents: Vec<Option<isize>>,
+    index: usize,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/pointer_sized_integers/")]
+struct Offset(#[ts(pointer_sized_integers = "bigint")] isize);
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/pointer_sized_integers/")]
+#[ts(tag = "kind", content = "index")]
+enum Cursor {
+    Start,
+    At(#[ts(pointer_sized_integers = "string")] usize),
+}
+
+#[test]
+fn pointer_sized_integers() {
+    assert_eq!(
+        Row::decl(),
+        "type Row = { id: bigint, parents: Array<string | null>, index: number, }"
+    );
+    assert_eq!(Offset::decl(), "type Offset = bigint;");
+}
+
+#[test]
+fn enum_variant() {
+    assert_eq!(
+        Cursor::decl(),
+        r#"type Cursor = { "kind": "Start" } | { "kind": "At", "index": string };"#
+    );
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn pointer_sized_integers_schema() {
+    assert_eq!(
+        Row::json_schema(),
+        r#"{"type":"object","properties":{"id":{"type":"integer"},"parents":{"type":"array","items":{"anyOf":[{"type":"string"},{"type":"null"}]}},"index":{"type":"number"}},"required":["id","parents","index"]}"#
+    );
+}
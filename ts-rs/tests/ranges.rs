@@ -33,3 +33,25 @@ fn range() {
         vec![Dependency::from_ty::<Inner>().unwrap(),]
     );
 }
+
+#[derive(TS)]
+#[allow(dead_code)]
+struct Generic<T> {
+    t: T,
+}
+
+#[derive(TS)]
+#[allow(dead_code)]
+struct RangeInGeneric {
+    a: Generic<Range<u32>>,
+    #[ts(inline)]
+    b: Generic<RangeInclusive<u8>>,
+}
+
+#[test]
+fn range_in_generic() {
+    assert_eq!(
+        RangeInGeneric::decl(),
+        "type RangeInGeneric = { a: Generic<{ start: number, end: number, }>, b: { t: { start: number, end: number, }, }, }"
+    );
+}
@@ -1,7 +1,10 @@
+#![allow(non_camel_case_types, dead_code)]
+
 use ts_rs::TS;
 
-#[allow(non_camel_case_types, dead_code)]
+// `enum` is a reserved word in TypeScript, so the type has to be renamed
 #[derive(TS)]
+#[ts(rename = "Enum")]
 struct r#enum {
     r#type: i32,
     r#use: i32,
@@ -10,11 +13,53 @@ struct r#enum {
     r#enum: i32,
 }
 
+#[derive(TS)]
+#[ts(export_to = "tests-out/raw_idents/")]
+enum r#async {
+    r#type,
+    r#match(i32),
+    r#loop { r#in: bool },
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/raw_idents/", rename_all = "UPPERCASE")]
+enum r#dyn {
+    r#type,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/raw_idents/")]
+struct r#match {
+    r#async: r#async,
+    r#dyn: r#dyn,
+}
+
 #[test]
 fn raw_idents() {
     let out = <r#enum as TS>::decl();
     assert_eq!(
         out,
-        "type enum = { type: number, use: number, struct: number, let: number, enum: number, }"
+        "type Enum = { type: number, use: number, struct: number, let: number, enum: number, }"
+    );
+}
+
+#[test]
+fn raw_variants() {
+    assert_eq!(
+        <r#async as TS>::decl(),
+        "type async = \"type\" | { \"match\": number } | { \"loop\": { in: boolean, } };"
+    );
+    assert_eq!(<r#dyn as TS>::decl(), "type dyn = \"TYPE\";");
+}
+
+#[test]
+fn raw_imports() {
+    let exported = <r#match as TS>::export_to_string().unwrap();
+    assert!(exported.contains("import type { async } from \"./async\";\n"));
+    assert!(exported.contains("import type { dyn } from \"./dyn\";\n"));
+    assert!(exported.contains("export type match = { async: async, dyn: dyn, }"));
+    assert_eq!(
+        <r#match as TS>::get_export_to().as_deref(),
+        Some("tests-out/raw_idents/match.ts")
     );
 }
@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[test]
+fn readonly_field() {
+    #[derive(TS)]
+    struct User {
+        #[ts(readonly)]
+        user_id: i32,
+        name: String,
+    }
+
+    assert_eq!(
+        User::inline(),
+        "{ readonly user_id: number, name: string, }"
+    );
+}
+
+#[test]
+fn readonly_struct() {
+    #[derive(TS)]
+    #[ts(readonly)]
+    struct User {
+        user_id: i32,
+        #[ts(optional)]
+        name: Option<String>,
+    }
+
+    assert_eq!(
+        User::inline(),
+        "{ readonly user_id: number, readonly name?: string, }"
+    );
+}
+
+#[test]
+fn readonly_variant_field() {
+    #[derive(TS)]
+    enum Event {
+        Created {
+            #[ts(readonly)]
+            id: i32,
+        },
+    }
+
+    assert_eq!(Event::inline(), r#"{ "Created": { readonly id: number, } }"#);
+}
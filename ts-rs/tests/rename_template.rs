@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/rename_template/", rename = "Api{name}")]
+struct Config {
+    retries: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/rename_template/", rename = "{name}Dto")]
+enum Error {
+    NotFound,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/rename_template/")]
+struct Response {
+    config: Config,
+    error: Option<Error>,
+}
+
+#[test]
+fn rename_template() {
+    assert_eq!(Config::decl(), "type ApiConfig = { retries: number, }");
+    assert_eq!(Error::decl(), r#"type ErrorDto = "NotFound";"#);
+    assert_eq!(
+        Response::decl(),
+        "type Response = { config: ApiConfig, error: ErrorDto | null, }"
+    );
+    assert_eq!(
+        Config::get_export_to().as_deref(),
+        Some("tests-out/rename_template/ApiConfig.ts")
+    );
+}
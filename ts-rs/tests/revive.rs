@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct User {
+    id: u32,
+    #[ts(revive = "Date")]
+    created_at: String,
+    /// Last login of the user
+    #[ts(revive = "Date")]
+    last_login: Option<String>,
+    #[ts(revive = "bigint")]
+    balance: String,
+    #[ts(revive = "Uint8Array")]
+    avatar: String,
+}
+
+#[test]
+fn revive_tags() {
+    assert_eq!(
+        User::inline(),
+        "{ \
+            id: number, \
+            \n/**\n * @revive Date\n */\ncreated_at: string, \
+            \n/**\n * Last login of the user\n *\n * @revive Date\n */\nlast_login: string | null, \
+            \n/**\n * @revive bigint\n */\nbalance: string, \
+            \n/**\n * @revive Uint8Array\n */\navatar: string, \
+        }"
+    );
+}
+
+#[derive(TS)]
+#[ts(reviver, export_to = "tests-out/revive/")]
+struct Event {
+    name: String,
+    #[ts(revive = "Date")]
+    at: String,
+    #[ts(optional, revive = "Date")]
+    until: Option<String>,
+    #[ts(revive = "bigint", rename = "total-amount")]
+    amount: String,
+    #[ts(revive = "Uint8Array")]
+    payload: Option<String>,
+}
+
+#[test]
+fn reviver() {
+    assert_eq!(
+        Event::decl(),
+        "type Event = { \
+            name: string, \
+            \n/**\n * @revive Date\n */\nat: Date, \
+            \n/**\n * @revive Date\n */\nuntil?: Date, \
+            \n/**\n * @revive bigint\n */\n\"total-amount\": bigint, \
+            \n/**\n * @revive Uint8Array\n */\npayload: Uint8Array | null, \
+        }"
+    );
+
+    let exported = Event::export_to_string().unwrap();
+    assert!(exported.contains(
+        "export type EventRaw = { \
+            name: string, \
+            \n/**\n * @revive Date\n */\nat: string, \
+            \n/**\n * @revive Date\n */\nuntil?: string, \
+            \n/**\n * @revive bigint\n */\n\"total-amount\": string, \
+            \n/**\n * @revive Uint8Array\n */\npayload: string | null, \
+        };"
+    ));
+    assert!(exported.contains(
+        "export function reviveEvent(raw: EventRaw): Event { return { ...raw, \
+            at: new Date(raw.at), \
+            until: raw.until == null ? raw.until : new Date(raw.until), \
+            \"total-amount\": BigInt(raw[\"total-amount\"]), \
+            payload: raw.payload == null ? raw.payload : Uint8Array.from(atob(raw.payload), (c) => c.charCodeAt(0)), \
+        }; }"
+    ));
+    assert!(exported.contains(
+        "export function serializeEvent(value: Event): EventRaw { return { ...value, \
+            at: value.at.toISOString(), \
+            until: value.until == null ? value.until : value.until.toISOString(), \
+            \"total-amount\": value[\"total-amount\"].toString(), \
+            payload: value.payload == null ? value.payload : btoa(Array.from(value.payload, (b) => String.fromCharCode(b)).join(\"\")), \
+        }; }"
+    ));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "serde-json-impl")]
+#![allow(dead_code)]
+
+use serde_json::{Map, Number, Value};
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/serde_json/")]
+struct Event {
+    payload: Value,
+    metadata: Map<String, Value>,
+    count: Number,
+    extra: Option<Vec<Value>>,
+}
+
+#[test]
+fn serde_json() {
+    assert_eq!(
+        Event::decl(),
+        "type Event = { payload: JsonValue, metadata: Record<string, JsonValue>, count: number, extra: Array<JsonValue> | null, }"
+    );
+    assert_eq!(
+        Value::decl(),
+        "type JsonValue = number | string | boolean | Array<JsonValue> | { [key in string]?: JsonValue } | null;"
+    );
+}
+
+#[test]
+fn export() {
+    Event::export().unwrap();
+
+    let event = std::fs::read_to_string("tests-out/serde_json/Event.ts").unwrap();
+    assert!(event.contains(r#"import type { JsonValue } from "../../bindings/JsonValue";"#));
+
+    let json_value = std::fs::read_to_string("bindings/JsonValue.ts").unwrap();
+    assert!(json_value.contains("export type JsonValue = "));
+    assert!(!json_value.contains("import"));
+}
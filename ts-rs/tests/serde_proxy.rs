@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+#![cfg(feature = "serde-compat")]
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(TS, Deserialize)]
+struct RawEmail {
+    address: String,
+}
+
+#[derive(TS, Deserialize)]
+#[serde(try_from = "RawEmail")]
+struct Email {
+    user: String,
+    domain: String,
+}
+
+impl TryFrom<RawEmail> for Email {
+    type Error = String;
+
+    fn try_from(raw: RawEmail) -> Result<Self, Self::Error> {
+        match raw.address.split_once('@') {
+            Some((user, domain)) => Ok(Email {
+                user: user.to_owned(),
+                domain: domain.to_owned(),
+            }),
+            None => Err(format!("invalid email address: {}", raw.address)),
+        }
+    }
+}
+
+#[derive(TS, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+enum Level {
+    Low,
+    High,
+}
+
+impl TryFrom<String> for Level {
+    type Error = String;
+
+    fn try_from(level: String) -> Result<Self, Self::Error> {
+        match level.as_str() {
+            "low" => Ok(Level::Low),
+            "high" => Ok(Level::High),
+            _ => Err(level),
+        }
+    }
+}
+
+impl From<Level> for String {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Low => "low".to_owned(),
+            Level::High => "high".to_owned(),
+        }
+    }
+}
+
+#[derive(TS, Deserialize)]
+#[serde(from = "Vec<T>")]
+struct Stack<T: TS> {
+    items: Vec<T>,
+}
+
+impl<T: TS> From<Vec<T>> for Stack<T> {
+    fn from(items: Vec<T>) -> Self {
+        Stack { items }
+    }
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(Email::decl(), "type Email = RawEmail;");
+    assert_eq!(Email::inline(), "RawEmail");
+    assert!(Email::dependencies()
+        .iter()
+        .any(|dep| dep.ts_name == "RawEmail"));
+}
+
+#[test]
+fn try_from_into() {
+    assert_eq!(Level::decl(), "type Level = string;");
+    assert_eq!(Level::inline(), "string");
+}
+
+#[test]
+fn from_generic() {
+    assert_eq!(Stack::<()>::decl(), "type Stack<T> = Array<T>;");
+    assert_eq!(Stack::<i32>::inline(), "Array<T>");
+}
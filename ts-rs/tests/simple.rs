@@ -20,5 +20,7 @@ fn test_def() {
     assert_eq!(
         Simple::inline(),
         "{ a: number, b: string, c: [number, string, number], d: Array<string>, e: string | null, f: string, g: string | null, }"
-    )
+    );
+    // structs are declared as type aliases, never as interfaces
+    assert!(Simple::decl().starts_with("type Simple = { a: number,"));
 }
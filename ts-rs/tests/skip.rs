@@ -84,7 +84,7 @@ fn internally_tagged() {
 
     assert_eq!(
         Internally::decl(),
-        r#"type Internally = { "t": "A" } | { "t": "B",  } | { "t": "C", y: number, };"#
+        r#"type Internally = { "t": "A" } | { "t": "B" } | { "t": "C", y: number, };"#
     );
 }
 
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/skip_private/", skip_private)]
+struct Account {
+    pub id: u32,
+    pub name: String,
+    pub(crate) internal_id: u64,
+    password_hash: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/skip_private/", skip_private)]
+struct Secret {
+    value: String,
+}
+
+#[test]
+fn skip_private() {
+    assert_eq!(Account::decl(), "type Account = { id: number, name: string, }");
+    // like a struct whose fields are all skipped
+    assert_eq!(Secret::decl(), "type Secret = {  }");
+}
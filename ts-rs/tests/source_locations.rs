@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/source_locations/")]
+struct Location {
+    line: u32,
+}
+
+#[test]
+fn source_locations() {
+    assert_eq!(Location::SOURCE_LOCATION, Some(concat!(file!(), ":", 7)));
+
+    // this is the only test in this binary, so no other test has read the variable yet
+    std::env::set_var("TS_RS_SOURCE_LOCATIONS", "true");
+    let exported = Location::export_to_string().unwrap();
+    assert!(exported.contains(&format!("\n// {}:7\nexport type Location", file!())));
+}
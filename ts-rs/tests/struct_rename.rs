@@ -43,6 +43,30 @@ fn rename_all_pascal_case() {
     assert_eq!(Rename::inline(), "{ Crc32cHash: number, B: number, }");
 }
 
+#[test]
+fn rename_all_kebab_case() {
+    #[derive(TS)]
+    #[ts(rename_all = "kebab-case")]
+    struct Rename {
+        content_hash: i32,
+        b: i32,
+    }
+
+    assert_eq!(Rename::inline(), r#"{ "content-hash": number, b: number, }"#);
+}
+
+#[test]
+fn rename_all_screaming_kebab_case() {
+    #[derive(TS)]
+    #[ts(rename_all = "SCREAMING-KEBAB-CASE")]
+    struct Rename {
+        content_hash: i32,
+        b: i32,
+    }
+
+    assert_eq!(Rename::inline(), r#"{ "CONTENT-HASH": number, B: number, }"#);
+}
+
 #[cfg(feature = "serde-compat")]
 #[test]
 fn serde_rename_special_char() {
@@ -0,0 +1,59 @@
+#![cfg(feature = "strum-interop")]
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[strum(serialize_all = "snake_case")]
+enum Status {
+    InProgress,
+    #[strum(serialize = "done", serialize = "finished")]
+    Completed,
+    #[strum(to_string = "on hold", serialize = "paused")]
+    OnHold,
+    #[strum(disabled)]
+    Unknown,
+}
+
+#[derive(TS)]
+#[ts(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "kebab-case", ascii_case_insensitive)]
+enum Priority {
+    #[ts(rename = "urgent")]
+    #[strum(serialize = "critical", props(level = "1"))]
+    High,
+    #[strum(message = "the default")]
+    Normal,
+}
+
+#[derive(TS)]
+#[ts(from_string)]
+enum Shape {
+    #[strum(serialize = "sq", serialize = "square")]
+    Square,
+    Circle,
+}
+
+#[test]
+fn serialize() {
+    assert_eq!(
+        Status::inline(),
+        r#""in_progress" | "finished" | "on hold" | "unknown""#
+    );
+}
+
+#[test]
+fn ts_attributes_take_precedence() {
+    assert_eq!(Priority::inline(), r#""urgent" | "NORMAL""#);
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(Shape::decl(), r#"type Shape = "square" | "Circle";"#);
+    assert_eq!(
+        Shape::extra_decls(),
+        vec![
+            r#"const ShapeFromString: Record<string, Shape> = { "square": "square", "sq": "square", "Circle": "Circle" };"#
+        ]
+    );
+}
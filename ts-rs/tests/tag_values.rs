@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/tag_values/", tag_values, rename_all = "snake_case")]
+enum UserEvent {
+    Created { id: i32 },
+    Renamed(String),
+    Deleted,
+    #[ts(skip)]
+    Internal,
+}
+
+#[derive(TS)]
+#[ts(tag = "type", tag_values = "ShapeType")]
+enum Shape {
+    Circle { radius: f64 },
+    #[ts(rename = "rect")]
+    Rectangle { width: f64, height: f64 },
+}
+
+#[test]
+fn externally_tagged() {
+    assert_eq!(
+        UserEvent::extra_decls(),
+        vec![r#"const UserEventKind = { Created: "created", Renamed: "renamed", Deleted: "deleted" } as const;"#]
+    );
+}
+
+#[test]
+fn internally_tagged() {
+    assert_eq!(
+        Shape::extra_decls(),
+        vec![r#"const ShapeType = { Circle: "Circle", Rectangle: "rect" } as const;"#]
+    );
+}
+
+#[test]
+fn exported() {
+    let exported = UserEvent::export_to_string().unwrap();
+    assert!(exported.ends_with(
+        r#";
+
+export const UserEventKind = { Created: "created", Renamed: "renamed", Deleted: "deleted" } as const;"#
+    ));
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/target/", target = "ES2015")]
+struct Legacy {
+    id: u64,
+    balance: i128,
+    #[ts(large_integers = "number")]
+    count: u64,
+    small: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/target/", target = "ES2015")]
+enum LegacyEvent {
+    Created { id: u64 },
+    Deleted(i64),
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/target/", target = "ESNext")]
+struct Modern {
+    id: u64,
+}
+
+#[test]
+fn target() {
+    assert_eq!(
+        Legacy::decl(),
+        "type Legacy = { id: string, balance: string, count: number, small: number, }"
+    );
+    assert_eq!(
+        LegacyEvent::decl(),
+        r#"type LegacyEvent = { "Created": { id: string, } } | { "Deleted": string };"#
+    );
+    assert_eq!(Modern::decl(), "type Modern = { id: bigint, }");
+}
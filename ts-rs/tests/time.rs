@@ -0,0 +1,49 @@
+#![cfg(feature = "time-impl")]
+#![allow(dead_code)]
+
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+use ts_rs::TS;
+
+#[test]
+fn time() {
+    #[derive(TS)]
+    struct Times {
+        offset_date_time: OffsetDateTime,
+        primitive_date_time: PrimitiveDateTime,
+        date: Date,
+        time: Time,
+        duration: Duration,
+    }
+
+    assert_eq!(
+        Times::inline(),
+        "{ offset_date_time: string, primitive_date_time: string, date: string, time: string, duration: string, }"
+    );
+}
+
+#[cfg(feature = "serde-compat")]
+#[test]
+fn serde_modules() {
+    use serde::Serialize;
+    use time::serde::rfc3339;
+
+    #[derive(Serialize, TS)]
+    struct Event {
+        #[serde(with = "time::serde::timestamp")]
+        created: OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::milliseconds")]
+        updated: OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::option")]
+        deleted: Option<OffsetDateTime>,
+        #[serde(with = "rfc3339")]
+        scheduled: OffsetDateTime,
+        #[serde(with = "time::serde::iso8601::option")]
+        reminder: Option<OffsetDateTime>,
+    }
+
+    assert_eq!(
+        Event::inline(),
+        "{ created: number, updated: number, deleted: number | null, scheduled: string, reminder: string | null, }"
+    );
+    assert!(Event::warnings().is_empty());
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use std::{any::Any, error::Error, sync::Arc};
+
+use ts_rs::TS;
+
+trait Plugin {
+    fn name(&self) -> &str;
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/trait_objects/")]
+struct Failure {
+    code: u16,
+    source: Box<dyn Error + Send + Sync>,
+    cause: Option<Arc<dyn Error>>,
+    context: Box<dyn Any + Send>,
+    #[ts(as = "Vec<Box<ts_rs::Unknown>>")]
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+#[test]
+fn trait_objects() {
+    assert_eq!(
+        Failure::decl(),
+        "type Failure = { code: number, source: unknown, cause: unknown | null, \
+         context: unknown, plugins: Array<unknown>, }"
+    );
+    assert!(Failure::dependencies().is_empty());
+}
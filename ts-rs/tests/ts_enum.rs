@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "serde-compat")]
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(enum, rename_all = "lowercase")]
+enum Status {
+    Active,
+    #[ts(rename = "gone")]
+    Inactive,
+    #[ts(skip)]
+    Unknown,
+}
+
+#[derive(TS)]
+#[ts(const_enum)]
+enum Direction {
+    Up,
+    Down,
+}
+
+#[derive(TS)]
+struct Account {
+    status: Status,
+    direction: Direction,
+    #[ts(inline)]
+    inline_status: Status,
+}
+
+#[test]
+fn ts_enum() {
+    assert_eq!(
+        Status::decl(),
+        r#"enum Status { Active = "active", Inactive = "gone" }"#
+    );
+    assert_eq!(Status::inline(), r#""active" | "gone""#);
+}
+
+#[test]
+fn ts_const_enum() {
+    assert_eq!(
+        Direction::decl(),
+        r#"const enum Direction { Up = "Up", Down = "Down" }"#
+    );
+}
+
+#[test]
+fn referenced() {
+    assert_eq!(
+        Account::inline(),
+        r#"{ status: Status, direction: Direction, inline_status: "active" | "gone", }"#
+    );
+}
+
+#[cfg(feature = "serde-compat")]
+#[test]
+fn serde_rename() {
+    #[derive(Serialize, TS)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[ts(enum)]
+    enum Role {
+        SuperUser,
+        Guest,
+    }
+
+    assert_eq!(
+        Role::decl(),
+        r#"enum Role { SuperUser = "SUPER_USER", Guest = "GUEST" }"#
+    );
+}
@@ -0,0 +1,132 @@
+#![allow(dead_code)]
+
+use ts_rs::{TsField, TsType, TS};
+
+#[derive(TS)]
+struct User {
+    id: u32,
+    /// Name of the user
+    name: String,
+    #[ts(optional)]
+    email: Option<String>,
+    #[ts(readonly, rename = "created-at")]
+    created_at: String,
+    roles: Vec<Role>,
+}
+
+#[derive(TS)]
+enum Role {
+    Admin,
+    Member { team: String },
+}
+
+#[derive(TS)]
+struct Override {
+    #[ts(type = "(id: number) => void")]
+    callback: (),
+}
+
+fn primitive(name: &str) -> TsType {
+    TsType::Primitive(name.to_owned())
+}
+
+fn field(name: &str, ty: TsType) -> TsField {
+    TsField {
+        name: name.to_owned(),
+        ty,
+        optional: false,
+        readonly: false,
+        docs: None,
+    }
+}
+
+#[test]
+fn parse_struct() {
+    assert_eq!(
+        User::ts_type(),
+        TsType::Object(vec![
+            field("id", primitive("number")),
+            TsField {
+                docs: Some("/**\n * Name of the user\n */".to_owned()),
+                ..field("name", primitive("string"))
+            },
+            TsField {
+                optional: true,
+                ..field("email", primitive("string"))
+            },
+            TsField {
+                readonly: true,
+                ..field("\"created-at\"", primitive("string"))
+            },
+            field(
+                "roles",
+                TsType::Reference {
+                    name: "Array".to_owned(),
+                    args: vec![TsType::Reference {
+                        name: "Role".to_owned(),
+                        args: vec![],
+                    }],
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn parse_enum() {
+    assert_eq!(
+        Role::ts_type(),
+        TsType::Union(vec![
+            TsType::Literal("\"Admin\"".to_owned()),
+            TsType::Object(vec![field(
+                "\"Member\"",
+                TsType::Object(vec![field("team", primitive("string"))])
+            )]),
+        ])
+    );
+}
+
+#[test]
+fn unsupported() {
+    assert_eq!(
+        Override::ts_type(),
+        TsType::Raw("{ callback: (id: number) => void, }".to_owned())
+    );
+}
+
+#[test]
+fn render() {
+    assert_eq!(User::ts_type().to_string(), User::inline());
+    assert_eq!(
+        TsType::parse("{ a: (A | B)[] } & (C | D)").to_string(),
+        "{ a: (A | B)[], } & (C | D)"
+    );
+    assert_eq!(
+        format!("{:#}", User::ts_type()),
+        r#"{
+  id: number,
+  /**
+   * Name of the user
+   */
+  name: string,
+  email?: string,
+  readonly "created-at": string,
+  roles: Array<Role>,
+}"#
+    );
+}
+
+#[test]
+fn transform() {
+    let TsType::Object(mut fields) = User::ts_type() else {
+        panic!("expected an object");
+    };
+    fields.retain(|field| field.name != "roles");
+    fields.iter_mut().for_each(|field| field.readonly = true);
+
+    assert_eq!(
+        TsType::Object(fields).to_string(),
+        "{ readonly id: number, \n/**\n * Name of the user\n */\nreadonly name: string, \
+         readonly email?: string, readonly \"created-at\": string, }"
+    );
+}
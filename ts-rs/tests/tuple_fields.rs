@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/tuple_fields/")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/tuple_fields/")]
+struct Segment(
+    #[ts(skip)] u64,
+    #[ts(type = "Date")] String,
+    #[ts(inline)] Point,
+    Point,
+    #[ts(optional)] Option<String>,
+    #[ts(optional = nullable)] Option<Point>,
+);
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/tuple_fields/")]
+struct Range(i32, #[ts(optional, type = "number | \"end\"")] Option<i32>);
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/tuple_fields/")]
+enum Shape {
+    Line(#[ts(inline)] Point, #[ts(skip)] String, Point),
+    Arc(
+        Point,
+        #[ts(type = "number")] String,
+        #[ts(optional)] Option<f32>,
+    ),
+}
+
+#[test]
+fn tuple_struct() {
+    assert_eq!(
+        Segment::decl(),
+        "type Segment = [Date, { x: number, y: number, }, Point, string?, (Point | null)?];"
+    );
+    assert_eq!(Range::decl(), "type Range = [number, (number | \"end\")?];");
+}
+
+#[test]
+fn tuple_variant() {
+    assert_eq!(
+        Shape::decl(),
+        "type Shape = { \"Line\": [{ x: number, y: number, }, Point] } | { \"Arc\": [Point, number, number?] };"
+    );
+}
+
+#[test]
+fn dependencies() {
+    let deps = Segment::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<Vec<_>>();
+    assert_eq!(deps, ["Point"]);
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn optional_schema() {
+    assert_eq!(
+        Range::json_schema(),
+        r#"{"type":"array","prefixItems":[{"type":"number"},{}],"minItems":1,"maxItems":2}"#
+    );
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+use ts_rs::TS;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/type_alias/", as = "(f64, f64)")]
+struct LatLng;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/type_alias/", as = "(T, T)")]
+struct Pair<T>(PhantomData<T>);
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/type_alias/")]
+struct Route {
+    #[ts(as = "LatLng")]
+    start: (f64, f64),
+    #[ts(as = "Vec<LatLng>")]
+    stops: Vec<(f64, f64)>,
+    #[ts(as = "Pair<String>")]
+    names: (String, String),
+}
+
+#[test]
+fn alias() {
+    assert_eq!(LatLng::decl(), "type LatLng = [number, number];");
+    assert_eq!(LatLng::inline(), "[number, number]");
+    assert_eq!(Pair::<()>::decl(), "type Pair<T> = [T, T];");
+}
+
+#[test]
+fn reference_alias() {
+    assert_eq!(
+        Route::decl(),
+        "type Route = { start: LatLng, stops: Array<LatLng>, names: Pair<string>, }"
+    );
+    let deps = Route::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<Vec<_>>();
+    assert_eq!(deps, ["LatLng", "Pair"]);
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::{EnumRepresentation, TypeKind, TS};
+
+#[derive(TS)]
+struct Named {
+    id: u32,
+}
+
+#[derive(TS)]
+struct Newtype(u32);
+
+#[derive(TS)]
+struct Pair(u32, String);
+
+#[derive(TS)]
+struct Marker;
+
+#[derive(TS)]
+enum External {
+    A,
+    B(u32),
+}
+
+#[derive(TS)]
+#[ts(tag = "type")]
+enum Internal {
+    A { id: u32 },
+}
+
+#[derive(TS)]
+#[ts(tag = "t", content = "c")]
+enum Adjacent {
+    A(u32),
+}
+
+#[derive(TS)]
+#[ts(untagged)]
+enum Untagged {
+    A(u32),
+    B(String),
+}
+
+#[derive(TS)]
+#[ts(as = "Named")]
+struct Proxy(u64);
+
+#[test]
+fn type_kind() {
+    assert_eq!(Named::KIND, TypeKind::Struct);
+    assert_eq!(Newtype::KIND, TypeKind::Newtype);
+    assert_eq!(Pair::KIND, TypeKind::Tuple);
+    assert_eq!(Marker::KIND, TypeKind::Unit);
+    assert_eq!(Proxy::KIND, TypeKind::Struct);
+
+    let representation = |kind| match kind {
+        TypeKind::Enum { representation } => representation,
+        other => panic!("expected an enum, found {:?}", other),
+    };
+    assert_eq!(representation(External::KIND), EnumRepresentation::External);
+    assert_eq!(
+        representation(Internal::KIND),
+        EnumRepresentation::Internal { tag: "type" }
+    );
+    assert_eq!(
+        representation(Adjacent::KIND),
+        EnumRepresentation::Adjacent {
+            tag: "t",
+            content: "c"
+        }
+    );
+    assert_eq!(representation(Untagged::KIND), EnumRepresentation::Untagged);
+
+    assert_eq!(u32::KIND, TypeKind::Primitive);
+    assert_eq!(<(u32, String)>::KIND, TypeKind::Tuple);
+    assert_eq!(<Vec<Named>>::KIND, TypeKind::Container);
+    assert_eq!(<HashMap<String, Named>>::KIND, TypeKind::Container);
+    assert_eq!(<Box<Named>>::KIND, TypeKind::Struct);
+    assert_eq!(TypeKind::Newtype.as_str(), "newtype");
+}
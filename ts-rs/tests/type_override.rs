@@ -64,3 +64,81 @@ fn enum_newtype_representations() {
     assert_eq!(Internal::inline(), r#"{ "t": "Newtype" } & unknown"#);
     assert_eq!(Adjacent::inline(), r#"{ "t": "Newtype", "c": unknown }"#);
 }
+
+#[test]
+fn generic_placeholders() {
+    #[derive(TS)]
+    struct Generic<T, U> {
+        #[ts(type = "Record<string, {T}>")]
+        map: Unsupported<T>,
+        #[ts(type = "{ value: {U}, values: Array<{U}> }")]
+        nested: Unsupported<U>,
+        #[ts(type = "{V}")]
+        unknown: Unsupported2,
+    }
+    #[derive(TS)]
+    struct GenericNewtype<T>(#[ts(type = "Set<{T}>")] Unsupported<T>);
+    #[derive(TS)]
+    enum GenericEnum<T> {
+        Tuple(#[ts(type = "Promise<{T}>")] Unsupported<T>, u8),
+    }
+
+    assert_eq!(
+        Generic::<(), ()>::decl(),
+        "type Generic<T, U> = { map: Record<string, T>, nested: { value: U, values: Array<U> }, unknown: {V}, }"
+    );
+    assert_eq!(
+        Generic::<String, u8>::inline(),
+        "{ map: Record<string, string>, nested: { value: number, values: Array<number> }, unknown: {V}, }"
+    );
+    assert_eq!(
+        GenericNewtype::<()>::decl(),
+        "type GenericNewtype<T> = Set<T>;"
+    );
+    assert_eq!(
+        GenericEnum::<()>::decl(),
+        r#"type GenericEnum<T> = { "Tuple": [Promise<T>, number] };"#
+    );
+}
+
+#[test]
+fn uses() {
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/type_override/")]
+    struct Paginated<T> {
+        items: Vec<T>,
+    }
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/type_override/")]
+    struct Item {
+        id: u32,
+    }
+
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/type_override/")]
+    struct Response {
+        #[ts(type = "Paginated<Item>", uses(Paginated<Item>, Item))]
+        page: Unsupported<Item>,
+    }
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/type_override/")]
+    struct ItemNewtype(#[ts(type = "Item | null", uses(Item))] Unsupported2);
+    #[derive(TS)]
+    #[ts(export_to = "tests-out/type_override/")]
+    struct Unreferenced(#[ts(type = "string", uses(Item))] Unsupported2, u8);
+
+    let names =
+        |deps: Vec<ts_rs::Dependency>| deps.into_iter().map(|dep| dep.ts_name).collect::<Vec<_>>();
+    assert_eq!(
+        Response::decl(),
+        "type Response = { page: Paginated<Item>, }"
+    );
+    assert_eq!(names(Response::dependencies()), ["Paginated", "Item"]);
+    assert_eq!(names(ItemNewtype::dependencies()), ["Item"]);
+    assert_eq!(names(Unreferenced::dependencies()), ["Item"]);
+    assert_eq!(
+        Unreferenced::warnings(),
+        ["`Item` is declared in `uses`, but is not referenced in `#[ts(type = \"string\")]`"]
+    );
+    assert!(Response::warnings().is_empty());
+}
@@ -66,6 +66,6 @@ fn test() {
 
     assert_eq!(
         TestInternally::decl(),
-        r#"type TestInternally = { "type": "A" } | { "type": "B" } | { "type": "C",  };"#
+        r#"type TestInternally = { "type": "A" } | { "type": "B" } | { "type": "C" };"#
     );
 }
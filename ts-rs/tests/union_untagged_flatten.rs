@@ -0,0 +1,196 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "serde-compat")]
+use serde::Serialize;
+use ts_rs::TS;
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+struct Inner {
+    x: i32,
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+struct Outer {
+    #[cfg_attr(feature = "serde-compat", serde(flatten))]
+    #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+    inner: Inner,
+    y: i32,
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+enum Choice {
+    A { a: i32 },
+    B { b: String },
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", serde(untagged))]
+#[cfg_attr(not(feature = "serde-compat"), ts(untagged))]
+enum Untagged {
+    Plain {
+        a: i32,
+    },
+    Optional {
+        #[cfg_attr(
+            feature = "serde-compat",
+            serde(skip_serializing_if = "Option::is_none")
+        )]
+        #[ts(optional)]
+        b: Option<String>,
+    },
+    Nested {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        outer: Outer,
+        z: bool,
+    },
+    Choice {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        choice: Choice,
+        c: i32,
+    },
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", serde(tag = "type"))]
+#[cfg_attr(not(feature = "serde-compat"), ts(tag = "type"))]
+enum Internal {
+    Nested {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        outer: Outer,
+    },
+    Choice {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        choice: Choice,
+    },
+    Empty {},
+}
+
+#[test]
+fn nested_flatten() {
+    #[cfg_attr(feature = "serde-compat", derive(Serialize))]
+    #[derive(TS)]
+    struct Top {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        outer: Outer,
+        t: i32,
+    }
+
+    assert_eq!(Top::inline(), "{ t: number, y: number, x: number, }");
+}
+
+#[test]
+fn untagged_struct_variants() {
+    assert_eq!(
+        Untagged::inline(),
+        "{ a: number, } | { b?: string, } | { z: boolean, } & Outer | { c: number, } & Choice"
+    );
+}
+
+#[test]
+fn internally_tagged_flattened_variants() {
+    assert_eq!(
+        Internal::inline(),
+        r#"{ "type": "Nested" } & Outer | { "type": "Choice" } & Choice | { "type": "Empty" }"#
+    );
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", serde(tag = "type"))]
+#[cfg_attr(not(feature = "serde-compat"), ts(tag = "type"))]
+enum InternalGeneric<T> {
+    Fields {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        inner: Inner,
+        z: bool,
+    },
+    Generic {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        value: T,
+    },
+    Optional {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        outer: Option<Outer>,
+    },
+}
+
+#[test]
+fn internally_tagged_flattened_references() {
+    assert_eq!(
+        InternalGeneric::<()>::decl(),
+        r#"type InternalGeneric<T> = { "type": "Fields", z: boolean, } & Inner | { "type": "Generic" } & T | { "type": "Optional" } & Partial<Outer>;"#
+    );
+    // the flattened types are referenced, so they're imported
+    let dependencies = InternalGeneric::<()>::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<Vec<_>>();
+    assert_eq!(dependencies, ["Inner", "Outer"]);
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+enum ExternalGeneric<T> {
+    Fields {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        choice: Choice,
+        z: bool,
+    },
+    Generic {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        value: T,
+    },
+}
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", serde(tag = "type", content = "data"))]
+#[cfg_attr(not(feature = "serde-compat"), ts(tag = "type", content = "data"))]
+enum AdjacentGeneric<T> {
+    Fields {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        inner: Inner,
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        outer: Option<Outer>,
+        z: bool,
+    },
+    Generic {
+        #[cfg_attr(feature = "serde-compat", serde(flatten))]
+        #[cfg_attr(not(feature = "serde-compat"), ts(flatten))]
+        value: T,
+    },
+}
+
+#[test]
+fn tagged_flattened_references() {
+    assert_eq!(
+        ExternalGeneric::<()>::decl(),
+        r#"type ExternalGeneric<T> = { "Fields": { z: boolean, } & Choice } | { "Generic": T };"#
+    );
+    assert_eq!(
+        AdjacentGeneric::<()>::decl(),
+        r#"type AdjacentGeneric<T> = { "type": "Fields", "data": { z: boolean, } & Inner & Partial<Outer> } | { "type": "Generic", "data": T };"#
+    );
+    let dependencies = AdjacentGeneric::<()>::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<Vec<_>>();
+    assert_eq!(dependencies, ["Inner", "Outer"]);
+}
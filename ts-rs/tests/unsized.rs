@@ -18,3 +18,34 @@ fn contains_str() {
         "type S = { b: string, c: string, r: string, a: string, }"
     )
 }
+
+#[test]
+fn contains_slice() {
+    #[derive(TS, Clone)]
+    #[ts(export_to = "tests-out/unsized/")]
+    #[allow(dead_code)]
+    struct Item {
+        id: u32,
+    }
+
+    #[derive(TS)]
+    #[allow(dead_code)]
+    struct S<'a> {
+        c: Cow<'a, [Item]>,
+        r: &'a [Item],
+        s: &'a str,
+        n: Cow<'a, [Cow<'a, str>]>,
+    }
+
+    assert_eq!(
+        S::decl(),
+        "type S = { c: Array<Item>, r: Array<Item>, s: string, n: Array<string>, }"
+    );
+    assert_eq!(<Cow<'static, [Item]>>::inline(), "Array<{ id: number, }>");
+
+    let deps = S::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<Vec<_>>();
+    assert_eq!(deps, ["Item"]);
+}
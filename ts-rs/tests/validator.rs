@@ -0,0 +1,56 @@
+#![cfg(feature = "validator-interop")]
+#![allow(dead_code)]
+
+use ts_rs::TS;
+
+#[derive(TS)]
+struct SignUp {
+    /// The name shown to other users
+    #[validate(length(min = 1, max = 64, message = "invalid name"))]
+    name: String,
+    #[validate(email, custom(function = "not_taken"))]
+    email: String,
+    #[validate(range(min = -10, exclusive_max = 2.5))]
+    offset: f64,
+    #[validate(length(equal = 6), regex(path = *CODE))]
+    code: String,
+}
+
+#[derive(TS)]
+struct Profile {
+    #[garde(length(chars, min = 3), pattern(r"^[a-z]+$"))]
+    handle: String,
+    #[garde(url)]
+    website: Option<String>,
+    #[garde(skip)]
+    bio: String,
+}
+
+#[test]
+fn validator() {
+    assert_eq!(
+        SignUp::decl(),
+        concat!(
+            "type SignUp = { ",
+            "\n/**\n * The name shown to other users\n *\n * @minLength 1\n * @maxLength 64\n */\nname: string, ",
+            "\n/**\n * @format email\n */\nemail: string, ",
+            "\n/**\n * @minimum -10\n * @exclusiveMaximum 2.5\n */\noffset: number, ",
+            "\n/**\n * @minLength 6\n * @maxLength 6\n */\ncode: string, ",
+            "}"
+        )
+    );
+}
+
+#[test]
+fn garde() {
+    assert_eq!(
+        Profile::decl(),
+        concat!(
+            "type Profile = { ",
+            "\n/**\n * @minLength 3\n * @pattern ^[a-z]+$\n */\nhandle: string, ",
+            "\n/**\n * @format url\n */\nwebsite: string | null, ",
+            "bio: string, ",
+            "}"
+        )
+    );
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Serialize, TS)]
+#[serde(tag = "type")]
+#[ts(export_to = "tests-out/variant_types/", variant_types)]
+enum Event {
+    Click { x: i32, y: i32 },
+    #[serde(rename = "key")]
+    KeyPress { code: String },
+    Close,
+    #[ts(skip)]
+    Internal,
+}
+
+#[derive(Serialize, TS)]
+#[serde(tag = "kind", content = "data")]
+#[ts(variant_types)]
+enum Message<T> {
+    Text(String),
+    Payload(T),
+}
+
+#[test]
+fn internally_tagged() {
+    assert_eq!(
+        Event::extra_decls(),
+        vec![
+            r#"type EventClick = Extract<Event, { type: "Click" }>;"#,
+            r#"type EventKeyPress = Extract<Event, { type: "key" }>;"#,
+            r#"type EventClose = Extract<Event, { type: "Close" }>;"#,
+        ]
+    );
+}
+
+#[test]
+fn adjacently_tagged() {
+    assert_eq!(
+        Message::<()>::extra_decls(),
+        vec![
+            r#"type MessageText<T> = Extract<Message<T>, { kind: "Text" }>;"#,
+            r#"type MessagePayload<T> = Extract<Message<T>, { kind: "Payload" }>;"#,
+        ]
+    );
+}
+
+#[test]
+fn exported() {
+    let exported = Event::export_to_string().unwrap();
+    assert!(exported.contains(
+        r#"
+
+export type EventClick = Extract<Event, { type: "Click" }>;
+
+export type EventKeyPress"#
+    ));
+}
@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "serde-compat")]
+use serde::Serialize;
+use ts_rs::TS;
+
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[derive(TS)]
+#[ts(export_to = "tests-out/warnings/")]
+struct Measurement {
+    #[cfg_attr(feature = "serde-compat", serde(serialize_with = "serialize_value"))]
+    value: f64,
+    timestamp: Vec<u64>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/warnings/")]
+struct Counters {
+    total: u64,
+    history: Vec<Option<u64>>,
+    #[ts(large_integers = "number")]
+    visits: Vec<u64>,
+    #[ts(large_integers = "bigint")]
+    balance: i64,
+    #[ts(type = "number")]
+    likes: i128,
+}
+
+#[cfg(feature = "serde-compat")]
+fn serialize_value<S: serde::Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(*value)
+}
+
+#[test]
+fn derived_warnings() {
+    let bigint = "`u64` is exported as `bigint`, but serde_json serializes it as a number";
+    #[cfg(feature = "serde-compat")]
+    assert_eq!(
+        Measurement::warnings(),
+        vec![
+            "failed to parse serde attribute `#[serde(serialize_with = \"serialize_value\")]`, it was ignored",
+            bigint
+        ]
+    );
+    #[cfg(not(feature = "serde-compat"))]
+    assert_eq!(Measurement::warnings(), vec![bigint]);
+
+    // the warning is recorded once per type, and not if `large_integers` is set for the field
+    assert_eq!(Counters::warnings(), vec![bigint]);
+    assert!(u64::warnings().is_empty());
+}
+
+#[test]
+fn warnings_file() {
+    Measurement::export().unwrap();
+
+    let warnings = std::fs::read_to_string("bindings/ts-rs-warnings.json").unwrap();
+    assert!(warnings.starts_with("[\n"));
+    assert!(warnings.contains(concat!(
+        r#"  {"type":"Measurement","file":"tests-out/warnings/Measurement.ts","#,
+        r#""message":"`u64` is exported as `bigint`, but serde_json serializes it as a number"}"#
+    )));
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/warnings/")]
+struct User {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/warnings/")]
+struct Author {
+    id: u32,
+    name: String,
+}
+
+struct Timestamp;
+
+#[derive(TS)]
+#[ts(export_to = "tests-out/warnings/")]
+struct Post {
+    #[ts(type = "string")]
+    title: String,
+    #[ts(type = "User", uses(User))]
+    author: Author,
+    #[ts(type = "number")]
+    views: u64,
+    #[ts(type = "Date")]
+    created_at: Timestamp,
+}
+
+#[test]
+fn stale_type_overrides() {
+    Post::export().unwrap();
+
+    let warnings = std::fs::read_to_string("bindings/ts-rs-warnings.json").unwrap();
+    let warnings = warnings
+        .lines()
+        .filter(|line| line.contains(r#"{"type":"Post","#))
+        .map(|line| line.trim_end_matches(','))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        [
+            concat!(
+                r#"  {"type":"Post","file":"tests-out/warnings/Post.ts","#,
+                r#""message":"`#[ts(type = \"User\")]` on `author` may be stale, since `User` is declared as `{ id: number, }`, but its type `Author` is exported as `{ id: number, name: string, }`"}"#
+            ),
+            concat!(
+                r#"  {"type":"Post","file":"tests-out/warnings/Post.ts","#,
+                r#""message":"`#[ts(type = \"string\")]` on `title` has no effect, since its type is already exported as `string`"}"#
+            ),
+        ]
+    );
+}